@@ -0,0 +1,220 @@
+use std::cell::Cell;
+use std::fmt;
+use std::io::Read;
+use std::rc::Rc;
+use xml::common::Position;
+use xml::reader::{EventReader, ParserConfig, XmlEvent};
+use xml::writer::{EmitterConfig, EventWriter};
+use yaserde::{de::Deserializer, YaDeserialize};
+
+/// Deserialize `content` like `yaserde::de::from_str`, without trimming the text of the elements.
+/// The `whiteSpace` facets of the generated types are then applied on the untouched text.
+pub fn from_str<T: YaDeserialize>(content: &str) -> Result<T, String> {
+  from_reader(content.as_bytes())
+}
+
+/// Deserialize from `reader`, without trimming the text of the elements.
+pub fn from_reader<R: Read, T: YaDeserialize>(reader: R) -> Result<T, String> {
+  let config = ParserConfig::new()
+    .trim_whitespace(false)
+    .whitespace_to_characters(true)
+    .cdata_to_characters(true)
+    .ignore_comments(true)
+    .coalesce_characters(true);
+
+  let mut deserializer = Deserializer::new(EventReader::new_with_config(reader, config));
+  T::deserialize(&mut deserializer)
+}
+
+/// Failure of a deserialization, located at the element being read when it failed.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseError {
+  pub message: String,
+  /// Local names of the elements from the root, as `Order/item/quantity`.
+  pub path: String,
+  pub line: u64,
+  pub column: u64,
+}
+
+impl fmt::Display for ParseError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(
+      f,
+      "{} at {} (line {}, column {})",
+      self.message, self.path, self.line, self.column
+    )
+  }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Deserialize `content` like `yaserde::de::from_str`, locating the failures in the document.
+pub fn from_str_located<T: YaDeserialize>(content: &str) -> Result<T, ParseError> {
+  let consumed = Rc::new(Cell::new(0));
+  let reader = CountingReader {
+    inner: content.as_bytes(),
+    consumed: consumed.clone(),
+  };
+
+  let mut deserializer = Deserializer::new_from_reader(reader);
+  T::deserialize(&mut deserializer)
+    .map_err(|message| locate(&content.as_bytes()[..consumed.get()], message))
+}
+
+/// Reader counting the bytes read, xml-rs reading its source byte by byte.
+struct CountingReader<R: Read> {
+  inner: R,
+  consumed: Rc<Cell<usize>>,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+  fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+    let size = self.inner.read(buf)?;
+    self.consumed.set(self.consumed.get() + size);
+    Ok(size)
+  }
+}
+
+/// Locate `message` at the last element started, or holding the last text, in the `read` part of
+/// the document.
+fn locate(read: &[u8], message: String) -> ParseError {
+  let config = ParserConfig::new()
+    .trim_whitespace(true)
+    .ignore_comments(true);
+  let mut reader = EventReader::new_with_config(read, config);
+
+  let mut names = vec![];
+  let mut path = String::new();
+  let mut position = reader.position();
+
+  loop {
+    match reader.next() {
+      Ok(XmlEvent::StartElement { name, .. }) => {
+        names.push(name.local_name);
+        path = names.join("/");
+        position = reader.position();
+      }
+      Ok(XmlEvent::Characters(_)) | Ok(XmlEvent::CData(_)) => {
+        path = names.join("/");
+        position = reader.position();
+      }
+      Ok(XmlEvent::EndElement { .. }) => {
+        names.pop();
+      }
+      Ok(XmlEvent::EndDocument) | Err(_) => break,
+      Ok(_) => {}
+    }
+  }
+
+  ParseError {
+    message,
+    path,
+    line: position.row + 1,
+    column: position.column + 1,
+  }
+}
+
+/// Check that the children of the root element of `content` follow the order of `sequence`,
+/// yaserde accepting the elements of a sequence in any order. Unknown children are left to the
+/// deserialization.
+pub fn check_sequence_order(content: &str, sequence: &[&str]) -> Result<(), String> {
+  let mut depth = 0;
+  let mut position = 0;
+
+  for event in EventReader::new(content.as_bytes()) {
+    match event.map_err(|e| e.to_string())? {
+      XmlEvent::StartElement { name, .. } => {
+        depth += 1;
+
+        if depth != 2 {
+          continue;
+        }
+
+        if let Some(index) = sequence.iter().position(|item| *item == name.local_name) {
+          if index < position {
+            return Err(format!(
+              "element {} is not expected after {}",
+              name.local_name, sequence[position]
+            ));
+          }
+          position = index;
+        }
+      }
+      XmlEvent::EndElement { .. } => depth -= 1,
+      _ => {}
+    }
+  }
+
+  Ok(())
+}
+
+/// Local names of the children of the root element of `content`, in document order.
+pub fn child_names(content: &str) -> Result<Vec<String>, String> {
+  let mut names = vec![];
+  let mut depth = 0;
+
+  for event in EventReader::new(content.as_bytes()) {
+    match event.map_err(|e| e.to_string())? {
+      XmlEvent::StartElement { name, .. } => {
+        depth += 1;
+
+        if depth == 2 {
+          names.push(name.local_name);
+        }
+      }
+      XmlEvent::EndElement { .. } => depth -= 1,
+      _ => {}
+    }
+  }
+
+  Ok(names)
+}
+
+/// Children of the root element of `content` named in `names`, each one deserialized as `T` alone
+/// under the root, in document order. Used for the repeated choices, which yaserde can not flatten.
+pub fn choice_elements<T: YaDeserialize>(content: &str, names: &[&str]) -> Result<Vec<T>, String> {
+  let children = child_names(content)?
+    .into_iter()
+    .zip(unknown_elements(content, &[])?);
+
+  children
+    .filter(|(name, _)| names.contains(&name.as_str()))
+    .map(|(_, element)| yaserde::de::from_str(&format!("<choice>{element}</choice>")))
+    .collect()
+}
+
+/// Children of the root element of `content` not named in `known`, as XML fragments.
+pub fn unknown_elements(content: &str, known: &[&str]) -> Result<Vec<String>, String> {
+  let mut unknown = vec![];
+  let mut depth = 0;
+  let mut writer: Option<EventWriter<Vec<u8>>> = None;
+
+  for event in EventReader::new(content.as_bytes()) {
+    let event = event.map_err(|e| e.to_string())?;
+
+    if let XmlEvent::StartElement { name, .. } = &event {
+      depth += 1;
+
+      if depth == 2 && !known.contains(&name.local_name.as_str()) {
+        let config = EmitterConfig::new().write_document_declaration(false);
+        writer = Some(config.create_writer(vec![]));
+      }
+    }
+
+    if let (Some(writer), Some(event)) = (writer.as_mut(), event.as_writer_event()) {
+      writer.write(event).map_err(|e| e.to_string())?;
+    }
+
+    if let XmlEvent::EndElement { .. } = &event {
+      depth -= 1;
+
+      if depth == 1 {
+        if let Some(writer) = writer.take() {
+          unknown.push(String::from_utf8(writer.into_inner()).map_err(|e| e.to_string())?);
+        }
+      }
+    }
+  }
+
+  Ok(unknown)
+}
@@ -1,4 +1,13 @@
-#[cfg(feature = "xml_schema_derive")]
+#[cfg(feature = "xml-schema-derive")]
 #[allow(unused_imports)]
 #[macro_use]
 extern crate xml_schema_derive;
+
+pub mod de;
+mod nillable;
+mod qname;
+mod validation;
+
+pub use nillable::Nillable;
+pub use qname::QName;
+pub use validation::{ValidationError, XsdError};
@@ -0,0 +1,102 @@
+use std::fmt::Display;
+use std::io::{Read, Write};
+use std::str::FromStr;
+use xml::namespace::Namespace;
+use xml::reader::XmlEvent;
+use yaserde::{de::Deserializer, ser::Serializer, YaDeserialize, YaSerialize};
+
+const XML_SCHEMA_INSTANCE_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+/// Content of a `nillable` element of a simple type, `Nil` when the element is present with
+/// `xsi:nil="true"`. An optional nillable element is an `Option<Nillable<T>>`, `None` when absent.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Nillable<T> {
+  #[default]
+  Nil,
+  Value(T),
+}
+
+impl<T> Nillable<T> {
+  pub fn is_nil(&self) -> bool {
+    matches!(self, Nillable::Nil)
+  }
+
+  pub fn as_value(&self) -> Option<&T> {
+    match self {
+      Nillable::Nil => None,
+      Nillable::Value(value) => Some(value),
+    }
+  }
+}
+
+impl<T: FromStr> YaDeserialize for Nillable<T> {
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    let is_nil = match reader.next_event()? {
+      XmlEvent::StartElement { attributes, .. } => attributes.iter().any(|attribute| {
+        attribute.name.local_name == "nil"
+          && attribute.name.namespace.as_deref() == Some(XML_SCHEMA_INSTANCE_NAMESPACE)
+          && (attribute.value == "true" || attribute.value == "1")
+      }),
+      _ => return Err("Missing start event for a nillable element".to_string()),
+    };
+
+    let content = if let XmlEvent::Characters(content) = reader.peek()? {
+      let content = content.clone();
+      reader.next_event()?;
+      content
+    } else {
+      String::new()
+    };
+
+    if is_nil {
+      return Ok(Nillable::Nil);
+    }
+
+    content
+      .parse()
+      .map(Nillable::Value)
+      .map_err(|_| format!("Unable to parse {content:?} for a nillable element"))
+  }
+}
+
+impl<T: Display> YaSerialize for Nillable<T> {
+  fn serialize<W: Write>(&self, writer: &mut Serializer<W>) -> Result<(), String> {
+    let skip_start_end = writer.skip_start_end();
+
+    if !skip_start_end {
+      let name = writer.get_start_event_name().unwrap_or_default();
+
+      let start_event = xml::writer::XmlEvent::start_element(name.as_str());
+      let start_event = match self {
+        Nillable::Nil => start_event
+          .ns("xsi", XML_SCHEMA_INSTANCE_NAMESPACE)
+          .attr("xsi:nil", "true"),
+        Nillable::Value(_) => start_event,
+      };
+      writer.write(start_event).map_err(|e| e.to_string())?;
+    }
+
+    if let Nillable::Value(value) = self {
+      let content = value.to_string();
+      writer
+        .write(xml::writer::XmlEvent::characters(&content))
+        .map_err(|e| e.to_string())?;
+    }
+
+    if !skip_start_end {
+      writer
+        .write(xml::writer::XmlEvent::end_element())
+        .map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+  }
+
+  fn serialize_attributes(
+    &self,
+    attributes: Vec<xml::attribute::OwnedAttribute>,
+    namespace: Namespace,
+  ) -> Result<(Vec<xml::attribute::OwnedAttribute>, Namespace), String> {
+    Ok((attributes, namespace))
+  }
+}
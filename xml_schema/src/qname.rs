@@ -0,0 +1,101 @@
+use std::fmt;
+use std::io::{Read, Write};
+use xml::namespace::Namespace;
+use xml::reader::XmlEvent;
+use yaserde::{de::Deserializer, ser::Serializer, YaDeserialize, YaSerialize};
+
+/// Qualified name (`xs:QName`), with its prefix resolved against the in-scope namespaces.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct QName {
+  pub prefix: Option<String>,
+  pub local_name: String,
+  pub namespace: Option<String>,
+}
+
+impl QName {
+  pub fn resolve(value: &str, namespace: &Namespace) -> Self {
+    let (prefix, local_name) = match value.split_once(':') {
+      Some((prefix, local_name)) => (Some(prefix.to_string()), local_name.to_string()),
+      None => (None, value.to_string()),
+    };
+
+    let namespace = namespace
+      .get(prefix.as_deref().unwrap_or_default())
+      .filter(|namespace| !namespace.is_empty())
+      .map(|namespace| namespace.to_string());
+
+    QName {
+      prefix,
+      local_name,
+      namespace,
+    }
+  }
+}
+
+impl fmt::Display for QName {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match &self.prefix {
+      Some(prefix) => write!(f, "{}:{}", prefix, self.local_name),
+      None => write!(f, "{}", self.local_name),
+    }
+  }
+}
+
+impl YaDeserialize for QName {
+  fn deserialize<R: Read>(reader: &mut Deserializer<R>) -> Result<Self, String> {
+    let namespace = match reader.next_event()? {
+      XmlEvent::StartElement { namespace, .. } => namespace,
+      _ => return Err("Missing start event for QName".to_string()),
+    };
+
+    let content = if let XmlEvent::Characters(content) = reader.peek()? {
+      let content = content.trim().to_string();
+      reader.next_event()?;
+      content
+    } else {
+      String::new()
+    };
+
+    Ok(QName::resolve(&content, &namespace))
+  }
+}
+
+impl YaSerialize for QName {
+  fn serialize<W: Write>(&self, writer: &mut Serializer<W>) -> Result<(), String> {
+    let skip_start_end = writer.skip_start_end();
+
+    if !skip_start_end {
+      let name = writer
+        .get_start_event_name()
+        .unwrap_or_else(|| "QName".to_string());
+
+      let start_event = xml::writer::XmlEvent::start_element(name.as_str());
+      let start_event = match (&self.prefix, &self.namespace) {
+        (Some(prefix), Some(namespace)) => start_event.ns(prefix.as_str(), namespace.as_str()),
+        _ => start_event,
+      };
+      writer.write(start_event).map_err(|e| e.to_string())?;
+    }
+
+    let content = self.to_string();
+    writer
+      .write(xml::writer::XmlEvent::characters(&content))
+      .map_err(|e| e.to_string())?;
+
+    if !skip_start_end {
+      writer
+        .write(xml::writer::XmlEvent::end_element())
+        .map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+  }
+
+  fn serialize_attributes(
+    &self,
+    attributes: Vec<xml::attribute::OwnedAttribute>,
+    namespace: Namespace,
+  ) -> Result<(Vec<xml::attribute::OwnedAttribute>, Namespace), String> {
+    Ok((attributes, namespace))
+  }
+}
@@ -0,0 +1,42 @@
+use crate::de::ParseError;
+use std::fmt;
+
+/// Failure of a check of the generated `validate()`, with the path of the field it concerns.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ValidationError {
+  pub path: String,
+  pub message: String,
+}
+
+impl fmt::Display for ValidationError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    write!(f, "{}: {}", self.path, self.message)
+  }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Failure of the document methods generated on the global elements (`from_str`,
+/// `from_xml_validated`, `to_xml_string`): the document can not be parsed, it does not satisfy
+/// the constraints of the schema, or the value can not be serialized.
+#[derive(Clone, Debug, PartialEq)]
+pub enum XsdError {
+  Parse(ParseError),
+  Validation(Vec<ValidationError>),
+  Serialization(String),
+}
+
+impl fmt::Display for XsdError {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    match self {
+      XsdError::Parse(error) => write!(f, "{error}"),
+      XsdError::Validation(errors) => {
+        let messages: Vec<String> = errors.iter().map(|error| error.to_string()).collect();
+        write!(f, "{}", messages.join(", "))
+      }
+      XsdError::Serialization(message) => write!(f, "{message}"),
+    }
+  }
+}
+
+impl std::error::Error for XsdError {}
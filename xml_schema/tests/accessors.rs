@@ -0,0 +1,86 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn accessors() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/accessors.xsd", accessors)]
+  struct AccessorsSchema;
+
+  let xml_1 = r#"<Book isbn="978-0"><title>Rust</title><pages>42</pages></Book>"#;
+
+  let mut sample_1: xml_schema_types::Book = from_str(xml_1).unwrap();
+
+  assert_eq!(sample_1.title(), "Rust");
+  assert_eq!(*sample_1.pages(), 42);
+  assert_eq!(sample_1.isbn(), &Some("978-0".to_string()));
+
+  sample_1.set_pages(43);
+  sample_1.set_isbn(None);
+
+  let mut model = xml_schema_types::Book::default();
+  model.set_title("Rust".to_string());
+  model.set_pages(43);
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Book><title>Rust</title><pages>43</pages></Book>"#
+  );
+}
+
+#[test]
+fn accessors_named_after_methods() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "xml_schema/tests/accessors.xsd",
+    accessors,
+    validating_constructors
+  )]
+  struct AccessorsSchema;
+
+  let xml_1 = r#"<Task><new>true</new><validate>schema</validate><step>parse</step></Task>"#;
+
+  let mut sample_1: xml_schema_types::Task = from_str(xml_1).unwrap();
+
+  assert!(*sample_1.get_new());
+  assert_eq!(sample_1.get_validate(), "schema");
+  assert_eq!(sample_1.step_list(), &vec!["parse".to_string()]);
+  assert_eq!(sample_1.validate(), Ok(()));
+
+  sample_1.set_new(false);
+
+  let model =
+    xml_schema_types::Task::new(false, "schema".to_string(), vec!["parse".to_string()]).unwrap();
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Task><new>false</new><validate>schema</validate><step>parse</step></Task>"#
+  );
+}
+
+#[test]
+fn accessors_named_after_choice_iterators() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/accessors.xsd", accessors)]
+  struct AccessorsSchema;
+
+  let xml_1 = r#"<Route><iter_walk>park</iter_walk><walk>north</walk><ride>east</ride><walk>south</walk></Route>"#;
+
+  let sample_1: xml_schema_types::Route = from_str(xml_1).unwrap();
+
+  assert_eq!(sample_1.get_iter_walk(), "park");
+  assert_eq!(
+    sample_1.iter_walk().collect::<Vec<_>>(),
+    vec!["north", "south"]
+  );
+  assert_eq!(sample_1.iter_ride().collect::<Vec<_>>(), vec!["east"]);
+}
@@ -0,0 +1,22 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+
+#[test]
+fn all_occurrences() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/all.xsd")]
+  struct AllSchema;
+
+  let xml_1 = r#"<Order><item>Pen</item><customer>Ferris</customer><item>Ink</item></Order>"#;
+
+  let sample_1: xml_schema_types::Order = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Order {
+    customer: "Ferris".to_string(),
+    item_list: vec!["Pen".to_string(), "Ink".to_string()],
+    note: None,
+  };
+
+  assert_eq!(sample_1, model);
+}
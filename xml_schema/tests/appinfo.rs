@@ -0,0 +1,28 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn appinfo_field_name() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/appinfo.xsd")]
+  struct AppinfoSchema;
+
+  let xml_1 = r#"<Record rr="AAAA"><ttl>3600</ttl></Record>"#;
+
+  let sample_1: xml_schema_types::Record = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Record {
+    time_to_live: 3600,
+    record_type: "AAAA".to_string(),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Record rr="AAAA"><ttl>3600</ttl></Record>"#
+  );
+}
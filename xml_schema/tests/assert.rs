@@ -0,0 +1,40 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+
+#[test]
+fn assert() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/assert.xsd")]
+  struct AssertSchema;
+
+  let valid: xml_schema_types::Range =
+    from_str(r#"<Range a="1" b="2"><label>first</label></Range>"#).unwrap();
+  assert_eq!(valid.validate(), Ok(()));
+
+  let inverted: xml_schema_types::Range =
+    from_str(r#"<Range a="3" b="2"><label>first</label></Range>"#).unwrap();
+  assert_eq!(
+    inverted.validate(),
+    Err(r#"Range does not satisfy the assertion "@a < @b""#.to_string())
+  );
+
+  let missing: xml_schema_types::Range =
+    from_str(r#"<Range a="3"><label>first</label></Range>"#).unwrap();
+  assert!(missing.validate().is_err());
+
+  let labelled: xml_schema_types::Range =
+    from_str(r#"<Range a="1" b="2"><label>none</label></Range>"#).unwrap();
+  assert_eq!(
+    labelled.validate(),
+    Err(r#"Range does not satisfy the assertion "label != 'none'""#.to_string())
+  );
+
+  let quoted: xml_schema_types::Range =
+    from_str(r#"<Range a="1" b="2"><label>this and that</label></Range>"#).unwrap();
+  assert!(quoted.validate().is_err());
+
+  let lowest: xml_schema_types::Range =
+    from_str(r#"<Range a="-11" b="2"><label>first</label></Range>"#).unwrap();
+  assert!(lowest.validate().is_err());
+}
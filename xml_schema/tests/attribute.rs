@@ -0,0 +1,175 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn attribute_inline_enumeration() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/attribute.xsd")]
+  struct AttributeSchema;
+
+  let xml_1 = r#"<Shape color="light-blue" />"#;
+
+  let sample_1: xml_schema_types::Shape = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Shape {
+    color: xml_schema_types::ShapeColor::LightBlue,
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Shape color="light-blue" />"#
+  );
+}
+
+#[test]
+fn attribute_enumeration() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/attribute.xsd")]
+  struct AttributeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?><Ticket status="open" previous="closed" severity="major" />"#;
+
+  let sample_1: xml_schema_types::Ticket = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Ticket {
+    status: xml_schema_types::TicketStatus::Open,
+    previous: Some(xml_schema_types::TicketStatus::Closed),
+    severity: Some(xml_schema_types::TicketSeverity::Major),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_1);
+
+  let xml_2 = r#"<Ticket status="closed" />"#;
+
+  let sample_2: xml_schema_types::Ticket = from_str(xml_2).unwrap();
+  assert_eq!(sample_2.status, xml_schema_types::TicketStatus::Closed);
+  assert_eq!(sample_2.previous, None);
+  assert_eq!(sample_2.severity, None);
+}
+
+#[test]
+fn attribute_idrefs() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/attribute.xsd")]
+  struct AttributeSchema;
+
+  let xml_1 = r#"<Reference id="ref" targets="a b c" />"#;
+
+  let sample_1: xml_schema_types::Reference = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Reference {
+    id: "ref".to_string(),
+    targets: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+  };
+
+  assert_eq!(sample_1, model);
+}
+
+#[test]
+fn attribute_declaration_order() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/attribute.xsd")]
+  struct AttributeSchema;
+
+  let model = xml_schema_types::Ordered {
+    zone: Some("north".to_string()),
+    alpha: Some("first".to_string()),
+    middle: Some(5),
+  };
+
+  let expected =
+    r#"<?xml version="1.0" encoding="UTF-8"?><Ordered zone="north" alpha="first" middle="5" />"#;
+
+  for _ in 0..10 {
+    assert_eq!(to_string(&model).unwrap(), expected);
+  }
+
+  let sample_1: xml_schema_types::Ordered =
+    from_str(r#"<Ordered middle="5" alpha="first" zone="north" />"#).unwrap();
+  assert_eq!(to_string(&sample_1).unwrap(), expected);
+}
+
+#[test]
+fn attribute_form() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/attribute_form.xsd", target_prefix = "sh")]
+  struct AttributeSchema;
+
+  let model = xml_schema_types::Shape {
+    color: "red".to_string(),
+    size: "large".to_string(),
+  };
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><sh:Shape xmlns:sh="http://example.com/ns/shape" sh:color="red" size="large" />"#
+  );
+
+  let sample_1: xml_schema_types::Shape = from_str(&data).unwrap();
+
+  assert_eq!(sample_1, model);
+}
+
+#[test]
+fn attribute_enumeration_default() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/attribute_enumeration_default.xsd")]
+  struct AttributeEnumerationDefaultSchema;
+
+  let model = xml_schema_types::Light::default();
+
+  assert_eq!(model.state, Some(xml_schema_types::LightState::Off));
+  assert_eq!(model.color, xml_schema_types::LightColor::WarmWhite);
+}
+
+#[test]
+fn attribute_only_types() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/attribute.xsd")]
+  struct AttributeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?><Point x="1" y="2" />"#;
+
+  let sample_1: xml_schema_types::Point = from_str(xml_1).unwrap();
+  assert_eq!(sample_1, xml_schema_types::Point { x: 1, y: 2 });
+  assert_eq!(to_string(&sample_1).unwrap(), xml_1);
+
+  // the inline type of a global element keeps its attributes
+  let xml_2 = r#"<?xml version="1.0" encoding="UTF-8"?><Marker a="1" b="2" />"#;
+
+  let sample_2: Marker = from_str(xml_2).unwrap();
+  assert_eq!(sample_2, Marker { a: 1, b: Some(2) });
+  assert_eq!(to_string(&sample_2).unwrap(), xml_2);
+}
+
+#[test]
+fn attribute_fixed_constant() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/attribute.xsd")]
+  struct AttributeSchema;
+
+  assert_eq!(xml_schema_types::Envelope::VERSION, "1.0");
+
+  let sample: xml_schema_types::Envelope = from_str(r#"<Envelope version="1.0" />"#).unwrap();
+
+  assert_eq!(
+    sample.version.as_deref(),
+    Some(xml_schema_types::Envelope::VERSION)
+  );
+}
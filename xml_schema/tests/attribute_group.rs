@@ -0,0 +1,59 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn default_attributes() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/attribute_group.xsd")]
+  struct AttributeGroupSchema;
+
+  let xml_1 = r#"<Paragraph id="intro" lang="en"><text>Hello</text></Paragraph>"#;
+
+  let sample_1: xml_schema_types::Paragraph = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Paragraph {
+    text: "Hello".to_string(),
+    id: Some("intro".to_string()),
+    lang: Some("en".to_string()),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Paragraph id="intro" lang="en"><text>Hello</text></Paragraph>"#
+  );
+
+  let model = xml_schema_types::Break { clear: Some(true) };
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Break clear="true" />"#
+  );
+}
+
+#[test]
+fn attribute_group_reference() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/attribute_group.xsd")]
+  struct AttributeGroupSchema;
+
+  let xml_1 = r#"<Image src="logo.png" width="20" height="10" id="logo" />"#;
+
+  let sample_1: xml_schema_types::Image = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Image {
+    src: "logo.png".to_string(),
+    width: 20,
+    height: 10,
+    id: Some("logo".to_string()),
+    lang: None,
+  };
+
+  assert_eq!(sample_1, model);
+}
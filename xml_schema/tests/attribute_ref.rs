@@ -0,0 +1,27 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn attribute_ref() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/attribute_ref.xsd", target_prefix = "doc")]
+  struct AttributeRefSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?><doc:Document xmlns:doc="http://example.com/doc" doc:version="2" doc:status="final" title="Guide" />"#;
+
+  let sample_1: xml_schema_types::Document = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Document {
+    version: 2,
+    status: Some(xml_schema_types::DocumentStatus::Final),
+    title: "Guide".to_string(),
+  };
+
+  assert_eq!(sample_1, model);
+  assert_eq!(xml_schema_types::Document::default().version, 1);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_1);
+}
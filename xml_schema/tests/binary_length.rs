@@ -0,0 +1,33 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+
+#[test]
+fn binary_length() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/binary_length.xsd")]
+  struct BinaryLengthSchema;
+
+  // 3 bytes, 4 base64 characters
+  assert!(xml_schema_types::Digest::new("AQID".to_string()).is_ok());
+  assert!(xml_schema_types::Digest::new("AQI=".to_string()).is_err());
+
+  // 4 bytes are accepted, 5 bytes exceed the limit even if the text is short
+  assert!(xml_schema_types::Thumbnail::new("AQIDBA==".to_string()).is_ok());
+  assert_eq!(
+    xml_schema_types::Thumbnail::new("AQIDBAU=".to_string()),
+    Err("\"AQIDBAU=\" must have a length of at most 4 bytes".to_string())
+  );
+  assert!(from_str::<xml_schema_types::Thumbnail>("<Thumbnail>AQIDBAU=</Thumbnail>").is_err());
+
+  assert!(xml_schema_types::Key::new("0a0b".to_string()).is_ok());
+  assert!(xml_schema_types::Key::new("0a".to_string()).is_err());
+
+  let sample_1: xml_schema_types::SmallBlob =
+    from_str(r#"<SmallBlob mime="image/png">AQIDBA==</SmallBlob>"#).unwrap();
+  assert_eq!(sample_1.validate(), Ok(()));
+
+  let sample_2: xml_schema_types::SmallBlob =
+    from_str(r#"<SmallBlob mime="image/png">AQIDBAU=</SmallBlob>"#).unwrap();
+  assert!(sample_2.validate().is_err());
+}
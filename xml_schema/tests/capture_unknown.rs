@@ -0,0 +1,77 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn capture_unknown_elements() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/sequence.xsd", capture_unknown)]
+  struct SequenceSchema;
+
+  let xml = r#"
+  <Letter>
+    <subject>Hello</subject>
+    <priority level="high">urgent</priority>
+    <body>Hi</body>
+  </Letter>
+  "#;
+
+  let sample: xml_schema_types::Letter = from_str(xml).unwrap();
+
+  assert_eq!(sample.subject, "Hello");
+  assert_eq!(sample.cc, None);
+  assert_eq!(sample.body, "Hi");
+  assert_eq!(
+    sample.unknown,
+    vec![r#"<priority level="high">urgent</priority>"#.to_string()]
+  );
+
+  let data = to_string(&sample).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Letter><subject>Hello</subject><body>Hi</body></Letter>"#
+  );
+}
+
+#[test]
+fn capture_unknown_elements_around_choices() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/choice.xsd", capture_unknown)]
+  struct ChoiceSchema;
+
+  let xml_1 = r#"
+  <Drawing>
+    <title>Sun</title>
+    <layer>sky</layer>
+    <circle radius="3"/>
+  </Drawing>
+  "#;
+
+  let sample_1: xml_schema_types::Drawing = from_str(xml_1).unwrap();
+
+  assert_eq!(sample_1.title, "Sun");
+  assert_eq!(
+    sample_1.choice,
+    xml_schema_types::DrawingChoice::Circle(xml_schema_types::Circle {
+      radius: Some(3),
+      unknown: vec![],
+    })
+  );
+  assert_eq!(sample_1.unknown, vec!["<layer>sky</layer>".to_string()]);
+
+  let xml_2 = r#"
+  <Canvas>
+    <name>Notes</name>
+    <text>first</text>
+    <layer>sky</layer>
+    <circle radius="1"/>
+  </Canvas>
+  "#;
+
+  let sample_2: xml_schema_types::Canvas = from_str(xml_2).unwrap();
+
+  assert_eq!(sample_2.choice_list.len(), 2);
+  assert_eq!(sample_2.unknown, vec!["<layer>sky</layer>".to_string()]);
+}
@@ -0,0 +1,177 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn choice_in_sequence() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/choice.xsd")]
+  struct ChoiceSchema;
+
+  let xml = r#"<?xml version="1.0" encoding="UTF-8"?><Drawing><title>Sketch</title><square side="3" /></Drawing>"#;
+
+  let sample: xml_schema_types::Drawing = from_str(xml).unwrap();
+
+  let model = xml_schema_types::Drawing {
+    title: "Sketch".to_string(),
+    choice: xml_schema_types::DrawingChoice::Square(xml_schema_types::Square { side: Some(3) }),
+  };
+
+  assert_eq!(sample, model);
+  assert_eq!(to_string(&model).unwrap(), xml);
+
+  let xml = r#"<?xml version="1.0" encoding="UTF-8"?><Drawing><title>Sketch</title><label>Empty</label></Drawing>"#;
+
+  let sample: xml_schema_types::Drawing = from_str(xml).unwrap();
+  assert_eq!(
+    sample.choice,
+    xml_schema_types::DrawingChoice::Label("Empty".to_string())
+  );
+  assert_eq!(to_string(&sample).unwrap(), xml);
+
+  // one of the elements of the choice is required
+  assert!(
+    from_str::<xml_schema_types::Drawing>("<Drawing><title>Sketch</title></Drawing>").is_err()
+  );
+}
+
+#[test]
+fn choice_content() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/choice.xsd")]
+  struct ChoiceSchema;
+
+  let xml = r#"<?xml version="1.0" encoding="UTF-8"?><Shape><circle radius="2" /></Shape>"#;
+
+  let sample: xml_schema_types::Shape = from_str(xml).unwrap();
+
+  let model = xml_schema_types::Shape {
+    choice: xml_schema_types::ShapeChoice::Circle(xml_schema_types::Circle { radius: Some(2) }),
+  };
+
+  assert_eq!(sample, model);
+  assert_eq!(to_string(&model).unwrap(), xml);
+}
+
+#[test]
+fn optional_choice() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/choice.xsd")]
+  struct ChoiceSchema;
+
+  let sample: xml_schema_types::Frame =
+    from_str("<Frame><color>red</color><circle radius=\"2\" /></Frame>").unwrap();
+  assert_eq!(
+    sample.choice,
+    Some(xml_schema_types::FrameChoice::Circle(
+      xml_schema_types::Circle { radius: Some(2) }
+    ))
+  );
+
+  let sample: xml_schema_types::Frame = from_str("<Frame><color>red</color></Frame>").unwrap();
+  assert_eq!(sample.choice, None);
+  assert_eq!(
+    to_string(&sample).unwrap(),
+    r#"<?xml version="1.0" encoding="UTF-8"?><Frame><color>red</color></Frame>"#
+  );
+}
+
+#[test]
+fn repeated_choice() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/choice.xsd")]
+  struct ChoiceSchema;
+
+  let xml = r#"<?xml version="1.0" encoding="UTF-8"?><Canvas><name>Board</name><text>first</text><circle radius="1" /><text>second</text></Canvas>"#;
+
+  let sample: xml_schema_types::Canvas = from_str(xml).unwrap();
+
+  let model = xml_schema_types::Canvas {
+    name: "Board".to_string(),
+    choice_list: vec![
+      xml_schema_types::CanvasChoice::Text("first".to_string()),
+      xml_schema_types::CanvasChoice::Circle(xml_schema_types::Circle { radius: Some(1) }),
+      xml_schema_types::CanvasChoice::Text("second".to_string()),
+    ],
+  };
+
+  assert_eq!(sample, model);
+  assert_eq!(to_string(&model).unwrap(), xml);
+}
+
+#[test]
+fn inline_type_choice() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/choice.xsd")]
+  struct ChoiceSchema;
+
+  let xml = r#"<?xml version="1.0" encoding="UTF-8"?><Picture><caption>Sunset</caption></Picture>"#;
+
+  let sample: Picture = from_str(xml).unwrap();
+  assert_eq!(sample.choice, PictureChoice::Caption("Sunset".to_string()));
+  assert_eq!(to_string(&sample).unwrap(), xml);
+}
+
+#[test]
+fn choice_variant_name() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/choice.xsd")]
+  struct ChoiceSchema;
+
+  let circle = xml_schema_types::DrawingChoice::Circle(xml_schema_types::Circle { radius: None });
+  let square = xml_schema_types::DrawingChoice::Square(xml_schema_types::Square { side: None });
+  let label = xml_schema_types::DrawingChoice::Label("Empty".to_string());
+
+  assert_eq!(circle.variant_name(), "circle");
+  assert_eq!(square.variant_name(), "square");
+  assert_eq!(label.variant_name(), "label");
+}
+
+#[test]
+fn repeated_choice_iterators() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/choice.xsd")]
+  struct ChoiceSchema;
+
+  let sample: xml_schema_types::Canvas = from_str(
+    r#"<Canvas><name>Board</name><text>first</text><circle radius="1" /><text>second</text></Canvas>"#,
+  )
+  .unwrap();
+
+  let texts: Vec<&String> = sample.iter_text().collect();
+  assert_eq!(texts, vec!["first", "second"]);
+
+  let circles: Vec<&xml_schema_types::Circle> = sample.iter_circle().collect();
+  assert_eq!(circles, vec![&xml_schema_types::Circle { radius: Some(1) }]);
+}
+
+#[test]
+fn recursive_choice() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/choice.xsd")]
+  struct ChoiceSchema;
+
+  let xml = r#"<?xml version="1.0" encoding="UTF-8"?><Expression><negate><negate><number>3</number></negate></negate></Expression>"#;
+
+  let sample: xml_schema_types::Expression = from_str(xml).unwrap();
+
+  // the variant holding the enclosing type is boxed
+  let model = xml_schema_types::Expression {
+    choice: xml_schema_types::ExpressionChoice::Negate(Box::new(xml_schema_types::Expression {
+      choice: xml_schema_types::ExpressionChoice::Negate(Box::new(xml_schema_types::Expression {
+        choice: xml_schema_types::ExpressionChoice::Number(3),
+      })),
+    })),
+  };
+
+  assert_eq!(sample, model);
+  assert_eq!(to_string(&model).unwrap(), xml);
+}
@@ -0,0 +1,180 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+static XML: &str = r#"
+  <Derived>
+    <id>identifier</id>
+    <label>Label content</label>
+  </Derived>
+  "#;
+
+#[test]
+fn complex_content_inline() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/complex_content.xsd")]
+  struct ComplexContentSchema;
+
+  let sample: xml_schema_types::Derived = from_str(XML).unwrap();
+
+  let model = xml_schema_types::Derived {
+    id: "identifier".to_string(),
+    label: "Label content".to_string(),
+  };
+
+  assert_eq!(sample, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Derived><id>identifier</id><label>Label content</label></Derived>"#
+  );
+}
+
+#[test]
+fn complex_content_flatten() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/complex_content.xsd", inherit = "flatten")]
+  struct ComplexContentSchema;
+
+  let sample: xml_schema_types::Derived = from_str(XML).unwrap();
+
+  let model = xml_schema_types::Derived {
+    base: xml_schema_types::Base {
+      id: "identifier".to_string(),
+    },
+    label: "Label content".to_string(),
+  };
+
+  assert_eq!(sample, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Derived><id>identifier</id><label>Label content</label></Derived>"#
+  );
+}
+
+#[test]
+fn complex_content_conversions() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/complex_content.xsd")]
+  struct ComplexContentSchema;
+
+  let derived = xml_schema_types::Derived {
+    id: "identifier".to_string(),
+    label: "Label content".to_string(),
+  };
+
+  let base: xml_schema_types::Base = derived.into();
+  assert_eq!(
+    base,
+    xml_schema_types::Base {
+      id: "identifier".to_string(),
+    }
+  );
+
+  let specialized = xml_schema_types::Specialized {
+    id: "identifier".to_string(),
+    label: "Label content".to_string(),
+    note: "Note content".to_string(),
+  };
+
+  let derived: xml_schema_types::Derived = specialized.clone().into();
+  assert_eq!(
+    derived,
+    xml_schema_types::Derived {
+      id: "identifier".to_string(),
+      label: "Label content".to_string(),
+    }
+  );
+
+  let base: xml_schema_types::Base = specialized.into();
+  assert_eq!(base.id, "identifier");
+}
+
+#[test]
+fn complex_content_restriction() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/complex_content.xsd")]
+  struct ComplexContentSchema;
+
+  // the restriction leaves out the phone of its base
+  let sample_1: xml_schema_types::EmailContact =
+    from_str("<EmailContact><email>user@example.com</email></EmailContact>").unwrap();
+  assert_eq!(
+    sample_1,
+    xml_schema_types::EmailContact {
+      email: Some("user@example.com".to_string()),
+    }
+  );
+
+  // the restriction removes the email with maxOccurs="0"
+  let sample_2: xml_schema_types::PhoneContact =
+    from_str("<PhoneContact><phone>0123</phone></PhoneContact>").unwrap();
+  assert_eq!(
+    sample_2,
+    xml_schema_types::PhoneContact {
+      phone: Some("0123".to_string()),
+    }
+  );
+}
+
+#[test]
+fn complex_content_restriction_narrowed_type() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/complex_content.xsd")]
+  struct ComplexContentSchema;
+
+  // the restriction narrows the resident of the shelter to a dog
+  let sample: xml_schema_types::Kennel =
+    from_str("<Kennel><resident><name>Rex</name><breed>Beagle</breed></resident></Kennel>")
+      .unwrap();
+
+  let resident: xml_schema_types::Dog = sample.resident;
+  assert_eq!(
+    resident,
+    xml_schema_types::Dog {
+      name: "Rex".to_string(),
+      breed: "Beagle".to_string(),
+    }
+  );
+}
+
+#[test]
+fn complex_content_down_cast() {
+  use std::convert::TryFrom;
+
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/complex_content.xsd", capture_unknown)]
+  struct ComplexContentSchema;
+
+  // a derived document read as its base keeps the elements of the extension as unknown
+  let base: xml_schema_types::Base = from_str(XML).unwrap();
+  assert_eq!(
+    base.unknown,
+    vec!["<label>Label content</label>".to_string()]
+  );
+
+  let derived = xml_schema_types::Derived::try_from(base).unwrap();
+  assert_eq!(
+    derived,
+    xml_schema_types::Derived {
+      id: "identifier".to_string(),
+      label: "Label content".to_string(),
+      unknown: vec![],
+    }
+  );
+
+  let base: xml_schema_types::Base = from_str("<Base><id>identifier</id></Base>").unwrap();
+  assert_eq!(
+    xml_schema_types::Derived::try_from(base),
+    Err("Base is not a Derived, the element label is missing".to_string())
+  );
+}
@@ -4,6 +4,7 @@ use yaserde::ser::to_string;
 
 #[test]
 fn complex_type_string() {
+  #[allow(dead_code)]
   #[derive(Debug, XmlSchema)]
   #[xml_schema(source = "xml_schema/tests/complex_type.xsd")]
   struct ComplexTypeSchema;
@@ -27,6 +28,62 @@ fn complex_type_string() {
   let data = to_string(&model).unwrap();
   assert_eq!(
     data,
-    r#"<?xml version="1.0" encoding="utf-8"?><ComplexListOfElements><Annotation>Test content</Annotation><Label>Label content</Label></ComplexListOfElements>"#
+    r#"<?xml version="1.0" encoding="UTF-8"?><ComplexListOfElements><Annotation>Test content</Annotation><Label>Label content</Label></ComplexListOfElements>"#
   );
 }
+
+#[test]
+fn complex_type_default() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/complex_type_default.xsd")]
+  struct ComplexTypeSchema;
+
+  let model = xml_schema_types::Settings::default();
+
+  assert_eq!(model.retries, 7);
+  assert_eq!(model.label, "");
+  assert!(model.enabled);
+  assert_eq!(model.ratio, f64::INFINITY);
+  assert_eq!(model.mode, Some("auto".to_string()));
+  assert_eq!(model.threshold, Some("+2.50".to_string()));
+  assert_eq!(model.timeout, Some(30));
+  assert_eq!(model.comment, None);
+}
+
+#[test]
+fn complex_type_optional_default() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/complex_type_default.xsd")]
+  struct ComplexTypeSchema;
+
+  // an absent optional element takes its default, without default it stays `None`
+  let sample_1: xml_schema_types::Settings =
+    from_str("<Settings><label>text</label></Settings>").unwrap();
+  assert_eq!(sample_1.timeout, Some(30));
+  assert_eq!(sample_1.comment, None);
+
+  let sample_2: xml_schema_types::Settings =
+    from_str("<Settings><label>text</label><timeout>5</timeout></Settings>").unwrap();
+  assert_eq!(sample_2.timeout, Some(5));
+}
+
+#[test]
+fn complex_type_list_default() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/complex_type_default.xsd")]
+  struct ComplexTypeSchema;
+
+  let model = xml_schema_types::Settings::default();
+  assert_eq!(model.weights, Some(vec![1, 2].into()));
+
+  let sample_1: xml_schema_types::Settings =
+    from_str("<Settings><label>text</label></Settings>").unwrap();
+  assert_eq!(sample_1.weights.as_deref(), Some(&vec![1, 2]));
+
+  let sample_2: xml_schema_types::Settings =
+    from_str("<Settings><label>text</label><weights>3 4 5</weights></Settings>").unwrap();
+  assert_eq!(sample_2.weights.as_deref(), Some(&vec![3, 4, 5]));
+}
@@ -0,0 +1,56 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn default_namespace() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/default_namespace.xsd")]
+  struct DefaultNamespaceSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?><Book xmlns="http://example.com/library"><title>Rust</title><author><name>Ferris</name></author></Book>"#;
+
+  let sample_1: xml_schema_types::Book = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Book {
+    title: "Rust".to_string(),
+    author: xml_schema_types::Author {
+      name: "Ferris".to_string(),
+    },
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_1);
+
+  let other_namespace = r#"<Book xmlns="http://example.com/other"><title>Rust</title><author><name>Ferris</name></author></Book>"#;
+  assert!(from_str::<xml_schema_types::Book>(other_namespace).is_err());
+}
+
+#[test]
+fn default_namespace_document() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/default_namespace.xsd")]
+  struct DefaultNamespaceSchema;
+
+  let model = Shelf {
+    content: xml_schema_types::Book {
+      title: "Rust".to_string(),
+      author: xml_schema_types::Author {
+        name: "Ferris".to_string(),
+      },
+    },
+  };
+
+  let data = model.to_xml_string().unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><shelf xmlns="http://example.com/library"><title>Rust</title><author><name>Ferris</name></author></shelf>"#
+  );
+
+  let sample_1: Shelf = data.parse().unwrap();
+  assert_eq!(sample_1, model);
+}
@@ -0,0 +1,32 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn unprefixed_built_in_types() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/default_xml_schema_namespace.xsd")]
+  struct DefaultXmlSchemaNamespaceSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?><Measure precision="2"><value>42</value><label>width</label></Measure>"#;
+
+  let sample_1: xml_schema_types::Measure = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Measure {
+    value: 42_i32,
+    label: "width".to_string(),
+    precision: Some(2_i32),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_1);
+
+  let xml_2 = r#"<Sample><measure><value>7</value><label>depth</label></measure></Sample>"#;
+
+  let sample_2: xml_schema_types::Sample = from_str(xml_2).unwrap();
+
+  assert_eq!(sample_2.measure.value, 7);
+}
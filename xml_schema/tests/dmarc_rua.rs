@@ -3,6 +3,7 @@ use yaserde::de::from_str;
 
 #[test]
 fn dmarc_rua_string() {
+  #[allow(dead_code)]
   #[derive(Debug, XmlSchema)]
   #[xml_schema(source = "xml_schema/tests/dmarc_rua.xsd", target_prefix = "dmarc")]
   struct DmarcRuaSchema;
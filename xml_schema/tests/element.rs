@@ -0,0 +1,109 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn element_fixed() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/element.xsd")]
+  struct ElementSchema;
+
+  let xml_1 = r#"
+  <Document>
+    <version>1.0</version>
+    <title>Title content</title>
+  </Document>
+  "#;
+
+  let sample_1: xml_schema_types::Document = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Document {
+    version: xml_schema_types::DocumentVersion::default(),
+    title: "Title content".to_string(),
+  };
+
+  assert_eq!(sample_1, model);
+  assert_eq!(sample_1.version.content, "1.0");
+  assert_eq!(xml_schema_types::Document::VERSION, "1.0");
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Document><version>1.0</version><title>Title content</title></Document>"#
+  );
+
+  let xml_2 = r#"
+  <Document>
+    <version>2.0</version>
+    <title>Title content</title>
+  </Document>
+  "#;
+
+  assert!(from_str::<xml_schema_types::Document>(xml_2).is_err());
+
+  let xml_3 = r#"
+  <Document>
+    <title>Title content</title>
+  </Document>
+  "#;
+
+  let sample_3: xml_schema_types::Document = from_str(xml_3).unwrap();
+  assert_eq!(sample_3.version.content, "1.0");
+}
+
+#[test]
+fn element_inline_restriction() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/element_restriction.xsd")]
+  struct ElementRestrictionSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?><Product><code>AB12</code><size>large</size></Product>"#;
+
+  let sample_1: xml_schema_types::Product = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Product {
+    code: xml_schema_types::ProductCode::new("AB12".to_string()).unwrap(),
+    size: xml_schema_types::ProductSize::Large,
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_1);
+
+  assert_eq!(
+    xml_schema_types::ProductCode::new("ABC123".to_string()),
+    Err(r#""ABC123" must have a length of at most 4"#.to_string())
+  );
+
+  let xml_2 = r#"<Product><code>ABC123</code><size>small</size></Product>"#;
+  assert!(from_str::<xml_schema_types::Product>(xml_2).is_err());
+}
+
+#[test]
+fn element_from_str() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/element.xsd")]
+  struct ElementSchema;
+
+  let sample_1: Report = "<Report><author>Ada</author><pages>12</pages></Report>"
+    .parse()
+    .unwrap();
+
+  let model = Report {
+    author: "Ada".to_string(),
+    pages: 12,
+  };
+
+  assert_eq!(sample_1, model);
+
+  assert!("<Report><author>Ada</author>".parse::<Report>().is_err());
+
+  // generated even when there is nothing to check
+  let sample_2 =
+    Report::from_xml_validated("<Report><author>Ada</author><pages>12</pages></Report>").unwrap();
+  assert_eq!(sample_2, model);
+}
@@ -0,0 +1,48 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn element_ref_occurrences() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/element_ref.xsd")]
+  struct ElementRefSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?><Album><Track>Intro</Track><Track>Outro</Track></Album>"#;
+
+  let sample_1: xml_schema_types::Album = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Album {
+    track_list: vec!["Intro".to_string(), "Outro".to_string()],
+    cover: None,
+    credit_list: vec![],
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_1);
+
+  let xml_2 = r#"<?xml version="1.0" encoding="UTF-8"?><Album><Track>Intro</Track><Cover>front.png</Cover><Credit><artist>Alice</artist></Credit><Credit><artist>Bob</artist></Credit></Album>"#;
+
+  let sample_2: xml_schema_types::Album = from_str(xml_2).unwrap();
+
+  let model = xml_schema_types::Album {
+    track_list: vec!["Intro".to_string()],
+    cover: Some("front.png".to_string()),
+    credit_list: vec![
+      Credit {
+        artist: "Alice".to_string(),
+      },
+      Credit {
+        artist: "Bob".to_string(),
+      },
+    ],
+  };
+
+  assert_eq!(sample_2, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_2);
+}
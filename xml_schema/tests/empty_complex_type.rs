@@ -0,0 +1,38 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn empty_complex_type() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/empty_complex_type.xsd")]
+  struct EmptyComplexTypeSchema;
+
+  let sample_1: xml_schema_types::Foo = from_str("<Foo/>").unwrap();
+  assert_eq!(sample_1, xml_schema_types::Foo {});
+
+  let data = to_string(&sample_1).unwrap();
+  assert_eq!(data, r#"<?xml version="1.0" encoding="UTF-8"?><Foo />"#);
+
+  let xml_2 = r#"<Flagged><name>item</name><marker/></Flagged>"#;
+  let sample_2: xml_schema_types::Flagged = from_str(xml_2).unwrap();
+
+  let model = xml_schema_types::Flagged {
+    name: "item".to_string(),
+    marker: Some(xml_schema_types::Foo {}),
+    flag: None,
+  };
+
+  assert_eq!(sample_2, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Flagged><name>item</name><marker /></Flagged>"#
+  );
+
+  let sample_3: xml_schema_types::Flagged =
+    from_str("<Flagged><name>item</name></Flagged>").unwrap();
+  assert_eq!(sample_3.marker, None);
+}
@@ -0,0 +1,65 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+const CURRENCIES: &[&str] = &[
+  "INR", "EUR", "CAD", "USD", "BGN", "DKK", "PEN", "GBP", "ILS", "THB", "COP", "AUD", "ARS", "MXN",
+  "CZK", "SAR", "NZD", "RON", "JPY", "PHP", "TWD", "ISK", "EGP", "NOK", "MYR", "RUB", "ZAR", "KRW",
+  "CHF", "CLP", "IDR", "HUF", "SGD", "AED", "BRL", "PLN", "MAD", "CNY", "SEK", "TRY", "HKD",
+];
+
+#[test]
+fn enumeration_lookup() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/enumeration_lookup.xsd")]
+  struct EnumerationLookupSchema;
+
+  for value in CURRENCIES.iter().chain(&["", "usd", "XXX", "ZZZ", "AAA"]) {
+    let parsed = value.parse::<xml_schema_types::Currency>();
+    let scanned = CURRENCIES.iter().find(|currency| *currency == value);
+
+    assert_eq!(
+      parsed.as_ref().ok().map(|currency| currency.as_str()),
+      scanned.copied()
+    );
+  }
+
+  assert_eq!(
+    "CHF".parse::<xml_schema_types::Currency>(),
+    Ok(xml_schema_types::Currency::Chf)
+  );
+  assert_eq!(
+    "XXX".parse::<xml_schema_types::Currency>(),
+    Err("\"XXX\" is not a value of Currency".to_string())
+  );
+  assert_eq!(
+    xml_schema_types::Currency::default(),
+    xml_schema_types::Currency::Inr
+  );
+}
+
+#[test]
+fn enumeration_lookup_fields() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/enumeration_lookup.xsd")]
+  struct EnumerationLookupSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?><Payment settlement="EUR"><currency>JPY</currency></Payment>"#;
+
+  let sample_1: xml_schema_types::Payment = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Payment {
+    currency: xml_schema_types::Currency::Jpy,
+    settlement: Some(xml_schema_types::Currency::Eur),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_1);
+
+  let xml_2 = r#"<Payment><currency>XXX</currency></Payment>"#;
+  assert!(from_str::<xml_schema_types::Payment>(xml_2).is_err());
+}
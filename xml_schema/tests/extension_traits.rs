@@ -0,0 +1,70 @@
+use xml_schema_derive::XmlSchema;
+
+#[test]
+fn extension_traits() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/complex_content.xsd", extension_traits)]
+  struct ComplexContentSchema;
+
+  use xml_schema_types::{BaseTrait, DerivedTrait};
+
+  let derived = xml_schema_types::Derived {
+    id: "derived".to_string(),
+    label: "Label".to_string(),
+  };
+
+  let specialized = xml_schema_types::Specialized {
+    id: "specialized".to_string(),
+    label: "Label".to_string(),
+    note: "Note".to_string(),
+  };
+
+  assert_eq!(
+    specialized.to_derived(),
+    xml_schema_types::Derived {
+      id: "specialized".to_string(),
+      label: "Label".to_string(),
+    }
+  );
+
+  let family: Vec<Box<dyn BaseTrait>> = vec![
+    xml_schema_types::Base {
+      id: "base".to_string(),
+    }
+    .into_boxed_base(),
+    derived.into_boxed_base(),
+    Box::new(specialized),
+  ];
+
+  let ids: Vec<String> = family.iter().map(|value| value.to_base().id).collect();
+  assert_eq!(ids, vec!["base", "derived", "specialized"]);
+}
+
+#[test]
+fn extension_traits_flatten() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "xml_schema/tests/complex_content.xsd",
+    inherit = "flatten",
+    extension_traits
+  )]
+  struct ComplexContentSchema;
+
+  use xml_schema_types::BaseTrait;
+
+  let specialized = xml_schema_types::Specialized {
+    base: xml_schema_types::Derived {
+      base: xml_schema_types::Base {
+        id: "specialized".to_string(),
+      },
+      label: "Label".to_string(),
+    },
+    note: "Note".to_string(),
+  };
+
+  let family: Vec<Box<dyn BaseTrait>> = vec![specialized.into_boxed_base()];
+
+  assert_eq!(family[0].to_base().id, "specialized");
+}
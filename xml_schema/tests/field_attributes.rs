@@ -0,0 +1,27 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn field_attributes() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "xml_schema/tests/field_attributes.xsd",
+    field_attributes("Vec" = "#[yaserde(skip_serializing)]")
+  )]
+  struct FieldAttributesSchema;
+
+  let xml_1 =
+    r#"<?xml version="1.0" encoding="UTF-8"?><Book><title>Dune</title><tag>novel</tag></Book>"#;
+
+  let sample_1: xml_schema_types::Book = from_str(xml_1).unwrap();
+  assert_eq!(sample_1.tag_list, vec!["novel".to_string()]);
+
+  // the repeated elements are skipped by the added attribute
+  let data = to_string(&sample_1).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Book><title>Dune</title></Book>"#
+  );
+}
@@ -0,0 +1,70 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+
+#[test]
+fn identity_constraint() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/identity_constraint.xsd")]
+  struct IdentityConstraintSchema;
+
+  let valid: Library = from_str(
+    r#"
+    <library>
+      <books>
+        <book id="b1"><title>First</title></book>
+        <book id="b2"/>
+        <book id="b3"/>
+      </books>
+      <loan book="b2"/>
+    </library>
+    "#,
+  )
+  .unwrap();
+  assert_eq!(valid.validate_identity(), Ok(()));
+
+  let duplicate_key: Library = from_str(
+    r#"
+    <library>
+      <books>
+        <book id="b1"/>
+        <book id="b1"/>
+      </books>
+    </library>
+    "#,
+  )
+  .unwrap();
+  assert_eq!(
+    duplicate_key.validate_identity(),
+    Err(r#"Duplicate value ["b1"] for bookId"#.to_string())
+  );
+
+  let duplicate_unique: Library = from_str(
+    r#"
+    <library>
+      <books>
+        <book id="b1"><title>First</title></book>
+        <book id="b2"><title>First</title></book>
+      </books>
+    </library>
+    "#,
+  )
+  .unwrap();
+  assert!(duplicate_unique.validate_identity().is_err());
+
+  let dangling_keyref: Library = from_str(
+    r#"
+    <library>
+      <books>
+        <book id="b1"/>
+      </books>
+      <loan book="b2"/>
+    </library>
+    "#,
+  )
+  .unwrap();
+  assert_eq!(
+    dangling_keyref.validate_identity(),
+    Err(r#"Value ["b2"] of loanBook does not refer to any bookId"#.to_string())
+  );
+}
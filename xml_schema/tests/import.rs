@@ -0,0 +1,69 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+mod signature {
+  use xml_schema_derive::XmlSchema;
+
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "xml_schema/tests/import_signature.xsd",
+    prefix("http://example.com/ns/signature" = "sig")
+  )]
+  pub struct SignatureSchema;
+}
+
+#[test]
+fn imported_element_reference() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "xml_schema/tests/import.xsd",
+    module_namespace_mappings = "http://example.com/ns/signature: crate::signature::xml_schema_types"
+  )]
+  struct ImportSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?><Document xmlns:sig="http://example.com/ns/signature"><title>Contract</title><sig:Signature><sig:value>c2lnbmVk</sig:value></sig:Signature></Document>"#;
+
+  let sample_1: xml_schema_types::Document = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Document {
+    title: "Contract".to_string(),
+    signature: signature::xml_schema_types::SignatureType {
+      value: "c2lnbmVk".to_string(),
+    },
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_1);
+}
+
+#[test]
+fn imported_prefix_collision() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "xml_schema/tests/import_prefix.xsd",
+    prefix("http://example.com/ns/author" = "ns")
+  )]
+  struct ImportPrefixSchema;
+
+  // the author namespace is configured with the prefix the schema binds to the book namespace
+  let model = xml_schema_types::Citation {
+    name: "Jane Austen".to_string(),
+    title: "Emma".to_string(),
+  };
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Citation xmlns:ns="http://example.com/ns/author" xmlns:ns1="http://example.com/ns/book"><ns:Name>Jane Austen</ns:Name><ns1:Title>Emma</ns1:Title></Citation>"#
+  );
+
+  let sample: xml_schema_types::Citation = from_str(&data).unwrap();
+
+  assert_eq!(sample, model);
+}
@@ -0,0 +1,40 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn included_form_default() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "xml_schema/tests/include.xsd",
+    prefix("http://example.com/ns/shop" = "shop")
+  )]
+  struct IncludeSchema;
+
+  let model = xml_schema_types::Customer {
+    name: "Alice".to_string(),
+    address: xml_schema_types::Address {
+      city: "Paris".to_string(),
+    },
+  };
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><shop:Customer xmlns:shop="http://example.com/ns/shop"><shop:name>Alice</shop:name><shop:address><city>Paris</city></shop:address></shop:Customer>"#
+  );
+
+  let xml_1 = r#"
+  <s:Customer xmlns:s="http://example.com/ns/shop">
+    <s:name>Alice</s:name>
+    <s:address>
+      <city>Paris</city>
+    </s:address>
+  </s:Customer>
+  "#;
+
+  let sample_1: xml_schema_types::Customer = from_str(xml_1).unwrap();
+
+  assert_eq!(sample_1, model);
+}
@@ -0,0 +1,24 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+
+#[test]
+fn iterators() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/iterators.xsd", iterators)]
+  struct IteratorsSchema;
+
+  let xml_1 =
+    r#"<Book><chapter title="One" /><chapter title="Two" /><author>Ferris</author></Book>"#;
+
+  let sample_1: xml_schema_types::Book = from_str(xml_1).unwrap();
+
+  let titles: Vec<&str> = sample_1
+    .iter_chapter_list()
+    .map(|chapter| chapter.title.as_str())
+    .collect();
+  assert_eq!(titles, vec!["One", "Two"]);
+
+  let authors: Vec<&String> = sample_1.iter_author_list().collect();
+  assert_eq!(authors, vec!["Ferris"]);
+}
@@ -0,0 +1,42 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn list_wrapper() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/list_wrapper.xsd", list_wrappers)]
+  struct ListWrapperSchema;
+
+  let xml_1 = r#"
+  <Order>
+    <items>
+      <item>first</item>
+      <item>second</item>
+    </items>
+    <notes>
+      <note><text>Note content</text></note>
+    </notes>
+  </Order>
+  "#;
+
+  let sample_1: xml_schema_types::Order = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Order {
+    items: vec!["first".to_string(), "second".to_string()].into(),
+    notes: vec![xml_schema_types::Note {
+      text: "Note content".to_string(),
+    }]
+    .into(),
+  };
+
+  assert_eq!(sample_1, model);
+  assert_eq!(sample_1.items.len(), 2);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Order><items><item>first</item><item>second</item></items><notes><note><text>Note content</text></note></notes></Order>"#
+  );
+}
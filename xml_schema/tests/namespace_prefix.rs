@@ -0,0 +1,66 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn configured_namespace_prefix() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "xml_schema/tests/namespace_prefix.xsd",
+    prefix("http://example.com/ns/item" = "item")
+  )]
+  struct NamespacePrefixSchema;
+
+  let model = xml_schema_types::Item {
+    label: "Label content".to_string(),
+  };
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><item:Item xmlns:item="http://example.com/ns/item"><item:label>Label content</item:label></item:Item>"#
+  );
+
+  let xml_1 = r#"
+  <i:Item xmlns:i="http://example.com/ns/item">
+    <i:label>Label content</i:label>
+  </i:Item>
+  "#;
+
+  let sample_1: xml_schema_types::Item = from_str(xml_1).unwrap();
+
+  assert_eq!(sample_1, model);
+}
+
+#[test]
+fn prefixed_namespace_document() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "xml_schema/tests/namespace_prefix.xsd",
+    prefix("http://example.com/ns/item" = "item")
+  )]
+  struct NamespacePrefixSchema;
+
+  let model = Item {
+    content: xml_schema_types::Item {
+      label: "Label content".to_string(),
+    },
+  };
+
+  let data = model.to_xml_string().unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><item:item xmlns:item="http://example.com/ns/item"><item:label>Label content</item:label></item:item>"#
+  );
+
+  let sample_1: Item = data.parse().unwrap();
+  assert_eq!(sample_1, model);
+
+  let xml_1 =
+    r#"<i:item xmlns:i="http://example.com/ns/item"><i:label>Label content</i:label></i:item>"#;
+
+  let sample_2: Item = xml_1.parse().unwrap();
+  assert_eq!(sample_2, model);
+}
@@ -0,0 +1,50 @@
+use xml_schema::Nillable;
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn optional_nillable_element() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/nillable.xsd")]
+  struct NillableSchema;
+
+  let xml_1 = r#"<Offer><product>tea</product><discount>5</discount></Offer>"#;
+
+  let sample_1: xml_schema_types::Offer = from_str(xml_1).unwrap();
+
+  assert_eq!(sample_1.price, None);
+  assert_eq!(sample_1.discount, Nillable::Value(5));
+
+  let xml_2 = r#"<Offer xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance"><product>tea</product><price xsi:nil="true"/><discount xsi:nil="true"></discount></Offer>"#;
+
+  let sample_2: xml_schema_types::Offer = from_str(xml_2).unwrap();
+
+  assert_eq!(sample_2.price, Some(Nillable::Nil));
+  assert_eq!(sample_2.discount, Nillable::Nil);
+
+  let xml_3 = r#"<Offer><product>tea</product><price>12</price><discount>5</discount></Offer>"#;
+
+  let sample_3: xml_schema_types::Offer = from_str(xml_3).unwrap();
+
+  assert_eq!(sample_3.price, Some(Nillable::Value(12)));
+
+  for sample in [sample_1, sample_2, sample_3] {
+    let data = to_string(&sample).unwrap();
+    let parsed: xml_schema_types::Offer = from_str(&data).unwrap();
+    assert_eq!(parsed, sample);
+  }
+
+  let model = xml_schema_types::Offer {
+    product: "tea".to_string(),
+    price: Some(Nillable::Nil),
+    discount: Nillable::Value(5),
+  };
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Offer><product>tea</product><price xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:nil="true" /><discount>5</discount></Offer>"#
+  );
+}
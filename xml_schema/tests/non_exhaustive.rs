@@ -0,0 +1,16 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+
+#[test]
+fn non_exhaustive() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/attribute.xsd", non_exhaustive)]
+  struct NonExhaustiveSchema;
+
+  let xml_1 = r#"<Shape color="light-blue"></Shape>"#;
+
+  let sample_1: xml_schema_types::Shape = from_str(xml_1).unwrap();
+
+  assert_eq!(sample_1.color, xml_schema_types::ShapeColor::LightBlue);
+}
@@ -0,0 +1,32 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn notation() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/notation.xsd")]
+  struct NotationSchema;
+
+  let xml_1 = r#"<Picture format="jpeg"><source>picture.jpg</source></Picture>"#;
+
+  let sample_1: xml_schema_types::Picture = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Picture {
+    source: "picture.jpg".to_string(),
+    format: xml_schema_types::Notation::Jpeg,
+  };
+
+  assert_eq!(sample_1, model);
+  assert_eq!(
+    xml_schema_types::Notation::default(),
+    xml_schema_types::Notation::Gif
+  );
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Picture format="jpeg"><source>picture.jpg</source></Picture>"#
+  );
+}
@@ -0,0 +1,244 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+
+#[test]
+fn bounded_max_occurrences() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/occurrences.xsd")]
+  struct OccurrencesSchema;
+
+  let xml_1 = r#"<Podium><winner>a</winner><winner>b</winner><winner>c</winner></Podium>"#;
+
+  let sample_1: xml_schema_types::Podium = from_str(xml_1).unwrap();
+
+  assert_eq!(sample_1.winner_list.len(), 3);
+  assert_eq!(sample_1.validate(), Ok(()));
+
+  let xml_2 =
+    r#"<Podium><winner>a</winner><winner>b</winner><winner>c</winner><winner>d</winner></Podium>"#;
+
+  let sample_2: xml_schema_types::Podium = from_str(xml_2).unwrap();
+
+  assert_eq!(
+    sample_2.validate(),
+    Err("winner expects at most 3 occurrences, found 4".to_string())
+  );
+}
+
+#[test]
+fn min_occurrences() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/occurrences.xsd")]
+  struct OccurrencesSchema;
+
+  let xml_1 = r#"<Team><member>a</member></Team>"#;
+
+  let sample_1: xml_schema_types::Team = from_str(xml_1).unwrap();
+
+  assert_eq!(
+    sample_1.validate(),
+    Err("member expects at least 2 occurrences, found 1".to_string())
+  );
+
+  let xml_2 = r#"<Team><member>a</member><member>b</member><member>c</member></Team>"#;
+
+  let sample_2: xml_schema_types::Team = from_str(xml_2).unwrap();
+
+  assert_eq!(sample_2.member_list.len(), 3);
+  assert_eq!(sample_2.validate(), Ok(()));
+}
+
+#[test]
+fn all_validation_errors() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "xml_schema/tests/occurrences.xsd",
+    validation_errors,
+    validating_constructors
+  )]
+  struct OccurrencesSchema;
+
+  let xml_1 = r#"<Race><runner>a</runner><referee>b</referee><referee>c</referee><referee>d</referee></Race>"#;
+
+  let sample_1: xml_schema_types::Race = from_str(xml_1).unwrap();
+
+  assert_eq!(
+    sample_1.validate(),
+    Err(vec![
+      xml_schema::ValidationError {
+        path: "Race.runner_list".to_string(),
+        message: "runner expects at least 2 occurrences, found 1".to_string(),
+      },
+      xml_schema::ValidationError {
+        path: "Race.referee_list".to_string(),
+        message: "referee expects at most 2 occurrences, found 3".to_string(),
+      },
+    ])
+  );
+
+  let xml_2 = r#"<Race><runner>a</runner><runner>b</runner><referee>c</referee></Race>"#;
+
+  let sample_2: xml_schema_types::Race = from_str(xml_2).unwrap();
+
+  assert_eq!(sample_2.validate(), Ok(()));
+
+  assert_eq!(
+    xml_schema_types::Race::new(vec!["a".to_string()], vec![]),
+    Err("Race.runner_list: runner expects at least 2 occurrences, found 1".to_string())
+  );
+
+  let xml_3 = r#"<Season><team><member>a</member></team><team><member>b</member></team></Season>"#;
+
+  assert_eq!(
+    Season::from_xml_validated(xml_3),
+    Err(xml_schema::XsdError::Validation(vec![
+      xml_schema::ValidationError {
+        path: "Team.member_list".to_string(),
+        message: "member expects at least 2 occurrences, found 1".to_string(),
+      },
+      xml_schema::ValidationError {
+        path: "Team.member_list".to_string(),
+        message: "member expects at least 2 occurrences, found 1".to_string(),
+      },
+    ]))
+  );
+}
+
+#[test]
+fn inline_type_occurrences() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/occurrences.xsd")]
+  struct OccurrencesSchema;
+
+  let xml_1 = r#"<Relay><leg>a</leg></Relay>"#;
+
+  let sample_1: Relay = from_str(xml_1).unwrap();
+
+  assert_eq!(
+    sample_1.validate(),
+    Err("leg expects at least 2 occurrences, found 1".to_string())
+  );
+
+  let xml_2 = r#"<Relay><leg>a</leg><leg>b</leg><leg>c</leg><leg>d</leg></Relay>"#;
+
+  let sample_2: Relay = from_str(xml_2).unwrap();
+
+  assert_eq!(
+    sample_2.validate(),
+    Err("leg expects at most 3 occurrences, found 4".to_string())
+  );
+
+  let xml_3 = r#"<League><division><club>a</club><club>b</club><club>c</club><club>d</club><club>e</club></division></League>"#;
+
+  let sample_3: xml_schema_types::League = from_str(xml_3).unwrap();
+
+  assert_eq!(
+    sample_3.division.validate(),
+    Err("club expects at most 4 occurrences, found 5".to_string())
+  );
+}
+
+#[test]
+fn nested_validation() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/occurrences.xsd")]
+  struct OccurrencesSchema;
+
+  let xml_1 = r#"<Championship><podium><winner>a</winner></podium><team><member>a</member></team></Championship>"#;
+
+  let sample_1: xml_schema_types::Championship = from_str(xml_1).unwrap();
+
+  assert_eq!(
+    sample_1.validate(),
+    Err("member expects at least 2 occurrences, found 1".to_string())
+  );
+
+  let xml_2 = r#"<Championship><podium><winner>a</winner><winner>b</winner><winner>c</winner><winner>d</winner></podium></Championship>"#;
+
+  let sample_2: xml_schema_types::Championship = from_str(xml_2).unwrap();
+
+  assert_eq!(
+    sample_2.validate(),
+    Err("winner expects at most 3 occurrences, found 4".to_string())
+  );
+
+  let xml_3 = r#"<League><division><club>a</club><club>b</club><club>c</club><club>d</club><club>e</club></division></League>"#;
+
+  let sample_3: xml_schema_types::League = from_str(xml_3).unwrap();
+
+  assert_eq!(
+    sample_3.validate(),
+    Err("club expects at most 4 occurrences, found 5".to_string())
+  );
+
+  // the inline type of the root declares no check, its repeated teams do
+  let xml_4 = r#"<Season><team><member>a</member><member>b</member></team><team><member>c</member></team></Season>"#;
+
+  assert_eq!(
+    Season::from_xml_validated(xml_4),
+    Err(xml_schema::XsdError::Validation(vec![
+      xml_schema::ValidationError {
+        path: "Season".to_string(),
+        message: "member expects at least 2 occurrences, found 1".to_string(),
+      }
+    ]))
+  );
+
+  let xml_5 = r#"<Season><team><member>a</member><member>b</member></team></Season>"#;
+
+  assert!(Season::from_xml_validated(xml_5).is_ok());
+}
+
+#[test]
+fn bounded_occurrences_capacity() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/occurrences.xsd")]
+  struct OccurrencesSchema;
+
+  let archive = xml_schema_types::Archive::with_capacity();
+
+  assert!(archive.entry_list.is_empty());
+  assert!(archive.entry_list.capacity() >= 1000);
+  assert_eq!(archive.label, "");
+}
+
+#[test]
+fn validated_parsing() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/occurrences.xsd")]
+  struct OccurrencesSchema;
+
+  let xml_1 = r#"<Podium><winner>a</winner><winner>b</winner></Podium>"#;
+
+  let sample_1 = Podium::from_xml_validated(xml_1).unwrap();
+
+  assert_eq!(sample_1.content.winner_list, vec!["a", "b"]);
+
+  let xml_2 =
+    r#"<Podium><winner>a</winner><winner>b</winner><winner>c</winner><winner>d</winner></Podium>"#;
+
+  let sample_2: Result<Podium, String> = from_str(xml_2);
+  assert!(sample_2.is_ok());
+
+  assert_eq!(
+    Podium::from_xml_validated(xml_2),
+    Err(xml_schema::XsdError::Validation(vec![
+      xml_schema::ValidationError {
+        path: "Podium".to_string(),
+        message: "winner expects at most 3 occurrences, found 4".to_string(),
+      }
+    ]))
+  );
+
+  assert!(matches!(
+    Podium::from_xml_validated("<Podium>"),
+    Err(xml_schema::XsdError::Parse(_))
+  ));
+}
@@ -0,0 +1,32 @@
+use xml_schema::de::{from_str_located, ParseError};
+use xml_schema::XsdError;
+use xml_schema_derive::XmlSchema;
+
+#[test]
+fn parse_error_location() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/parse_error.xsd")]
+  struct ParseErrorSchema;
+
+  let xml_1 = r#"<order>
+  <item><sku>a-1</sku><quantity>3</quantity></item>
+  <item><sku>b-2</sku><quantity>many</quantity></item>
+</order>"#;
+
+  let error = from_str_located::<Order>(xml_1).unwrap_err();
+
+  assert_eq!(error.path, "order/item/quantity");
+  assert_eq!((error.line, error.column), (3, 33));
+
+  let parse_error = xml_1.parse::<Order>().unwrap_err();
+  assert_eq!(parse_error, XsdError::Parse(error.clone()));
+  assert!(parse_error
+    .to_string()
+    .ends_with(" at order/item/quantity (line 3, column 33)"));
+
+  let xml_2 = r#"<order><item><sku>a-1</sku><quantity>3</quantity></item></order>"#;
+
+  let sample_2: Result<Order, ParseError> = from_str_located(xml_2);
+  assert_eq!(sample_2.unwrap().item_list[0].quantity.value(), &3);
+}
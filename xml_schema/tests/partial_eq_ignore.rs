@@ -0,0 +1,28 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+
+#[test]
+fn partial_eq_ignore() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "xml_schema/tests/partial_eq_ignore.xsd",
+    partial_eq_ignore("timestamp", "id")
+  )]
+  struct PartialEqIgnoreSchema;
+
+  let xml_1 =
+    r#"<Event id="1"><name>Release</name><timestamp>2024-01-01T10:00:00</timestamp></Event>"#;
+  let xml_2 =
+    r#"<Event id="2"><name>Release</name><timestamp>2024-06-01T08:30:00</timestamp></Event>"#;
+  let xml_3 =
+    r#"<Event id="1"><name>Launch</name><timestamp>2024-01-01T10:00:00</timestamp></Event>"#;
+
+  let sample_1: xml_schema_types::Event = from_str(xml_1).unwrap();
+  let sample_2: xml_schema_types::Event = from_str(xml_2).unwrap();
+  let sample_3: xml_schema_types::Event = from_str(xml_3).unwrap();
+
+  assert_ne!(sample_1.timestamp, sample_2.timestamp);
+  assert_eq!(sample_1, sample_2);
+  assert_ne!(sample_1, sample_3);
+}
@@ -0,0 +1,42 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+
+#[test]
+fn prune_unused_types() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/prune_unused.xsd", prune_unused = true)]
+  struct PruneUnusedSchema;
+
+  let xml_1 = r#"<Document><address><city>Paris</city><country>FR</country></address><summary><title>Q3</title></summary></Document>"#;
+
+  let sample_1: Document = from_str(xml_1).unwrap();
+
+  let model = Document {
+    address: xml_schema_types::Address {
+      city: "Paris".to_string(),
+      country: xml_schema_types::Country {
+        content: "FR".to_string(),
+      },
+    },
+    summary: xml_schema_types::Summary {
+      title: "Q3".to_string(),
+    },
+  };
+
+  assert_eq!(sample_1, model);
+
+  // kept although only reached through a restriction base and a group reference
+  let outline = xml_schema_types::Outline {
+    title: "Q3".to_string(),
+    note: None,
+  };
+  let remarks = xml_schema_types::Remarks {
+    remark: xml_schema_types::Remark {
+      text: "draft".to_string(),
+    },
+  };
+
+  assert_eq!(outline.title, model.summary.title);
+  assert_eq!(remarks.remark.text, "draft");
+}
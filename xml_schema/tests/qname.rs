@@ -0,0 +1,52 @@
+use xml_schema::QName;
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn qname_resolution() {
+  let xml_1 = r#"<ref xmlns:ns="http://example.com/ns">ns:Foo</ref>"#;
+
+  let sample_1: QName = from_str(xml_1).unwrap();
+
+  assert_eq!(
+    sample_1,
+    QName {
+      prefix: Some("ns".to_string()),
+      local_name: "Foo".to_string(),
+      namespace: Some("http://example.com/ns".to_string()),
+    }
+  );
+}
+
+#[test]
+fn qname_element() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/qname.xsd")]
+  struct QNameSchema;
+
+  let xml_1 = r#"
+  <Link xmlns:ns="http://example.com/ns">
+    <target>ns:Foo</target>
+  </Link>
+  "#;
+
+  let sample_1: xml_schema_types::Link = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Link {
+    target: QName {
+      prefix: Some("ns".to_string()),
+      local_name: "Foo".to_string(),
+      namespace: Some("http://example.com/ns".to_string()),
+    },
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Link><target xmlns:ns="http://example.com/ns">ns:Foo</target></Link>"#
+  );
+}
@@ -0,0 +1,148 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn recursive_optional() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/recursive.xsd")]
+  struct RecursiveSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?><Node><value>1</value><next><value>2</value></next></Node>"#;
+
+  let sample_1: xml_schema_types::Node = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Node {
+    value: 1,
+    next: Some(Box::new(xml_schema_types::Node {
+      value: 2,
+      next: None,
+    })),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_1);
+}
+
+#[test]
+fn recursive_repeated() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/recursive.xsd")]
+  struct RecursiveSchema;
+
+  let xml_1 = r#"<Tree><label>root</label><child><label>leaf</label></child></Tree>"#;
+
+  let sample_1: xml_schema_types::Tree = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Tree {
+    label: "root".to_string(),
+    child_list: vec![xml_schema_types::Tree {
+      label: "leaf".to_string(),
+      child_list: vec![],
+    }],
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    format!(r#"<?xml version="1.0" encoding="UTF-8"?>{xml_1}"#)
+  );
+}
+
+#[test]
+fn recursive_through_other_type() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/recursive.xsd")]
+  struct RecursiveSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?><Person><name>Ada</name><employer><name>Engines</name><ceo><name>Charles</name></ceo></employer></Person>"#;
+
+  let sample_1: xml_schema_types::Person = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Person {
+    name: "Ada".to_string(),
+    employer: Some(Box::new(xml_schema_types::Company {
+      name: "Engines".to_string(),
+      ceo: xml_schema_types::Person {
+        name: "Charles".to_string(),
+        employer: None,
+      },
+    })),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_1);
+}
+
+#[test]
+fn recursive_cycles_boxed_once() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/recursive.xsd")]
+  struct RecursiveSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?><Chapter><title>Intro</title><section><note><text>See</text><reference><title>Outro</title><section><note><text>End</text></note></section></reference></note><subsection><note><text>Nested</text></note></subsection></section></Chapter>"#;
+
+  let sample_1: xml_schema_types::Chapter = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Chapter {
+    title: "Intro".to_string(),
+    section: xml_schema_types::Section {
+      note: xml_schema_types::Note {
+        text: "See".to_string(),
+        reference: Some(Box::new(xml_schema_types::Chapter {
+          title: "Outro".to_string(),
+          section: xml_schema_types::Section {
+            note: xml_schema_types::Note {
+              text: "End".to_string(),
+              reference: None,
+            },
+            subsection: None,
+          },
+        })),
+      },
+      subsection: Some(Box::new(xml_schema_types::Section {
+        note: xml_schema_types::Note {
+          text: "Nested".to_string(),
+          reference: None,
+        },
+        subsection: None,
+      })),
+    },
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_1);
+
+  let xml_2 = r#"<?xml version="1.0" encoding="UTF-8"?><Menu><item><label>File</label><submenu><item><label>Open</label></item></submenu></item></Menu>"#;
+
+  let sample_2: xml_schema_types::Menu = from_str(xml_2).unwrap();
+
+  let model = xml_schema_types::Menu {
+    item: xml_schema_types::Item {
+      label: "File".to_string(),
+      submenu: Some(Box::new(xml_schema_types::Menu {
+        item: xml_schema_types::Item {
+          label: "Open".to_string(),
+          submenu: None,
+        },
+      })),
+    },
+  };
+
+  assert_eq!(sample_2, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_2);
+}
@@ -0,0 +1,22 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+
+#[test]
+fn redact_binary_debug() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/redact_binary.xsd", redact_binary)]
+  struct RedactBinarySchema;
+
+  let xml_1 = r#"<Payload checksum="0a1b2c3d"><name>logo</name><data>aGVsbG8gd29ybGQ=</data><byte>1</byte><byte>2</byte><byte>3</byte></Payload>"#;
+
+  let sample_1: xml_schema_types::Payload = from_str(xml_1).unwrap();
+
+  assert_eq!(sample_1.data, "aGVsbG8gd29ybGQ=");
+  assert_eq!(sample_1.byte_list, vec![1, 2, 3]);
+
+  assert_eq!(
+    format!("{:?}", sample_1),
+    r#"Payload { name: "logo", data: <11 bytes>, byte_list: <3 bytes>, checksum: Some(<4 bytes>) }"#
+  );
+}
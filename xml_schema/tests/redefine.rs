@@ -0,0 +1,76 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+
+#[test]
+fn redefine_complex_type() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/redefine.xsd")]
+  struct RedefineSchema;
+
+  let xml_1 = r#"
+  <Address>
+    <street>Main street</street>
+    <city>Paris</city>
+  </Address>
+  "#;
+
+  let sample_1: xml_schema_types::Address = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Address {
+    street: "Main street".to_string(),
+    city: "Paris".to_string(),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let xml_2 = r#"
+  <Contact>
+    <email>contact@example.com</email>
+  </Contact>
+  "#;
+
+  let sample_2: xml_schema_types::Contact = from_str(xml_2).unwrap();
+
+  let model = xml_schema_types::Contact {
+    email: "contact@example.com".to_string(),
+  };
+
+  assert_eq!(sample_2, model);
+}
+
+#[test]
+fn redefine_complex_type_replacement() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/redefine_replacement.xsd")]
+  struct RedefineSchema;
+
+  let xml_1 = r#"
+  <Contact>
+    <phone>+33 1 23 45 67 89</phone>
+  </Contact>
+  "#;
+
+  let sample_1: xml_schema_types::Contact = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Contact {
+    phone: "+33 1 23 45 67 89".to_string(),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let xml_2 = r#"
+  <Address>
+    <street>Main street</street>
+  </Address>
+  "#;
+
+  let sample_2: xml_schema_types::Address = from_str(xml_2).unwrap();
+
+  let model = xml_schema_types::Address {
+    street: "Main street".to_string(),
+  };
+
+  assert_eq!(sample_2, model);
+}
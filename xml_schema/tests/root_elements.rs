@@ -0,0 +1,29 @@
+use xml_schema_derive::XmlSchema;
+
+#[test]
+fn root_elements() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/root_elements.xsd", root_elements)]
+  struct RootElementsSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?><invoice xmlns="http://example.com/billing"><amount>12</amount></invoice>"#;
+
+  assert_eq!(RootElement::from_document(xml_1), Ok(RootElement::Invoice));
+
+  let invoice: Invoice = xml_1.parse().unwrap();
+  assert_eq!(invoice.amount, 12);
+
+  // only the root tag is read, the rest of the document is not parsed
+  let xml_2 = r#"<order xmlns="http://example.com/billing"><reference>A-1</unclosed>"#;
+
+  assert_eq!(RootElement::from_document(xml_2), Ok(RootElement::Order));
+  assert!(xml_2.parse::<Order>().is_err());
+
+  let xml_3 = r#"<order xmlns="http://example.com/other"><reference>A-1</reference></order>"#;
+
+  assert_eq!(
+    RootElement::from_document(xml_3),
+    Err("{http://example.com/other}order is not a global element of the schema".to_string())
+  );
+}
@@ -0,0 +1,30 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn same_namespace_prefixed_type() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "xml_schema/tests/same_namespace_type.xsd",
+    target_prefix = "tns"
+  )]
+  struct SameNamespaceTypeSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?><tns:Book xmlns:tns="http://example.com/library"><tns:title>Dune</tns:title><tns:author><tns:name>Frank Herbert</tns:name></tns:author></tns:Book>"#;
+
+  let sample_1: xml_schema_types::Book = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Book {
+    title: "Dune".to_string(),
+    author: xml_schema_types::Author {
+      name: "Frank Herbert".to_string(),
+    },
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_1);
+}
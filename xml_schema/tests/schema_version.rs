@@ -0,0 +1,11 @@
+use xml_schema_derive::XmlSchema;
+
+#[test]
+fn schema_version() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/schema_version.xsd")]
+  struct SchemaVersionSchema;
+
+  assert_eq!(xml_schema_types::SCHEMA_VERSION, "2.1");
+}
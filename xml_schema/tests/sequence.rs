@@ -0,0 +1,88 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn sequence_unbounded_element() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/sequence.xsd")]
+  struct SequenceSchema;
+
+  let xml_1 = r#"
+  <Items>
+    <item>1</item>
+    <item>2</item>
+  </Items>
+  "#;
+
+  let sample_1: xml_schema_types::Items = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Items {
+    item_list: vec![1, 2],
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Items><item>1</item><item>2</item></Items>"#
+  );
+}
+
+#[test]
+fn strict_sequence_order() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/sequence.xsd", strict_order)]
+  struct SequenceSchema;
+
+  let xml_1 = r#"<Letter><subject>Hello</subject><cc>Bob</cc><body>Hi</body></Letter>"#;
+
+  let sample_1: xml_schema_types::Letter = from_str(xml_1).unwrap();
+
+  assert_eq!(
+    sample_1,
+    xml_schema_types::Letter {
+      subject: "Hello".to_string(),
+      cc: Some("Bob".to_string()),
+      body: "Hi".to_string(),
+    }
+  );
+
+  let xml_2 = r#"<Letter><subject>Hello</subject><body>Hi</body><cc>Bob</cc></Letter>"#;
+
+  let sample_2: Result<xml_schema_types::Letter, String> = from_str(xml_2);
+
+  assert_eq!(
+    sample_2,
+    Err("element cc is not expected after body".to_string())
+  );
+}
+
+#[test]
+fn sequence_order() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/sequence.xsd")]
+  struct SequenceSchema;
+
+  let xml = r#"<Letter><subject>Hello</subject><body>Hi</body><cc>Bob</cc></Letter>"#;
+
+  let sample: xml_schema_types::Letter = from_str(xml).unwrap();
+
+  let model = xml_schema_types::Letter {
+    subject: "Hello".to_string(),
+    cc: Some("Bob".to_string()),
+    body: "Hi".to_string(),
+  };
+
+  assert_eq!(sample, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Letter><subject>Hello</subject><cc>Bob</cc><body>Hi</body></Letter>"#
+  );
+}
@@ -0,0 +1,37 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn simple_content_restriction() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/simple_content.xsd")]
+  struct SimpleContentSchema;
+
+  let xml_1 =
+    r#"<?xml version="1.0" encoding="UTF-8"?><ShortText lang="en" dir="ltr">Hello</ShortText>"#;
+
+  let sample_1: xml_schema_types::ShortText = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::ShortText {
+    base: "Hello".to_string(),
+    lang: "en".to_string(),
+    dir: Some("ltr".to_string()),
+  };
+
+  assert_eq!(sample_1, model);
+  assert_eq!(sample_1.validate(), Ok(()));
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_1);
+
+  let xml_2 = r#"<ShortText lang="en">Hello world</ShortText>"#;
+
+  let sample_2: xml_schema_types::ShortText = from_str(xml_2).unwrap();
+
+  assert_eq!(
+    sample_2.validate(),
+    Err(r#""Hello world" must have a length of at most 5"#.to_string())
+  );
+}
@@ -0,0 +1,59 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn simple_content_simple_type_extension() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/simple_content_enum.xsd")]
+  struct SimpleContentSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?><Parcel><unit precision="2">lb</unit><label lang="en">abc</label></Parcel>"#;
+
+  let sample_1: xml_schema_types::Parcel = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Parcel {
+    unit: xml_schema_types::Measure {
+      base: xml_schema_types::Unit::Lb,
+      precision: Some(2),
+    },
+    label: xml_schema_types::Label {
+      base: xml_schema_types::Code::new("abc".to_string()).unwrap(),
+      lang: Some("en".to_string()),
+    },
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_1);
+
+  let xml_2 = r#"<Parcel><unit>kg</unit><label>abcd</label></Parcel>"#;
+  assert!(from_str::<xml_schema_types::Parcel>(xml_2).is_err());
+}
+
+#[test]
+fn simple_content_inline_simple_type_extension() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/simple_content_enum.xsd")]
+  struct SimpleContentSchema;
+
+  let xml_1 =
+    r#"<?xml version="1.0" encoding="UTF-8"?><Shipment><reference>xyz</reference></Shipment>"#;
+
+  let sample_1: xml_schema_types::Shipment = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Shipment {
+    reference: xml_schema_types::Code::new("xyz".to_string()).unwrap(),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_1);
+
+  let xml_2 = r#"<Shipment><reference>wxyz</reference></Shipment>"#;
+  assert!(from_str::<xml_schema_types::Shipment>(xml_2).is_err());
+}
@@ -4,6 +4,7 @@ use yaserde::ser::to_string;
 
 #[test]
 fn simple_type_string() {
+  #[allow(dead_code)]
   #[derive(Debug, XmlSchema)]
   #[xml_schema(
     source = "xml_schema/tests/simple_type_string.xsd",
@@ -28,6 +29,7 @@ fn simple_type_string() {
 
 #[test]
 fn simple_type_list() {
+  #[allow(dead_code)]
   #[derive(Debug, XmlSchema)]
   #[xml_schema(source = "xml_schema/tests/simple_type_list.xsd")]
   struct SimpleTypeSchema;
@@ -53,6 +55,133 @@ fn simple_type_list() {
   let data = to_string(&model).unwrap();
   assert_eq!(
     data,
-    r#"<?xml version="1.0" encoding="utf-8"?><BaseType strings="value1 value2" integers="3 6" booleans="true false" />"#
+    r#"<?xml version="1.0" encoding="UTF-8"?><BaseType strings="value1 value2" integers="3 6" booleans="true false" />"#
+  );
+}
+
+#[test]
+fn simple_type_list_attribute() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/simple_type_list.xsd")]
+  struct SimpleTypeSchema;
+
+  let sample_1: xml_schema_types::Measures = from_str(r#"<Measures values="1 2 3" />"#).unwrap();
+  assert_eq!(*sample_1.values, vec![1, 2, 3]);
+  assert_eq!(sample_1.values.len(), 3);
+
+  // items are separated by any whitespace
+  let sample_2: xml_schema_types::Measures =
+    from_str("<Measures values=\" 1\t 2\n3 \" />").unwrap();
+  assert_eq!(sample_2, sample_1);
+
+  assert!(from_str::<xml_schema_types::Measures>(r#"<Measures values="1 x" />"#).is_err());
+
+  let model = xml_schema_types::Measures {
+    values: vec![4, 5].into(),
+  };
+  assert_eq!(
+    to_string(&model).unwrap(),
+    r#"<?xml version="1.0" encoding="UTF-8"?><Measures values="4 5" />"#
+  );
+}
+
+#[test]
+fn simple_type_restriction() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/simple_type_restriction.xsd")]
+  struct SimpleTypeSchema;
+
+  let code = xml_schema_types::Code::new("abc".to_string()).unwrap();
+  assert_eq!(code.value(), "abc");
+  assert_eq!(code.into_inner(), "abc".to_string());
+  assert!(xml_schema_types::Code::new("abcdef".to_string()).is_err());
+  assert!(xml_schema_types::Code::new("a".to_string()).is_err());
+
+  let sample_1: xml_schema_types::Code = from_str("<Code>abc</Code>").unwrap();
+  assert_eq!(
+    sample_1,
+    xml_schema_types::Code::new("abc".to_string()).unwrap()
+  );
+
+  assert!(from_str::<xml_schema_types::Code>("<Code>abcdef</Code>").is_err());
+}
+
+#[test]
+fn simple_type_restriction_holder() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/simple_type_restriction.xsd")]
+  struct SimpleTypeSchema;
+
+  let xml_1 =
+    r#"<?xml version="1.0" encoding="UTF-8"?><Item reference="xyz"><code>abc</code></Item>"#;
+
+  let sample_1: xml_schema_types::Item = from_str(xml_1).unwrap();
+
+  assert_eq!(sample_1.code.value(), "abc");
+  assert_eq!(sample_1.reference.value(), "xyz");
+  assert_eq!(to_string(&sample_1).unwrap(), xml_1);
+}
+
+#[test]
+fn simple_type_restriction_as_str() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/simple_type_restriction.xsd")]
+  struct SimpleTypeSchema;
+
+  fn shout(value: &str) -> String {
+    value.to_uppercase()
+  }
+
+  let code = xml_schema_types::Code::new("abc".to_string()).unwrap();
+
+  assert_eq!(shout(&code), "ABC");
+  assert_eq!(shout(code.as_ref()), "ABC");
+  assert_eq!(&*code, "abc");
+  assert_eq!(code.len(), 3);
+}
+
+#[test]
+fn simple_type_enumeration_pattern() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/simple_type_enumeration_pattern.xsd")]
+  struct SimpleTypeSchema;
+
+  let sample_1: xml_schema_types::Exam = from_str("<Exam><grade>B2</grade></Exam>").unwrap();
+  assert_eq!(sample_1.grade, xml_schema_types::Grade::B2);
+
+  // the enumeration wins over the pattern: the values are not checked against it
+  let sample_2: xml_schema_types::Exam = from_str("<Exam><grade>c3</grade></Exam>").unwrap();
+  assert_eq!(sample_2.grade, xml_schema_types::Grade::C3);
+
+  let data = to_string(&sample_2).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Exam><grade>c3</grade></Exam>"#
+  );
+}
+
+#[test]
+fn simple_type_enumeration_collision() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/simple_type_enumeration_collision.xsd")]
+  struct SimpleTypeSchema;
+
+  let sample_1: xml_schema_types::Shirt = from_str("<Shirt><size>x_large</size></Shirt>").unwrap();
+  assert_eq!(sample_1.size, xml_schema_types::Size::XLarge2);
+
+  let sample_2: xml_schema_types::Shirt = from_str("<Shirt><size>s</size></Shirt>").unwrap();
+  assert_eq!(sample_2.size, xml_schema_types::Size::S2);
+  assert_eq!(xml_schema_types::Size::S.to_string(), "S");
+
+  let data = to_string(&sample_1).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Shirt><size>x_large</size></Shirt>"#
   );
 }
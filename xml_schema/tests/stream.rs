@@ -0,0 +1,73 @@
+use std::cell::Cell;
+use std::io::Read;
+use std::rc::Rc;
+use xml_schema_derive::XmlSchema;
+
+/// Produce a feed of `count` entries on demand, recording how many were generated.
+struct LazyFeed {
+  count: usize,
+  generated: Rc<Cell<usize>>,
+  buffer: Vec<u8>,
+  finished: bool,
+}
+
+impl LazyFeed {
+  fn new(count: usize, generated: Rc<Cell<usize>>) -> Self {
+    LazyFeed {
+      count,
+      generated,
+      buffer: b"<Feed>".to_vec(),
+      finished: false,
+    }
+  }
+}
+
+impl Read for LazyFeed {
+  fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+    if self.buffer.is_empty() && !self.finished {
+      let index = self.generated.get();
+      if index < self.count {
+        self.buffer = format!("<entry><title>Entry {index}</title></entry>").into_bytes();
+        self.generated.set(index + 1);
+      } else {
+        self.buffer = b"</Feed>".to_vec();
+        self.finished = true;
+      }
+    }
+
+    let size = buf.len().min(self.buffer.len());
+    buf[..size].copy_from_slice(&self.buffer[..size]);
+    self.buffer.drain(..size);
+    Ok(size)
+  }
+}
+
+#[test]
+fn stream() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/stream.xsd", streams)]
+  struct StreamSchema;
+
+  let generated = Rc::new(Cell::new(0));
+  let mut entries = xml_schema_types::Feed::stream(LazyFeed::new(10_000, generated.clone()));
+
+  let first = entries.next().unwrap().unwrap();
+  assert_eq!(
+    first,
+    xml_schema_types::Entry {
+      title: "Entry 0".to_string(),
+    }
+  );
+  assert!(generated.get() < 100);
+
+  let mut count = 1;
+  for entry in entries {
+    let entry = entry.unwrap();
+    assert_eq!(entry.title, format!("Entry {count}"));
+    count += 1;
+  }
+
+  assert_eq!(count, 10_000);
+  assert_eq!(generated.get(), 10_000);
+}
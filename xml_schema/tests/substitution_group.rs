@@ -0,0 +1,115 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn substitution_group() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/substitution_group.xsd")]
+  struct SubstitutionGroupSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?><Drawing><circle color="red" radius="2" /><frame color="black" /></Drawing>"#;
+
+  let sample_1: Drawing = from_str(xml_1).unwrap();
+
+  let model = Drawing {
+    shape: xml_schema_types::ShapeSubstitution::Circle(xml_schema_types::Circle {
+      color: Some("red".to_string()),
+      radius: 2,
+    }),
+    frame: xml_schema_types::Shape {
+      color: Some("black".to_string()),
+    },
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_1);
+
+  let xml_2 = r#"<Drawing><shape color="blue" /><frame /></Drawing>"#;
+
+  let sample_2: Drawing = from_str(xml_2).unwrap();
+  assert_eq!(
+    sample_2.shape,
+    xml_schema_types::ShapeSubstitution::Shape(xml_schema_types::Shape {
+      color: Some("blue".to_string()),
+    })
+  );
+}
+
+#[test]
+fn abstract_substitution_head() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/substitution_group.xsd")]
+  struct SubstitutionGroupSchema;
+
+  let xml_1 = r#"<Garage><bike wheels="2" /></Garage>"#;
+
+  let sample_1: Garage = from_str(xml_1).unwrap();
+
+  // the abstract head has no variant, the match is exhaustive with the members
+  let wheels = match sample_1.vehicle {
+    xml_schema_types::VehicleSubstitution::Car(vehicle) => vehicle.wheels,
+    xml_schema_types::VehicleSubstitution::Bike(vehicle) => vehicle.wheels,
+    xml_schema_types::VehicleSubstitution::Tram(vehicle) => vehicle.wheels,
+  };
+
+  assert_eq!(wheels, Some(2));
+}
+
+#[test]
+fn substitution_member_without_type() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/substitution_group.xsd")]
+  struct SubstitutionGroupSchema;
+
+  let xml_1 =
+    r#"<?xml version="1.0" encoding="UTF-8"?><Holder><derived label="inherited" /></Holder>"#;
+
+  let sample_1: Holder = from_str(xml_1).unwrap();
+
+  // the member declares no type, it has the type of its head
+  let model = Holder {
+    base: xml_schema_types::BaseSubstitution::Derived(xml_schema_types::Base {
+      label: Some("inherited".to_string()),
+    }),
+  };
+
+  assert_eq!(sample_1, model);
+  assert_eq!(to_string(&model).unwrap(), xml_1);
+
+  let derived: Derived = from_str(r#"<derived label="global" />"#).unwrap();
+  assert_eq!(derived.content.label, Some("global".to_string()));
+}
+
+#[test]
+fn substitution_member_of_several_heads() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/substitution_group.xsd")]
+  struct SubstitutionGroupSchema;
+
+  let tram = xml_schema_types::Vehicle { wheels: Some(8) };
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?><Garage><tram wheels="8" /></Garage>"#;
+
+  let sample_1: Garage = from_str(xml_1).unwrap();
+  assert_eq!(
+    sample_1.vehicle,
+    xml_schema_types::VehicleSubstitution::Tram(tram.clone())
+  );
+  assert_eq!(to_string(&sample_1).unwrap(), xml_1);
+
+  let xml_2 = r#"<?xml version="1.0" encoding="UTF-8"?><Depot><tram wheels="8" /></Depot>"#;
+
+  let sample_2: Depot = from_str(xml_2).unwrap();
+  assert_eq!(
+    sample_2.transport,
+    xml_schema_types::TransportSubstitution::Tram(tram)
+  );
+  assert_eq!(to_string(&sample_2).unwrap(), xml_2);
+}
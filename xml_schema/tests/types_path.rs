@@ -0,0 +1,24 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+
+#[allow(dead_code)]
+#[derive(Debug, XmlSchema)]
+#[xml_schema(
+  source = "xml_schema/tests/types_path.xsd",
+  types_path = "crate::types_path_schema::xml_schema_types"
+)]
+struct TypesPathSchema;
+
+#[test]
+fn custom_types_path() {
+  let xml_1 = r#"<Line><start x="0" y="1" /><end x="2" y="3" /></Line>"#;
+
+  let sample_1: Line = from_str(xml_1).unwrap();
+
+  let model = Line {
+    start: xml_schema_types::Point { x: 0, y: 1 },
+    end: xml_schema_types::Point { x: 2, y: 3 },
+  };
+
+  assert_eq!(sample_1, model);
+}
@@ -0,0 +1,38 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn attribute_union() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/union.xsd")]
+  struct UnionSchema;
+
+  let xml_1 = r#"<Box size="42" weight="heavy" />"#;
+
+  let sample_1: xml_schema_types::Box = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Box {
+    size: xml_schema_types::Size::Int(42),
+    weight: xml_schema_types::BoxWeight::String("heavy".to_string()),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Box size="42" weight="heavy" />"#
+  );
+
+  let xml_2 = r#"<Box size="large" weight="12" />"#;
+
+  let sample_2: xml_schema_types::Box = from_str(xml_2).unwrap();
+
+  assert_eq!(
+    sample_2.size,
+    xml_schema_types::Size::String("large".to_string())
+  );
+  assert_eq!(sample_2.weight, xml_schema_types::BoxWeight::Int(12));
+}
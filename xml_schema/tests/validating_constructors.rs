@@ -0,0 +1,46 @@
+use xml_schema_derive::XmlSchema;
+
+#[test]
+fn validating_constructor() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/occurrences.xsd", validating_constructors)]
+  struct OccurrencesSchema;
+
+  assert_eq!(
+    xml_schema_types::Team::new(vec!["a".to_string()]),
+    Err("member expects at least 2 occurrences, found 1".to_string())
+  );
+
+  let team = xml_schema_types::Team::new(vec!["a".to_string(), "b".to_string()]).unwrap();
+  assert_eq!(
+    team,
+    xml_schema_types::Team {
+      member_list: vec!["a".to_string(), "b".to_string()],
+    }
+  );
+}
+
+#[test]
+fn constructor_without_constraints() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(
+    source = "xml_schema/tests/element_restriction.xsd",
+    validating_constructors
+  )]
+  struct ElementRestrictionSchema;
+
+  let product = xml_schema_types::Product::new(
+    xml_schema_types::ProductCode::new("AB12".to_string()).unwrap(),
+    xml_schema_types::ProductSize::Small,
+  );
+
+  assert_eq!(
+    product,
+    Ok(xml_schema_types::Product {
+      code: xml_schema_types::ProductCode::new("AB12".to_string()).unwrap(),
+      size: xml_schema_types::ProductSize::Small,
+    })
+  );
+}
@@ -0,0 +1,69 @@
+use xml_schema_derive::XmlSchema;
+
+#[test]
+fn visitor() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/visitor.xsd", visitors)]
+  struct VisitorSchema;
+
+  #[derive(Default)]
+  struct Collector {
+    items: Vec<xml_schema_types::Item>,
+    notes: Vec<String>,
+  }
+
+  impl xml_schema_types::InventoryVisitor for Collector {
+    fn on_item(&mut self, item: xml_schema_types::Item) {
+      self.items.push(item);
+    }
+
+    fn on_note(&mut self, note: String) {
+      self.notes.push(note);
+    }
+  }
+
+  let xml_1 = r#"
+  <Inventory>
+    <warehouse>north</warehouse>
+    <item><sku>a-1</sku><quantity>3</quantity></item>
+    <item><sku>b-2</sku><quantity>5</quantity></item>
+    <item><sku>c-3</sku><quantity>8</quantity></item>
+    <note>checked</note>
+  </Inventory>
+  "#;
+
+  let mut collector = Collector::default();
+  xml_schema_types::Inventory::visit(xml_1.as_bytes(), &mut collector).unwrap();
+
+  assert_eq!(
+    collector.items,
+    vec![
+      xml_schema_types::Item {
+        sku: "a-1".to_string(),
+        quantity: 3,
+      },
+      xml_schema_types::Item {
+        sku: "b-2".to_string(),
+        quantity: 5,
+      },
+      xml_schema_types::Item {
+        sku: "c-3".to_string(),
+        quantity: 8,
+      },
+    ]
+  );
+  assert_eq!(collector.notes, vec!["checked".to_string()]);
+
+  struct Counter(usize);
+
+  impl xml_schema_types::InventoryVisitor for Counter {
+    fn on_item(&mut self, _item: xml_schema_types::Item) {
+      self.0 += 1;
+    }
+  }
+
+  let mut counter = Counter(0);
+  xml_schema_types::Inventory::visit(xml_1.as_bytes(), &mut counter).unwrap();
+  assert_eq!(counter.0, 3);
+}
@@ -0,0 +1,60 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::ser::to_string;
+
+#[test]
+fn white_space_preserve() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/white_space.xsd")]
+  struct WhiteSpaceSchema;
+
+  let xml_1 = r#"<?xml version="1.0" encoding="UTF-8"?><note>  spaced  </note>"#;
+
+  let sample_1: Note = xml_schema::de::from_str(xml_1).unwrap();
+
+  let model = Note {
+    content: "  spaced  ".to_string(),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(data, xml_1);
+
+  // the yaserde reader trims the text of the elements
+  let sample_2: Note = yaserde::de::from_str(xml_1).unwrap();
+  assert_eq!(sample_2.content, "spaced");
+}
+
+#[test]
+fn white_space_collapse() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/white_space.xsd")]
+  struct WhiteSpaceSchema;
+
+  let xml_1 = r#"<Label><code>  AB
+    12  </code></Label>"#;
+
+  let sample_1: Label = xml_schema::de::from_str(xml_1).unwrap();
+
+  assert_eq!(&*sample_1.code, "AB 12");
+}
+
+#[test]
+fn white_space_plain_text() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/white_space.xsd")]
+  struct WhiteSpaceSchema;
+
+  let xml_1 = r#"<Entry lang="en">  two
+    lines  </Entry>"#;
+
+  // no whiteSpace facet applies to a plain string, the text is kept as read
+  let sample_1: xml_schema_types::Entry = xml_schema::de::from_str(xml_1).unwrap();
+  assert_eq!(sample_1.base, "  two\n    lines  ");
+
+  let sample_2: xml_schema_types::Entry = yaserde::de::from_str(xml_1).unwrap();
+  assert_eq!(sample_2.base, "two\n    lines");
+}
@@ -0,0 +1,29 @@
+use xml_schema_derive::XmlSchema;
+use yaserde::de::from_str;
+use yaserde::ser::to_string;
+
+#[test]
+fn xml_schema_namespace_prefix() {
+  #[allow(dead_code)]
+  #[derive(Debug, XmlSchema)]
+  #[xml_schema(source = "xml_schema/tests/xsd_prefix.xsd")]
+  struct XsdPrefixSchema;
+
+  let xml_1 = r#"<Counter step="2"><label>visits</label><value>42</value></Counter>"#;
+
+  let sample_1: xml_schema_types::Counter = from_str(xml_1).unwrap();
+
+  let model = xml_schema_types::Counter {
+    label: "visits".to_string(),
+    value: 42,
+    step: Some(2),
+  };
+
+  assert_eq!(sample_1, model);
+
+  let data = to_string(&model).unwrap();
+  assert_eq!(
+    data,
+    r#"<?xml version="1.0" encoding="UTF-8"?><Counter step="2"><label>visits</label><value>42</value></Counter>"#
+  );
+}
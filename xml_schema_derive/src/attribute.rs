@@ -1,16 +1,60 @@
-use darling::FromDeriveInput;
+use crate::xsd::Inheritance;
+use darling::{FromDeriveInput, FromMeta};
 use log::Level;
 use std::collections::BTreeMap;
+use syn::{
+  parse::{Parse, ParseStream},
+  punctuated::Punctuated,
+  LitStr, Token,
+};
 
 #[derive(Debug, PartialEq, Clone, FromDeriveInput)]
 #[darling(attributes(xml_schema), forward_attrs(allow, doc, cfg))]
 pub struct XmlSchemaAttributes {
   ident: syn::Ident,
+  #[darling(default)]
+  pub accessors: bool,
+  pub inherit: Option<String>,
+  #[darling(default)]
+  pub list_wrappers: bool,
   pub log_level: Option<String>,
   pub module_namespace_mappings: Option<String>,
+  #[darling(default)]
+  pub non_exhaustive: bool,
+  #[darling(default)]
+  pub prefix: NamespacePrefixes,
+  #[darling(default)]
+  pub prune_unused: bool,
+  #[darling(default)]
+  pub iterators: bool,
+  #[darling(default)]
+  pub streams: bool,
+  #[darling(default)]
+  pub visitors: bool,
+  #[darling(default)]
+  pub extension_traits: bool,
+  #[darling(default)]
+  pub field_attributes: FieldAttributes,
+  #[darling(default)]
+  pub redact_binary: bool,
   pub source: String,
   pub store_generated_code: Option<String>,
   pub target_prefix: Option<String>,
+  pub types_path: Option<String>,
+  #[darling(default)]
+  pub validating_constructors: bool,
+  #[darling(default)]
+  pub validation_errors: bool,
+  #[darling(default)]
+  pub strict_order: bool,
+  #[darling(default)]
+  pub capture_unknown: bool,
+  #[darling(default)]
+  pub partial_eq_ignore: FieldNames,
+  #[darling(default)]
+  pub hide_anonymous_types: bool,
+  #[darling(default)]
+  pub root_elements: bool,
 }
 
 impl XmlSchemaAttributes {
@@ -29,6 +73,16 @@ impl XmlSchemaAttributes {
     }
   }
 
+  pub fn inheritance(&self) -> Result<Inheritance, String> {
+    match self.inherit.as_deref() {
+      Some("flatten") => Ok(Inheritance::Flatten),
+      Some("inline") | None => Ok(Inheritance::Inline),
+      Some(inherit) => Err(format!(
+        "unsupported inherit value \"{inherit}\", expected \"inline\" or \"flatten\""
+      )),
+    }
+  }
+
   pub fn module_namespace_mappings(&self) -> BTreeMap<String, String> {
     let module_namespace_mappings = self.module_namespace_mappings.clone().unwrap_or_default();
     if module_namespace_mappings.is_empty() {
@@ -46,3 +100,68 @@ impl XmlSchemaAttributes {
     }
   }
 }
+
+/// Preferred prefixes of namespaces, declared as `prefix("http://example.com" = "ex")`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct NamespacePrefixes(pub BTreeMap<String, String>);
+
+impl FromMeta for NamespacePrefixes {
+  fn from_meta(item: &syn::Meta) -> darling::Result<Self> {
+    parse_string_mappings(item).map(NamespacePrefixes)
+  }
+}
+
+/// Attributes added to the generated fields by outer type, declared as
+/// `field_attributes("Option" = "#[serde(skip_serializing_if = \"Option::is_none\")]")`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FieldAttributes(pub BTreeMap<String, String>);
+
+impl FromMeta for FieldAttributes {
+  fn from_meta(item: &syn::Meta) -> darling::Result<Self> {
+    parse_string_mappings(item).map(FieldAttributes)
+  }
+}
+
+/// Names of generated fields, declared as `partial_eq_ignore("timestamp", "id")`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FieldNames(pub Vec<String>);
+
+impl FromMeta for FieldNames {
+  fn from_meta(item: &syn::Meta) -> darling::Result<Self> {
+    Ok(FieldNames(
+      item
+        .require_list()?
+        .parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)?
+        .iter()
+        .map(|name| name.value())
+        .collect(),
+    ))
+  }
+}
+
+struct StringMapping {
+  key: LitStr,
+  value: LitStr,
+}
+
+impl Parse for StringMapping {
+  fn parse(input: ParseStream) -> syn::Result<Self> {
+    let key = input.parse()?;
+    input.parse::<Token![=]>()?;
+    let value = input.parse()?;
+
+    Ok(StringMapping { key, value })
+  }
+}
+
+/// Parse a `name("key" = "value", ...)` list.
+fn parse_string_mappings(item: &syn::Meta) -> darling::Result<BTreeMap<String, String>> {
+  Ok(
+    item
+      .require_list()?
+      .parse_args_with(Punctuated::<StringMapping, Token![,]>::parse_terminated)?
+      .iter()
+      .map(|mapping| (mapping.key.value(), mapping.value.value()))
+      .collect(),
+  )
+}
@@ -1,5 +1,9 @@
-use crate::{attribute::XmlSchemaAttributes, xsd::Xsd};
+use crate::{
+  attribute::XmlSchemaAttributes,
+  xsd::{OuterTypeAttributes, Xsd},
+};
 use proc_macro2::TokenStream;
+use std::rc::Rc;
 use syn::{token::Pub, Visibility};
 
 pub fn expand_derive(attributes: &XmlSchemaAttributes) -> Result<TokenStream, String> {
@@ -13,7 +17,30 @@ pub fn expand_derive(attributes: &XmlSchemaAttributes) -> Result<TokenStream, St
     vis,
     &attributes.source,
     &attributes.module_namespace_mappings(),
-  )?;
+  )?
+  .with_inheritance(attributes.inheritance()?)
+  .with_list_wrappers(attributes.list_wrappers)
+  .with_accessors(attributes.accessors)
+  .with_non_exhaustive(attributes.non_exhaustive)
+  .with_redact_binary(attributes.redact_binary)
+  .with_prune_unused(attributes.prune_unused)
+  .with_iterators(attributes.iterators)
+  .with_streams(attributes.streams)
+  .with_visitors(attributes.visitors)
+  .with_field_hook(Rc::new(OuterTypeAttributes::new(
+    &attributes.field_attributes.0,
+  )?))
+  .with_extension_traits(attributes.extension_traits)
+  .with_validating_constructors(attributes.validating_constructors)
+  .with_validation_errors(attributes.validation_errors)
+  .with_strict_order(attributes.strict_order)
+  .with_capture_unknown(attributes.capture_unknown)
+  .with_partial_eq_ignore(&attributes.partial_eq_ignore.0)
+  .with_hide_anonymous_types(attributes.hide_anonymous_types)
+  .with_root_elements(attributes.root_elements)
+  .with_types_path(&attributes.types_path)
+  .with_namespace_prefixes(&attributes.prefix.0);
+
   let generated = xsd.implement(&attributes.target_prefix);
 
   if let Some(store_generated_code) = &attributes.store_generated_code {
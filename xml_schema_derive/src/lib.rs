@@ -1,3 +1,5 @@
+#![allow(unknown_lints, non_local_definitions)]
+
 extern crate proc_macro;
 #[macro_use]
 extern crate quote;
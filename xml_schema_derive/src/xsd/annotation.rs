@@ -1,4 +1,6 @@
-use crate::xsd::{attribute::Attribute, Implementation, XsdContext};
+use crate::xsd::{
+  appinfo::Appinfo, attribute::Attribute, documentation::Documentation, Implementation, XsdContext,
+};
 use proc_macro2::TokenStream;
 
 #[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
@@ -17,7 +19,13 @@ pub struct Annotation {
       prefix = "xs",
       namespace = "xs: http://www.w3.org/2001/XMLSchema"
     )]
-  pub documentation: Vec<String>,
+  pub documentation: Vec<Documentation>,
+  #[yaserde(
+      rename = "appinfo"
+      prefix = "xs",
+      namespace = "xs: http://www.w3.org/2001/XMLSchema"
+    )]
+  pub appinfos: Vec<Appinfo>,
 }
 
 impl Implementation for Annotation {
@@ -29,11 +37,122 @@ impl Implementation for Annotation {
   ) -> TokenStream {
     log::info!("Generate annotation");
 
-    let documentation = self
+    let lines = self.get_documentation_lines();
+
+    quote!(#(#[doc = #lines])*)
+  }
+}
+
+impl Annotation {
+  /// Documentation of the whole schema, as inner doc of the generated module.
+  pub fn get_module_documentation(&self) -> TokenStream {
+    let lines = self.get_documentation_lines();
+
+    quote!(#(#![doc = #lines])*)
+  }
+
+  /// Each documentation block is a paragraph, separated by a blank line.
+  fn get_documentation_lines(&self) -> Vec<String> {
+    self
       .documentation
       .iter()
-      .map(|documentation| quote!(#[doc = #documentation]));
+      .map(|documentation| documentation.get_doc_content())
+      .enumerate()
+      .flat_map(|(index, documentation)| {
+        let separator = (index > 0).then(String::new);
+        separator.into_iter().chain(std::iter::once(documentation))
+      })
+      .collect()
+  }
+
+  /// Rust field name requested by an appinfo directive, the XML name is kept for serialization.
+  pub fn get_field_name(&self) -> Option<String> {
+    self
+      .appinfos
+      .iter()
+      .find_map(|appinfo| appinfo.get_field_name())
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::str::FromStr;
+  use yaserde::de::from_str;
+
+  #[test]
+  fn multiple_languages_documentation() {
+    let xml = r#"
+      <xs:annotation xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:documentation xml:lang="en">Volume</xs:documentation>
+        <xs:documentation xml:lang="de">Lautstärke</xs:documentation>
+        <xs:documentation>Untagged</xs:documentation>
+      </xs:annotation>
+    "#;
+
+    let annotation: Annotation = from_str(xml).unwrap();
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = annotation.implement(&TokenStream::new(), &None, &context);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[doc = "[en] Volume"]
+        #[doc = ""]
+        #[doc = "[de] Lautstärke"]
+        #[doc = ""]
+        #[doc = "Untagged"]
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn appinfo_field_name() {
+    let xml = r#"
+      <xs:annotation xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:documentation>Identifier</xs:documentation>
+        <xs:appinfo source="http://example.com/other">ignored</xs:appinfo>
+        <xs:appinfo source="rust:field_name"> identifier </xs:appinfo>
+      </xs:annotation>
+    "#;
+
+    let annotation: Annotation = from_str(xml).unwrap();
+
+    assert_eq!(annotation.get_field_name(), Some("identifier".to_string()));
+  }
+
+  #[test]
+  fn documentation_paragraphs() {
+    let xml = r#"
+      <xs:annotation xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:documentation>First paragraph.</xs:documentation>
+        <xs:documentation>Second paragraph.</xs:documentation>
+      </xs:annotation>
+    "#;
+
+    let annotation: Annotation = from_str(xml).unwrap();
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = annotation.implement(&TokenStream::new(), &None, &context);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[doc = "First paragraph."]
+        #[doc = ""]
+        #[doc = "Second paragraph."]
+      "#,
+    )
+    .unwrap();
 
-    quote!(#(#documentation)*)
+    assert_eq!(implementation.to_string(), expected.to_string());
   }
 }
@@ -0,0 +1,33 @@
+/// Source of the `xs:appinfo` directive setting the Rust field name of an element or attribute.
+pub const FIELD_NAME_SOURCE: &str = "rust:field_name";
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(
+  rename = "appinfo"
+  prefix = "xs",
+  namespace = "xs: http://www.w3.org/2001/XMLSchema"
+)]
+pub struct Appinfo {
+  #[yaserde(attribute)]
+  pub source: Option<String>,
+  #[yaserde(text)]
+  pub content: String,
+}
+
+impl Appinfo {
+  /// Rust field name requested by a `<xs:appinfo source="rust:field_name">name</xs:appinfo>` directive.
+  pub fn get_field_name(&self) -> Option<String> {
+    if self.source.as_deref() != Some(FIELD_NAME_SOURCE) {
+      return None;
+    }
+
+    let field_name = self.content.trim();
+
+    if field_name.is_empty() {
+      log::warn!("Empty field name directive in appinfo, it will be ignored");
+      return None;
+    }
+
+    Some(field_name.to_string())
+  }
+}
@@ -0,0 +1,280 @@
+use proc_macro2::TokenStream;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct Assert {
+  #[yaserde(attribute)]
+  pub test: String,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Operand {
+  /// `@name`
+  Attribute(String),
+  /// `name`
+  Element(String),
+  Number(String),
+  Text(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+  pub left: Operand,
+  pub operator: String,
+  pub right: Operand,
+}
+
+impl Comparison {
+  pub fn get_operator_implementation(&self) -> TokenStream {
+    match self.operator.as_str() {
+      "=" | "eq" => quote!(==),
+      "!=" | "ne" => quote!(!=),
+      "<" | "lt" => quote!(<),
+      "<=" | "le" => quote!(<=),
+      ">" | "gt" => quote!(>),
+      ">=" | "ge" => quote!(>=),
+      operator => panic!("[Assert] unsupported operator {:?}", operator),
+    }
+  }
+}
+
+impl Operand {
+  /// Literal of a number compared with a field of `rust_type`,
+  /// `None` if the number does not fit in that type.
+  pub fn get_number_implementation(value: &str, rust_type: Option<&str>) -> Option<TokenStream> {
+    let fits = match rust_type {
+      None => true,
+      Some("i8") => value.parse::<i8>().is_ok(),
+      Some("u8") => value.parse::<u8>().is_ok(),
+      Some("i16") => value.parse::<i16>().is_ok(),
+      Some("u16") => value.parse::<u16>().is_ok(),
+      Some("i32") => value.parse::<i32>().is_ok(),
+      Some("u32") => value.parse::<u32>().is_ok(),
+      Some("i64") => value.parse::<i64>().is_ok(),
+      Some("u64") => value.parse::<u64>().is_ok(),
+      Some("f32") | Some("f64") => value.parse::<f64>().is_ok(),
+      Some(_) => false,
+    };
+
+    if !fits {
+      return None;
+    }
+
+    let (sign, digits) = match value.strip_prefix('-') {
+      Some(digits) => (quote!(-), digits),
+      None => (quote!(), value.trim_start_matches('+')),
+    };
+
+    let literal: TokenStream = format!("{}{}", digits, rust_type.unwrap_or_default())
+      .parse()
+      .ok()?;
+
+    Some(quote!(#sign #literal))
+  }
+}
+
+impl Assert {
+  /// Comparisons joined by `and`, the only XPath subset supported.
+  /// Returns `None` if the expression uses anything else.
+  pub fn get_comparisons(&self) -> Option<Vec<Comparison>> {
+    let tokens = Assert::tokenize(&self.test)?;
+
+    tokens
+      .split(|token| *token == Token::Word("and".to_string()))
+      .map(Assert::parse_comparison)
+      .collect()
+  }
+
+  /// Split the expression in quoted strings, operator symbols and words,
+  /// so that the content of the strings is never read as an operator.
+  fn tokenize(expression: &str) -> Option<Vec<Token>> {
+    let mut tokens = vec![];
+    let mut chars = expression.chars().peekable();
+
+    while let Some(c) = chars.next() {
+      match c {
+        c if c.is_whitespace() => {}
+        '\'' | '"' => {
+          let mut text = String::new();
+
+          loop {
+            match chars.next()? {
+              // a doubled quote stands for the quote itself
+              quote if quote == c && chars.peek() == Some(&c) => {
+                chars.next();
+                text.push(quote);
+              }
+              quote if quote == c => break,
+              other => text.push(other),
+            }
+          }
+
+          tokens.push(Token::Text(text));
+        }
+        '<' | '>' | '!' | '=' => {
+          let mut symbol = c.to_string();
+          if c != '=' && chars.peek() == Some(&'=') {
+            symbol.push(chars.next()?);
+          }
+
+          tokens.push(Token::Symbol(symbol));
+        }
+        c => {
+          let mut word = c.to_string();
+          while let Some(next) = chars.peek() {
+            if next.is_whitespace() || "'\"<>!=".contains(*next) {
+              break;
+            }
+            word.push(chars.next()?);
+          }
+
+          tokens.push(Token::Word(word));
+        }
+      }
+    }
+
+    Some(tokens)
+  }
+
+  fn parse_comparison(tokens: &[Token]) -> Option<Comparison> {
+    let symbols = ["<=", ">=", "!=", "<", ">", "="];
+    let words = ["eq", "ne", "lt", "le", "gt", "ge"];
+
+    let [left, operator, right] = tokens else {
+      return None;
+    };
+
+    let operator = match operator {
+      Token::Symbol(symbol) if symbols.contains(&symbol.as_str()) => symbol,
+      Token::Word(word) if words.contains(&word.as_str()) => word,
+      _ => return None,
+    };
+
+    Some(Comparison {
+      left: Assert::parse_operand(left)?,
+      operator: operator.to_string(),
+      right: Assert::parse_operand(right)?,
+    })
+  }
+
+  fn parse_operand(token: &Token) -> Option<Operand> {
+    let operand = match token {
+      Token::Text(text) => return Some(Operand::Text(text.to_string())),
+      Token::Word(word) => word,
+      Token::Symbol(_) => return None,
+    };
+
+    let is_name = |name: &str| {
+      name.starts_with(|c: char| c.is_alphabetic() || c == '_')
+        && name
+          .chars()
+          .all(|c| c.is_alphanumeric() || c == '_' || c == '-' || c == '.')
+    };
+
+    if let Some(name) = operand.strip_prefix('@') {
+      return is_name(name).then(|| Operand::Attribute(name.to_string()));
+    }
+
+    if operand.parse::<f64>().is_ok()
+      && operand
+        .chars()
+        .all(|c| !c.is_alphabetic() || c == 'e' || c == 'E')
+    {
+      return Some(Operand::Number(operand.to_string()));
+    }
+
+    is_name(operand).then(|| Operand::Element(operand.to_string()))
+  }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Token {
+  Text(String),
+  Symbol(String),
+  Word(String),
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn supported_comparisons() {
+    let assert = Assert {
+      test: "@min <= @max and count gt 2 and label != 'none'".to_string(),
+    };
+
+    assert_eq!(
+      assert.get_comparisons(),
+      Some(vec![
+        Comparison {
+          left: Operand::Attribute("min".to_string()),
+          operator: "<=".to_string(),
+          right: Operand::Attribute("max".to_string()),
+        },
+        Comparison {
+          left: Operand::Element("count".to_string()),
+          operator: "gt".to_string(),
+          right: Operand::Number("2".to_string()),
+        },
+        Comparison {
+          left: Operand::Element("label".to_string()),
+          operator: "!=".to_string(),
+          right: Operand::Text("none".to_string()),
+        },
+      ])
+    );
+  }
+
+  #[test]
+  fn unsupported_expression() {
+    let assert = Assert {
+      test: "count(item) > 0".to_string(),
+    };
+
+    assert_eq!(assert.get_comparisons(), None);
+
+    let assert = Assert {
+      test: "@a or @b".to_string(),
+    };
+
+    assert_eq!(assert.get_comparisons(), None);
+  }
+
+  #[test]
+  fn quoted_operators() {
+    let assert = Assert {
+      test: r#"label = 'a and b' and note != "x < y""#.to_string(),
+    };
+
+    assert_eq!(
+      assert.get_comparisons(),
+      Some(vec![
+        Comparison {
+          left: Operand::Element("label".to_string()),
+          operator: "=".to_string(),
+          right: Operand::Text("a and b".to_string()),
+        },
+        Comparison {
+          left: Operand::Element("note".to_string()),
+          operator: "!=".to_string(),
+          right: Operand::Text("x < y".to_string()),
+        },
+      ])
+    );
+  }
+
+  #[test]
+  fn number_implementation() {
+    assert_eq!(
+      Operand::get_number_implementation("-2", Some("i32")).map(|number| number.to_string()),
+      Some("- 2i32".to_string())
+    );
+    assert_eq!(
+      Operand::get_number_implementation("2", Some("f64")).map(|number| number.to_string()),
+      Some("2f64".to_string())
+    );
+    assert!(Operand::get_number_implementation("1.5", Some("i32")).is_none());
+    assert!(Operand::get_number_implementation("1", Some("String")).is_none());
+  }
+}
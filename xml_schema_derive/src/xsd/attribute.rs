@@ -1,7 +1,8 @@
 use crate::xsd::{
+  annotation::Annotation, qualification::Qualification, restriction::Restriction,
   rust_types_mapping::RustTypesMapping, simple_type::SimpleType, Implementation, XsdContext,
 };
-use heck::ToSnakeCase;
+use heck::{ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::{Span, TokenStream};
 use syn::Ident;
 
@@ -16,16 +17,20 @@ pub struct Attribute {
   pub name: Option<String>,
   #[yaserde(rename = "type", attribute)]
   pub kind: Option<String>,
-  // #[yaserde(attribute)]
-  // pub default: Option<String>,
-  // #[yaserde(attribute)]
-  // pub fixed: Option<String>,
+  #[yaserde(attribute)]
+  pub default: Option<String>,
+  #[yaserde(attribute)]
+  pub fixed: Option<String>,
   #[yaserde(rename = "use", attribute)]
   pub required: Required,
   #[yaserde(rename = "ref", attribute)]
   pub reference: Option<String>,
   #[yaserde(rename = "simpleType")]
   pub simple_type: Option<SimpleType>,
+  #[yaserde(attribute)]
+  pub form: Option<Qualification>,
+  #[yaserde(rename = "annotation")]
+  pub annotation: Option<Annotation>,
 }
 
 #[derive(Clone, Debug, Default, PartialEq, YaDeserialize)]
@@ -44,17 +49,24 @@ impl Implementation for Attribute {
     prefix: &Option<String>,
     context: &XsdContext,
   ) -> TokenStream {
+    if let Some(attribute) = self.get_referenced_attribute(context) {
+      let prefix = self
+        .reference
+        .as_ref()
+        .and_then(|reference| context.get_reference_prefix(reference))
+        .or_else(|| prefix.clone());
+
+      return attribute.implement(_namespace_definition, &prefix, context);
+    }
+
     if self.name.is_none() {
+      if let Some(reference) = &self.reference {
+        log::warn!("Unable to find the global attribute {:?}", reference);
+      }
       return quote!();
     }
     let raw_name = self.name.clone().unwrap();
-    let name = raw_name.to_snake_case();
-
-    let name = if name == "type" {
-      "kind".to_string()
-    } else {
-      name
-    };
+    let name = self.get_field_name().unwrap();
 
     let field_name = Ident::new(&name, Span::call_site());
 
@@ -65,11 +77,29 @@ impl Implementation for Attribute {
     ) {
       (None, Some(kind), None) => RustTypesMapping::get(context, kind),
       (Some(reference), None, None) => RustTypesMapping::get(context, reference),
-      (None, None, Some(simple_type)) => simple_type.get_type_implementation(context, prefix),
+      (None, None, Some(simple_type)) => self
+        .get_inline_type_name(context)
+        .map(|type_name| quote!(#type_name))
+        .unwrap_or_else(|| simple_type.get_type_implementation(context, prefix)),
       (_, _, _) => panic!("Not implemented Rust type for: {:?}", self),
     };
 
-    let rust_type = if self.required == Required::Optional {
+    let has_default = self
+      .kind
+      .as_ref()
+      .or(self.reference.as_ref())
+      .map(|kind| context.has_default(kind))
+      .unwrap_or(true);
+
+    let placeholder_attribute = if self.is_optional(&rust_type) || has_default {
+      quote!()
+    } else {
+      let placeholder =
+        Restriction::get_placeholder_name(context.parent_name().unwrap_or_default(), &name);
+      quote!(, default=#placeholder)
+    };
+
+    let rust_type = if self.is_optional(&rust_type) {
       quote!(Option<#rust_type>)
     } else {
       quote!(#rust_type)
@@ -81,16 +111,184 @@ impl Implementation for Attribute {
       quote!(attribute, rename=#raw_name)
     };
 
+    let form = self
+      .form
+      .as_ref()
+      .unwrap_or_else(|| context.get_attribute_form_default());
+
+    let attributes = match (form, prefix) {
+      (Qualification::Qualidified, Some(prefix)) => quote!(#attributes, prefix=#prefix),
+      _ => attributes,
+    };
+
     quote!(
-      #[yaserde(#attributes)]
+      #[yaserde(#attributes #placeholder_attribute)]
       pub #field_name: #rust_type,
     )
   }
 }
 
+impl Attribute {
+  pub fn get_subtypes_implementation(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+  ) -> TokenStream {
+    if let Some(attribute) = self.get_referenced_attribute(context) {
+      return attribute.get_subtypes_implementation(namespace_definition, prefix, context);
+    }
+
+    match (self.get_inline_type_name(context), &self.simple_type) {
+      (Some(type_name), Some(simple_type)) => {
+        match (&simple_type.union, &simple_type.restriction) {
+          (Some(union), _) => {
+            union.implement_childs(namespace_definition, prefix, context, &type_name)
+          }
+          (None, Some(restriction)) => {
+            restriction.implement_childs(namespace_definition, prefix, context, &type_name)
+          }
+          (None, None) => quote!(),
+        }
+      }
+      _ => quote!(),
+    }
+  }
+
+  pub fn get_field_name(&self) -> Option<String> {
+    if let Some(field_name) = self
+      .annotation
+      .as_ref()
+      .and_then(|annotation| annotation.get_field_name())
+    {
+      return Some(field_name);
+    }
+
+    let name = match (&self.name, &self.reference) {
+      (Some(name), _) => name,
+      (None, Some(reference)) => reference.split(':').next_back().unwrap_or(reference),
+      (None, None) => return None,
+    }
+    .to_snake_case();
+
+    if name == "type" {
+      Some("kind".to_string())
+    } else {
+      Some(name)
+    }
+  }
+
+  /// Expression building the schema `default` (or `fixed`) value of the field,
+  /// for built-in types and inline enumerations only.
+  pub fn get_default_implementation(&self, context: &XsdContext) -> Option<TokenStream> {
+    if let Some(attribute) = self.get_referenced_attribute(context) {
+      return attribute.get_default_implementation(context);
+    }
+
+    let default = self.default.as_ref().or(self.fixed.as_ref())?;
+
+    if let Some(default) = self.get_enumeration_default_implementation(context, default) {
+      return Some(default);
+    }
+
+    let kind = self.kind.as_ref()?;
+
+    if !RustTypesMapping::is_xml_schema_type(context, kind) {
+      return None;
+    }
+
+    let default = RustTypesMapping::get_literal(kind, default)?;
+
+    if self.is_optional(&RustTypesMapping::get(context, kind)) {
+      Some(quote!(Some(#default)))
+    } else {
+      Some(default)
+    }
+  }
+
+  /// Variant of the inline enumeration matching the default value, resolved at generation time.
+  fn get_enumeration_default_implementation(
+    &self,
+    context: &XsdContext,
+    default: &str,
+  ) -> Option<TokenStream> {
+    let restriction = self
+      .simple_type
+      .as_ref()?
+      .restriction
+      .as_ref()
+      .filter(|restriction| restriction.is_enumeration())?;
+    let type_name = self.get_inline_type_name(context)?;
+
+    let Some(variant_name) = restriction.get_enumeration_variant(default) else {
+      panic!(
+        "[Attribute] default value {:?} of {} is not one of its enumerations",
+        default, type_name
+      );
+    };
+
+    if self.required == Required::Optional {
+      Some(quote!(Some(#type_name::#variant_name)))
+    } else {
+      Some(quote!(#type_name::#variant_name))
+    }
+  }
+
+  /// The global declaration referenced by `ref`, keeping the `use`, `default` and `fixed` of the reference.
+  /// Global attributes belong to the target namespace, they are always qualified.
+  pub fn get_referenced_attribute(&self, context: &XsdContext) -> Option<Attribute> {
+    if self.name.is_some() {
+      return None;
+    }
+
+    let attribute = context.get_attribute(self.reference.as_ref()?)?;
+
+    Some(Attribute {
+      required: self.required.clone(),
+      default: self.default.clone().or_else(|| attribute.default.clone()),
+      fixed: self.fixed.clone().or_else(|| attribute.fixed.clone()),
+      form: Some(Qualification::Qualidified),
+      reference: None,
+      ..attribute.clone()
+    })
+  }
+
+  pub fn is_optional(&self, rust_type: &TokenStream) -> bool {
+    // a missing whitespace-separated list is an empty one
+    self.required == Required::Optional && !rust_type.to_string().starts_with("Vec")
+  }
+
+  /// Name of the dedicated type generated for an inline enumeration or union, prefixed by the parent type.
+  fn get_inline_type_name(&self, context: &XsdContext) -> Option<Ident> {
+    let has_inline_type = self
+      .simple_type
+      .as_ref()
+      .map(|simple_type| {
+        simple_type.union.is_some()
+          || simple_type
+            .restriction
+            .as_ref()
+            .map(|restriction| restriction.is_enumeration())
+            .unwrap_or_default()
+      })
+      .unwrap_or_default();
+
+    match (&self.name, has_inline_type) {
+      (Some(name), true) => {
+        let type_name = format!("{}_{}", context.parent_name().unwrap_or_default(), name)
+          .replace('.', "_")
+          .to_upper_camel_case();
+        Some(Ident::new(&type_name, Span::call_site()))
+      }
+      _ => None,
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::xsd::{enumeration::Enumeration, restriction::Restriction};
   use std::str::FromStr;
 
   #[test]
@@ -98,6 +296,67 @@ mod tests {
     assert_eq!(Required::default(), Required::Optional);
   }
 
+  #[test]
+  fn enumeration_default() {
+    let attribute = Attribute {
+      name: Some("state".to_string()),
+      default: Some("off".to_string()),
+      simple_type: Some(SimpleType {
+        restriction: Some(Restriction {
+          base: Some("xs:string".to_string()),
+          enumerations: vec![
+            Enumeration {
+              value: "on".to_string(),
+            },
+            Enumeration {
+              value: "off".to_string(),
+            },
+          ],
+          ..Default::default()
+        }),
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+
+    let mut context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+    context.set_parent_name("Light");
+
+    let implementation = attribute.get_default_implementation(&context).unwrap();
+
+    assert_eq!(
+      implementation.to_string(),
+      "Some (LightState :: Off)".to_string()
+    );
+  }
+
+  #[test]
+  #[should_panic]
+  fn unknown_enumeration_default() {
+    let attribute = Attribute {
+      name: Some("state".to_string()),
+      default: Some("dimmed".to_string()),
+      simple_type: Some(SimpleType {
+        restriction: Some(Restriction {
+          enumerations: vec![Enumeration {
+            value: "on".to_string(),
+          }],
+          ..Default::default()
+        }),
+        ..Default::default()
+      }),
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    attribute.get_default_implementation(&context);
+  }
+
   #[test]
   fn string_attribute() {
     let attribute = Attribute {
@@ -106,6 +365,10 @@ mod tests {
       reference: None,
       required: Required::Required,
       simple_type: None,
+      default: None,
+      fixed: None,
+      form: None,
+      annotation: None,
     };
 
     let context =
@@ -133,6 +396,10 @@ mod tests {
       reference: None,
       required: Required::Optional,
       simple_type: None,
+      default: None,
+      fixed: None,
+      form: None,
+      annotation: None,
     };
 
     let context =
@@ -160,6 +427,10 @@ mod tests {
       reference: None,
       required: Required::Optional,
       simple_type: None,
+      default: None,
+      fixed: None,
+      form: None,
+      annotation: None,
     };
 
     let context =
@@ -187,6 +458,10 @@ mod tests {
       reference: Some("MyType".to_string()),
       required: Required::Optional,
       simple_type: None,
+      default: None,
+      fixed: None,
+      form: None,
+      annotation: None,
     };
 
     let context =
@@ -206,6 +481,57 @@ mod tests {
     assert_eq!(implementation.to_string(), expected.to_string());
   }
 
+  #[test]
+  fn qualified_attribute() {
+    let attribute = Attribute {
+      name: Some("language".to_string()),
+      kind: Some("xs:string".to_string()),
+      reference: None,
+      required: Required::Required,
+      simple_type: None,
+      default: None,
+      fixed: None,
+      form: Some(Qualification::Qualidified),
+      annotation: None,
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation =
+      attribute.implement(&TokenStream::new(), &Some("ex".to_string()), &context);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(attribute, prefix="ex")]
+        pub language: String,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+
+    let attribute = Attribute {
+      form: None,
+      annotation: None,
+      ..attribute
+    };
+
+    let implementation =
+      attribute.implement(&TokenStream::new(), &Some("ex".to_string()), &context);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(attribute)]
+        pub language: String,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
   #[test]
   #[should_panic]
   fn bad_type_attribute() {
@@ -215,6 +541,10 @@ mod tests {
       reference: None,
       required: Required::Optional,
       simple_type: None,
+      default: None,
+      fixed: None,
+      form: None,
+      annotation: None,
     };
 
     let context =
@@ -232,6 +562,10 @@ mod tests {
       reference: None,
       required: Required::Optional,
       simple_type: None,
+      default: None,
+      fixed: None,
+      form: None,
+      annotation: None,
     };
 
     let context =
@@ -10,7 +10,7 @@ pub struct AttributeGroup {
   #[yaserde(prefix = "xs", attribute)]
   pub name: Option<String>,
   #[yaserde(rename = "ref", attribute)]
-  pub reference: String,
+  pub reference: Option<String>,
   #[yaserde(rename = "attribute")]
   pub attributes: Vec<Attribute>,
   // #[yaserde(rename = "attributeGroup")]
@@ -0,0 +1,434 @@
+use crate::xsd::{element::Element, max_occurences::MaxOccurences, Implementation, XsdContext};
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::{Span, TokenStream};
+use syn::{FieldsNamed, GenericArgument, Ident, PathArguments, Type};
+
+/// Types read from the text of their element, which do not implement `YaDeserialize`.
+const TEXT_TYPES: &[&str] = &[
+  "String", "bool", "i8", "u8", "i16", "u16", "i32", "u32", "i64", "u64", "f32", "f64",
+];
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct Choice {
+  #[yaserde(rename = "minOccurs", attribute)]
+  pub min_occurences: Option<u64>,
+  #[yaserde(rename = "maxOccurs", attribute)]
+  pub max_occurences: Option<MaxOccurences>,
+  #[yaserde(rename = "element")]
+  pub elements: Vec<Element>,
+}
+
+/// Enumeration with a variant for each element of the choice, named `enum_name`.
+/// The enumeration is (de)serialized as a flattened field: it reads the children of the element
+/// holding it, and writes the element of its variant.
+impl Implementation for Choice {
+  fn implement_childs(
+    &self,
+    _namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    enum_name: &Ident,
+  ) -> TokenStream {
+    let variants = self.get_variants(context, prefix);
+
+    let Some((default_variant, _, _)) = variants.first() else {
+      log::warn!("No element in the choice {enum_name}");
+      return quote!();
+    };
+
+    let non_exhaustive = context.get_non_exhaustive_attribute();
+    let names = variants.iter().map(|(_, name, _)| name);
+
+    let variants_definition = variants
+      .iter()
+      .map(|(variant_name, _, rust_type)| quote!(#variant_name(#rust_type),));
+
+    let deserialize_variants = variants.iter().map(|(variant_name, name, rust_type)| {
+      if Choice::is_text_type(rust_type) {
+        quote!(
+          #name => {
+            return reader
+              .read_inner_value(|reader| match reader.peek()?.to_owned() {
+                xml::reader::XmlEvent::Characters(text) => {
+                  reader.next_event()?;
+                  text.parse::<#rust_type>().map_err(|e| e.to_string())
+                }
+                _ => "".parse::<#rust_type>().map_err(|e| e.to_string()),
+              })
+              .map(#enum_name::#variant_name);
+          }
+        )
+      } else {
+        quote!(
+          #name => {
+            return <#rust_type as yaserde::YaDeserialize>::deserialize(reader).map(#enum_name::#variant_name);
+          }
+        )
+      }
+    });
+
+    let serialize_variants = variants.iter().map(|(variant_name, name, rust_type)| {
+      if Choice::is_text_type(rust_type) {
+        quote!(
+          #enum_name::#variant_name(value) => {
+            writer.write(xml::writer::XmlEvent::start_element(#name)).map_err(|e| e.to_string())?;
+            writer.write(xml::writer::XmlEvent::characters(&value.to_string())).map_err(|e| e.to_string())?;
+            writer.write(xml::writer::XmlEvent::end_element()).map_err(|e| e.to_string())
+          }
+        )
+      } else {
+        quote!(
+          #enum_name::#variant_name(value) => {
+            writer.set_start_event_name(Some(#name.to_string()));
+            writer.set_skip_start_end(false);
+            yaserde::YaSerialize::serialize(value, writer)
+          }
+        )
+      }
+    });
+
+    let variant_names = variants
+      .iter()
+      .map(|(variant_name, name, _)| quote!(#enum_name::#variant_name(_) => #name,));
+
+    let enum_name_string = enum_name.to_string();
+
+    quote!(
+      #[derive(Clone, Debug, PartialEq)]
+      #non_exhaustive
+      pub enum #enum_name {
+        #(#variants_definition)*
+      }
+
+      impl #enum_name {
+        /// Names of the elements of the choice.
+        pub const ELEMENTS: &'static [&'static str] = &[#(#names),*];
+
+        /// Name of the element of the active variant, as declared in the schema.
+        pub fn variant_name(&self) -> &'static str {
+          match self {
+            #(#variant_names)*
+          }
+        }
+      }
+
+      impl Default for #enum_name {
+        fn default() -> Self {
+          #enum_name::#default_variant(Default::default())
+        }
+      }
+
+      impl yaserde::YaDeserialize for #enum_name {
+        fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
+          // the children of the element holding the choice are matched, not their descendants
+          let start_depth = reader.depth();
+
+          loop {
+            match reader.peek()?.to_owned() {
+              xml::reader::XmlEvent::StartElement { name, .. } if reader.depth() == start_depth + 1 => {
+                match name.local_name.as_str() {
+                  #(#deserialize_variants)*
+                  // elements of the other flattened fields, or matching none of the fields, which
+                  // the structure holding the choice captures with the capture_unknown option
+                  _ => {
+                    reader.next_event()?;
+                    reader.skip_element(|_| {})?;
+                  }
+                }
+              }
+              xml::reader::XmlEvent::EndElement { .. } if reader.depth() == start_depth + 1 => {
+                return Err(format!("No element of the choice {} found", #enum_name_string));
+              }
+              xml::reader::XmlEvent::EndDocument => {
+                return Err(format!("No element of the choice {} found", #enum_name_string));
+              }
+              _ => {
+                reader.next_event()?;
+              }
+            }
+          }
+        }
+      }
+
+      impl yaserde::YaSerialize for #enum_name {
+        fn serialize<W: std::io::Write>(&self, writer: &mut yaserde::ser::Serializer<W>) -> Result<(), String> {
+          match self {
+            #(#serialize_variants)*
+          }
+        }
+
+        fn serialize_attributes(&self, source_attributes: Vec<xml::attribute::OwnedAttribute>, source_namespace: xml::namespace::Namespace) -> Result<(Vec<xml::attribute::OwnedAttribute>, xml::namespace::Namespace), String> {
+          Ok((source_attributes, source_namespace))
+        }
+      }
+    )
+  }
+}
+
+impl Choice {
+  /// Name of the enumeration of the choice `index` of the parent type, the first one being
+  /// `ParentChoice` and the next ones `ParentChoice2`, `ParentChoice3`...
+  pub fn get_type_name(context: &XsdContext, index: usize) -> Ident {
+    let parent_name = context.parent_name().unwrap_or_default().replace('.', "_");
+
+    let type_name = if index == 0 {
+      format!("{parent_name}_choice")
+    } else {
+      format!("{parent_name}_choice_{}", index + 1)
+    };
+
+    Ident::new(&type_name.to_upper_camel_case(), Span::call_site())
+  }
+
+  /// Enumeration of the choice and the inline types of its elements.
+  pub fn get_subtypes_implementation(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    index: usize,
+  ) -> TokenStream {
+    let enum_name = Choice::get_type_name(context, index);
+    let enumeration = self.implement_childs(namespace_definition, prefix, context, &enum_name);
+
+    let elements_sub_types: TokenStream = self
+      .elements
+      .iter()
+      .map(|element| element.get_subtypes_implementation(namespace_definition, prefix, context))
+      .collect();
+
+    let iterators = self.get_iterators_implementation(prefix, context, index);
+
+    quote!(
+      #enumeration
+      #iterators
+      #elements_sub_types
+    )
+  }
+
+  /// `iter_<element>()` on the structure holding a repeated choice, for each of its elements,
+  /// going through the occurrences of that element only.
+  fn get_iterators_implementation(
+    &self,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    index: usize,
+  ) -> TokenStream {
+    if !self.is_multiple() {
+      return quote!();
+    }
+
+    let struct_name = Ident::new(
+      &context
+        .parent_name()
+        .unwrap_or_default()
+        .replace('.', "_")
+        .to_upper_camel_case(),
+      Span::call_site(),
+    );
+    let enum_name = Choice::get_type_name(context, index);
+    let field_name = Ident::new(
+      &format!("{}_list", Choice::get_field_name(index)),
+      Span::call_site(),
+    );
+
+    let iterators =
+      self
+        .get_variants(context, prefix)
+        .into_iter()
+        .map(|(variant_name, name, rust_type)| {
+          let iterator_name = format_ident!("iter_{}", name.replace('.', "_").to_snake_case());
+
+          quote!(
+            pub fn #iterator_name(&self) -> impl Iterator<Item = &#rust_type> {
+              self.#field_name.iter().filter_map(|item| match item {
+                #enum_name::#variant_name(value) => Some(value),
+                #[allow(unreachable_patterns)]
+                _ => None,
+              })
+            }
+          )
+        });
+
+    quote!(
+      impl #struct_name {
+        #(#iterators)*
+      }
+    )
+  }
+
+  fn get_field_name(index: usize) -> String {
+    if index == 0 {
+      "choice".to_string()
+    } else {
+      format!("choice_{}", index + 1)
+    }
+  }
+
+  /// Whether `field` is the flattened field of a choice, named by `get_field_implementation`.
+  pub fn is_field(field: &syn::Field) -> bool {
+    let Some(field_name) = &field.ident else {
+      return false;
+    };
+
+    let field_name = field_name.to_string();
+    let field_name = field_name.strip_suffix("_list").unwrap_or(&field_name);
+
+    field_name == "choice"
+      || field_name
+        .strip_prefix("choice_")
+        .is_some_and(|index| index.parse::<usize>().is_ok())
+  }
+
+  /// Flattened field holding the choice, a `Vec` when the choice repeats and an `Option` when it
+  /// may be absent.
+  pub fn get_field_implementation(&self, context: &XsdContext, index: usize) -> TokenStream {
+    if self.elements.is_empty() {
+      return quote!();
+    }
+
+    // the enumeration is generated beside the structure holding the field
+    let enum_name = Choice::get_type_name(context, index);
+
+    let field_name = Choice::get_field_name(index);
+
+    let (field_name, rust_type) = if self.is_multiple() {
+      (format!("{field_name}_list"), quote!(Vec<#enum_name>))
+    } else if self.min_occurences == Some(0) {
+      (field_name, quote!(Option<#enum_name>))
+    } else {
+      (field_name, quote!(#enum_name))
+    };
+
+    let field_name = Ident::new(&field_name.to_snake_case(), Span::call_site());
+
+    quote!(
+      #[yaserde(flatten)]
+      pub #field_name: #rust_type,
+    )
+  }
+
+  pub fn is_multiple(&self) -> bool {
+    !matches!(
+      self.max_occurences.clone().unwrap_or_default(),
+      MaxOccurences::Number { value: 0 | 1 }
+    )
+  }
+
+  /// Variant name, element name and type of each element of the choice. The type is the one of
+  /// a single occurrence of the element, as the choice holds one element.
+  fn get_variants(
+    &self,
+    context: &XsdContext,
+    prefix: &Option<String>,
+  ) -> Vec<(Ident, String, Type)> {
+    self
+      .elements
+      .iter()
+      .filter_map(|element| {
+        let field = element.get_field_implementation(context, prefix);
+        let field: FieldsNamed =
+          syn::parse2(quote!({ #field })).expect("[Choice] unable to parse element field");
+        let field = field.named.first()?;
+
+        let name = if element.name.is_empty() {
+          let refers = element.refers.as_deref()?;
+          refers.split(':').next_back().unwrap_or(refers).to_string()
+        } else {
+          element.name.clone()
+        };
+
+        if element.is_multiple() {
+          log::warn!("The repeated element {name} of a choice is read as a single occurrence");
+        }
+
+        let variant_name = Ident::new(
+          &name.replace('.', "_").to_upper_camel_case(),
+          Span::call_site(),
+        );
+
+        Some((variant_name, name, Choice::get_single_type(&field.ty)))
+      })
+      .collect()
+  }
+
+  /// Type inside the `Option` or `Vec` of an optional or repeated element.
+  pub fn get_single_type(rust_type: &Type) -> Type {
+    if let Type::Path(path) = rust_type {
+      if let Some(segment) = path.path.segments.last() {
+        if segment.ident == "Option" || segment.ident == "Vec" {
+          if let PathArguments::AngleBracketed(arguments) = &segment.arguments {
+            if let Some(GenericArgument::Type(inner_type)) = arguments.args.first() {
+              return inner_type.clone();
+            }
+          }
+        }
+      }
+    }
+
+    rust_type.clone()
+  }
+
+  fn is_text_type(rust_type: &Type) -> bool {
+    match rust_type {
+      Type::Path(path) => path
+        .path
+        .get_ident()
+        .map(|ident| TEXT_TYPES.contains(&ident.to_string().as_str()))
+        .unwrap_or_default(),
+      _ => false,
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::str::FromStr;
+  use yaserde::de::from_str;
+
+  #[test]
+  fn repeated_choice_field() {
+    let xml = r#"
+      <xs:choice xmlns:xs="http://www.w3.org/2001/XMLSchema" maxOccurs="unbounded">
+        <xs:element name="note" type="xs:string"/>
+        <xs:element name="item" type="xs:int"/>
+      </xs:choice>
+    "#;
+
+    let choice: Choice = from_str(xml).unwrap();
+
+    let mut context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+    context.set_parent_name("order");
+
+    let implementation = choice.get_field_implementation(&context, 1);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(flatten)]
+        pub choice_2_list: Vec<OrderChoice2> ,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn choice_fields() {
+    let fields: FieldsNamed = syn::parse2(quote!({
+      choice: OrderChoice,
+      choice_2_list: Vec<OrderChoice2>,
+      choice_note: String,
+      item: i32,
+    }))
+    .unwrap();
+
+    let choice_fields: Vec<bool> = fields.named.iter().map(Choice::is_field).collect();
+
+    assert_eq!(choice_fields, vec![true, true, false, false]);
+  }
+}
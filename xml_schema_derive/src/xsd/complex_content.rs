@@ -1,13 +1,152 @@
-use crate::xsd::{extension::Extension, xsd_context::XsdContext};
+use crate::xsd::{
+  element::Element,
+  extension::Extension,
+  sequence::Sequence,
+  xsd_context::{Inheritance, XsdContext},
+  Implementation,
+};
 use proc_macro2::TokenStream;
 
 #[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
 #[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
 pub struct ComplexContent {
   pub extension: Option<Extension>,
+  pub restriction: Option<Extension>,
+}
+
+impl Implementation for ComplexContent {
+  fn implement(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+  ) -> TokenStream {
+    // a restriction restates the whole content model, nothing comes from the base
+    let Some(extension) = &self.extension else {
+      let restriction = self.restriction.as_ref().unwrap();
+
+      let sequences: TokenStream = ComplexContent::get_restricted_sequences(restriction, context)
+        .iter()
+        .map(|sequence| sequence.implement(namespace_definition, prefix, context))
+        .collect();
+
+      let attributes: TokenStream = restriction
+        .attributes
+        .iter()
+        .map(|attribute| attribute.implement(namespace_definition, prefix, context))
+        .collect();
+
+      return quote!(
+        #sequences
+        #attributes
+      );
+    };
+
+    let inlined_base = match context.inheritance() {
+      Inheritance::Inline => {
+        let base = context.get_complex_type(&extension.base);
+        if base.is_none() {
+          log::warn!(
+            "Unable to inline {:?}, fallback to a flatten field",
+            extension.base
+          );
+        }
+
+        base.map(|base| base.get_content_implementation(namespace_definition, prefix, context))
+      }
+      Inheritance::Flatten => None,
+    };
+
+    let base = inlined_base.unwrap_or_else(|| {
+      let base = self.get_field_implementation(context, prefix);
+      quote!(
+        #[yaserde(flatten)]
+        #base,
+      )
+    });
+
+    let sequences: TokenStream = extension
+      .sequences
+      .iter()
+      .map(|sequence| sequence.implement(namespace_definition, prefix, context))
+      .collect();
+
+    let attributes: TokenStream = extension
+      .attributes
+      .iter()
+      .map(|attribute| attribute.implement(namespace_definition, prefix, context))
+      .collect();
+
+    quote!(
+      #base
+      #sequences
+      #attributes
+    )
+  }
 }
 
 impl ComplexContent {
+  /// Sequences of a restriction, resolved against the elements of its base: an element restated
+  /// without a type keeps the type of the base element, a narrowed type must derive from it.
+  pub fn get_restricted_sequences(restriction: &Extension, context: &XsdContext) -> Vec<Sequence> {
+    let base_elements: Vec<&Element> = context
+      .get_complex_type(&restriction.base)
+      .into_iter()
+      .flat_map(|base| {
+        let mut types = vec![base];
+        types.extend(context.get_ancestors(&base.name));
+        types
+      })
+      .flat_map(|complex_type| complex_type.get_sequences())
+      .flat_map(|sequence| sequence.elements.iter())
+      .filter(|element| !element.name.is_empty())
+      .collect();
+
+    restriction
+      .sequences
+      .iter()
+      .map(|sequence| {
+        let elements = sequence
+          .elements
+          .iter()
+          .map(|element| {
+            let base_element = base_elements
+              .iter()
+              .find(|base_element| !element.name.is_empty() && base_element.name == element.name);
+
+            let Some(base_kind) = base_element.and_then(|base_element| base_element.kind.as_ref())
+            else {
+              return element.clone();
+            };
+
+            match &element.kind {
+              None if element.complex_type.is_none() && element.simple_type.is_none() => Element {
+                kind: Some(base_kind.clone()),
+                ..element.clone()
+              },
+              Some(kind) if !context.is_derived_from(kind, base_kind) => {
+                log::warn!(
+                  "{} restricts the element {} to {}, which does not derive from {}",
+                  restriction.base,
+                  element.name,
+                  kind,
+                  base_kind
+                );
+                element.clone()
+              }
+              _ => element.clone(),
+            }
+          })
+          .collect();
+
+        Sequence {
+          elements,
+          ..sequence.clone()
+        }
+      })
+      .collect()
+  }
+
   pub fn get_field_implementation(
     &self,
     context: &XsdContext,
@@ -20,3 +159,129 @@ impl ComplexContent {
       .get_field_implementation(context, prefix)
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::xsd::schema::Schema;
+  use std::str::FromStr;
+  use yaserde::de::from_str;
+
+  static SCHEMA: &str = r#"
+    <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+      <xs:complexType name="Base">
+        <xs:sequence>
+          <xs:element name="id" type="xs:string"/>
+        </xs:sequence>
+      </xs:complexType>
+      <xs:complexType name="Derived">
+        <xs:complexContent>
+          <xs:extension base="Base">
+            <xs:sequence>
+              <xs:element name="label" type="xs:string"/>
+            </xs:sequence>
+          </xs:extension>
+        </xs:complexContent>
+      </xs:complexType>
+    </xs:schema>
+  "#;
+
+  fn derived_implementation(inheritance: Inheritance) -> String {
+    let schema: Schema = from_str(SCHEMA).unwrap();
+    let context = XsdContext::new(SCHEMA)
+      .unwrap()
+      .with_schema(&schema)
+      .with_inheritance(inheritance);
+
+    schema.complex_type[1]
+      .complex_content
+      .as_ref()
+      .unwrap()
+      .implement(&TokenStream::new(), &None, &context)
+      .to_string()
+  }
+
+  #[test]
+  fn inline_extension() {
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(rename = "id")]
+        pub id: String,
+        #[yaserde(rename = "label")]
+        pub label: String,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+      derived_implementation(Inheritance::Inline),
+      expected.to_string()
+    );
+  }
+
+  #[test]
+  fn flatten_extension() {
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(flatten)]
+        pub base: Base,
+        #[yaserde(rename = "label")]
+        pub label: String,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(
+      derived_implementation(Inheritance::Flatten),
+      expected.to_string()
+    );
+  }
+
+  #[test]
+  fn restriction_keeps_base_element_type() {
+    let schema_content = r#"
+      <xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:complexType name="Animal">
+          <xs:sequence>
+            <xs:element name="name" type="xs:string"/>
+          </xs:sequence>
+        </xs:complexType>
+        <xs:complexType name="Shelter">
+          <xs:sequence>
+            <xs:element name="resident" type="Animal" maxOccurs="unbounded"/>
+          </xs:sequence>
+        </xs:complexType>
+        <xs:complexType name="SingleShelter">
+          <xs:complexContent>
+            <xs:restriction base="Shelter">
+              <xs:sequence>
+                <xs:element name="resident"/>
+              </xs:sequence>
+            </xs:restriction>
+          </xs:complexContent>
+        </xs:complexType>
+      </xs:schema>
+    "#;
+
+    let schema: Schema = from_str(schema_content).unwrap();
+    let context = XsdContext::new(schema_content)
+      .unwrap()
+      .with_schema(&schema);
+
+    let implementation = schema.complex_type[2]
+      .complex_content
+      .as_ref()
+      .unwrap()
+      .implement(&TokenStream::new(), &None, &context);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(rename = "resident")]
+        pub resident: xml_schema_types::Animal,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+}
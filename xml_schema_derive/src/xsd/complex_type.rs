@@ -1,10 +1,22 @@
 use crate::xsd::{
-  annotation::Annotation, attribute::Attribute, complex_content::ComplexContent,
-  sequence::Sequence, simple_content::SimpleContent, Implementation, XsdContext,
+  annotation::Annotation,
+  assert::{Assert, Operand},
+  attribute::Attribute,
+  attribute_group::AttributeGroup,
+  choice::Choice,
+  complex_content::ComplexContent,
+  element::Element,
+  max_occurences::MaxOccurences,
+  restriction::Restriction,
+  rust_types_mapping::{BinaryEncoding, RustTypesMapping},
+  sequence::Sequence,
+  simple_content::SimpleContent,
+  Implementation, Inheritance, XsdContext,
 };
-use heck::ToUpperCamelCase;
-use proc_macro2::{Span, TokenStream};
-use syn::Ident;
+use heck::{ToShoutySnakeCase, ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::{Delimiter, Span, TokenStream, TokenTree};
+use std::collections::{BTreeMap, BTreeSet};
+use syn::{Expr, FieldsNamed, Ident, LitStr, Token, Visibility};
 
 #[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
 #[yaserde(
@@ -17,13 +29,28 @@ pub struct ComplexType {
   pub name: String,
   #[yaserde(rename = "attribute")]
   pub attributes: Vec<Attribute>,
+  #[yaserde(rename = "attributeGroup")]
+  pub attribute_groups: Vec<AttributeGroup>,
+  #[yaserde(rename = "defaultAttributesApply", attribute)]
+  pub default_attributes_apply: Option<bool>,
   pub sequence: Option<Sequence>,
+  /// `xs:all` children, read as a sequence since the element order is not checked on deserialization.
+  #[yaserde(rename = "all")]
+  pub all: Option<Sequence>,
+  pub choice: Option<Choice>,
   #[yaserde(rename = "simpleContent")]
   pub simple_content: Option<SimpleContent>,
   #[yaserde(rename = "complexContent")]
   pub complex_content: Option<ComplexContent>,
   #[yaserde(rename = "annotation")]
   pub annotation: Option<Annotation>,
+  #[yaserde(rename = "assert")]
+  pub asserts: Vec<Assert>,
+  #[yaserde(rename = "abstract", attribute)]
+  pub is_abstract: Option<bool>,
+  /// Derivations forbidden from this type, `extension`, `restriction` or `#all`.
+  #[yaserde(rename = "final", attribute)]
+  pub final_derivations: Option<String>,
 }
 
 impl Implementation for ComplexType {
@@ -37,6 +64,260 @@ impl Implementation for ComplexType {
       &self.name.replace('.', "_").to_upper_camel_case(),
       Span::call_site(),
     );
+
+    let docs = self
+      .annotation
+      .as_ref()
+      .map(|annotation| annotation.implement(namespace_definition, prefix, context))
+      .unwrap_or_default();
+
+    let stream = self.get_stream_implementation(context, &struct_name);
+    let visitor = self.get_visitor_implementation(context, &struct_name);
+
+    if let Some(element) = self.get_list_wrapper_element(context) {
+      let list_wrapper = self.get_list_wrapper_implementation(context, &struct_name, element);
+
+      return quote!(
+        #docs
+        #list_wrapper
+        #stream
+        #visitor
+      );
+    }
+
+    let fields = self.get_content_implementation(namespace_definition, prefix, context);
+    let iterators = ComplexType::get_iterators_implementation(context, &struct_name, &fields);
+    let capacity = self.get_capacity_implementation(&struct_name);
+    let fixed_constants = self.get_fixed_constants_implementation(context, &struct_name);
+    let (derive_deserialize, recursive_implementation) =
+      self.get_recursive_implementation(context, namespace_definition, &struct_name, &fields);
+    let fields = ComplexType::get_unknown_field_implementation(context, fields);
+
+    let sub_types_implementation =
+      self.get_sub_types_implementation(namespace_definition, prefix, context);
+
+    let validation = self.get_validation_implementation(context, &struct_name);
+    let constructor =
+      ComplexType::get_constructor_implementation(context, &struct_name, &fields, &validation);
+
+    let conversions = self.get_conversions_implementation(namespace_definition, prefix, context);
+    let extension_traits = self.get_extension_traits_implementation(context);
+
+    let (derive_debug, debug_implementation) =
+      self.get_debug_implementation(context, &struct_name, &fields);
+    let (derive_partial_eq, partial_eq_implementation) =
+      ComplexType::get_partial_eq_implementation(context, &struct_name, &fields);
+
+    let (derive_default, default_implementation) =
+      self.get_default_implementation(context, &struct_name, &fields);
+    let non_exhaustive = context.get_non_exhaustive_attribute();
+
+    // the getters are named after the methods generated on the structure, to not shadow them
+    let generated_methods = ComplexType::get_method_names(
+      &struct_name,
+      &quote!(
+        #iterators
+        #capacity
+        #debug_implementation
+        #partial_eq_implementation
+        #validation
+        #constructor
+        #default_implementation
+        #conversions
+        #extension_traits
+        #recursive_implementation
+        #stream
+        #visitor
+        #sub_types_implementation
+      ),
+    );
+    let (fields, accessors) =
+      ComplexType::get_accessors_implementation(context, &struct_name, fields, &generated_methods);
+
+    quote! {
+      #docs
+
+      #[derive(Clone, #derive_debug #derive_default #derive_partial_eq #derive_deserialize yaserde_derive::YaSerialize)]
+      #namespace_definition
+      #non_exhaustive
+      pub struct #struct_name {
+        #fields
+      }
+
+      #accessors
+
+      #iterators
+
+      #capacity
+
+      #fixed_constants
+
+      #debug_implementation
+
+      #partial_eq_implementation
+
+      #validation
+
+      #constructor
+
+      #default_implementation
+
+      #conversions
+
+      #extension_traits
+
+      #recursive_implementation
+
+      #stream
+
+      #visitor
+
+      #sub_types_implementation
+    }
+  }
+}
+
+impl ComplexType {
+  /// Types generated for the inline types of the elements and attributes.
+  fn get_sub_types_implementation(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+  ) -> TokenStream {
+    let mut sub_types_context = context.clone();
+    sub_types_context.set_parent_name(&self.name);
+
+    let sequences_sub_types_implementation: TokenStream = self
+      .get_sequences()
+      .iter()
+      .map(|sequence| {
+        sequence.get_sub_types_implementation(&sub_types_context, namespace_definition, prefix)
+      })
+      .collect();
+
+    let choice_sub_types_implementation = self
+      .choice
+      .as_ref()
+      .map(|choice| {
+        choice.get_subtypes_implementation(namespace_definition, prefix, &sub_types_context, 0)
+      })
+      .unwrap_or_default();
+
+    let attributes_sub_types_implementation: TokenStream = self
+      .get_attributes(context)
+      .iter()
+      .map(|attribute| {
+        attribute.get_subtypes_implementation(namespace_definition, prefix, &sub_types_context)
+      })
+      .collect();
+
+    quote!(
+      #sequences_sub_types_implementation
+      #choice_sub_types_implementation
+      #attributes_sub_types_implementation
+    )
+  }
+
+  pub fn has_choices(&self) -> bool {
+    self.choice.is_some()
+      || self
+        .sequence
+        .as_ref()
+        .is_some_and(|sequence| !sequence.choices.is_empty())
+  }
+
+  /// Enumerations of the choices of an inline type, generated beside the structure of its element.
+  pub fn get_choices_implementation(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+  ) -> TokenStream {
+    let sequence_choices = self
+      .sequence
+      .iter()
+      .flat_map(|sequence| sequence.choices.iter().enumerate());
+
+    self
+      .choice
+      .iter()
+      .enumerate()
+      .chain(sequence_choices)
+      .map(|(index, choice)| {
+        choice.get_subtypes_implementation(namespace_definition, prefix, context, index)
+      })
+      .collect()
+  }
+
+  /// `Default` derive, or implementation when fields have default values or placeholders.
+  fn get_default_implementation(
+    &self,
+    context: &XsdContext,
+    struct_name: &Ident,
+    fields: &TokenStream,
+  ) -> (TokenStream, TokenStream) {
+    let parsed_fields: FieldsNamed =
+      syn::parse2(quote!({ #fields })).expect("[ComplexType] unable to parse fields");
+    let placeholders = Restriction::get_placeholders(&parsed_fields);
+    let placeholders_implementation = Restriction::get_placeholders_implementation(&placeholders);
+
+    let mut field_defaults = self.get_field_defaults(context);
+
+    // optional elements absent from the document take their schema default while deserializing
+    let default_functions =
+      Element::get_default_functions_implementation(&parsed_fields, &field_defaults);
+    let placeholders_implementation = quote!(#placeholders_implementation #default_functions);
+
+    field_defaults.extend(
+      placeholders
+        .iter()
+        .map(|(field_name, function, _)| (field_name.to_string(), quote!(#function()))),
+    );
+
+    if field_defaults.is_empty() {
+      return (quote!(Default,), placeholders_implementation);
+    }
+
+    let fields_default = parsed_fields.named.iter().map(|field| {
+      let field_name = field.ident.as_ref().unwrap();
+      let value = field_defaults
+        .get(&field_name.to_string())
+        .cloned()
+        .unwrap_or_else(|| quote!(Default::default()));
+
+      quote!(#field_name: #value,)
+    });
+
+    (
+      quote!(),
+      quote!(
+        impl Default for #struct_name {
+          fn default() -> Self {
+            #struct_name {
+              #(#fields_default)*
+            }
+          }
+        }
+
+        #placeholders_implementation
+      ),
+    )
+  }
+
+  pub fn get_content_implementation(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+  ) -> TokenStream {
+    // inline types keep the complex type holding them as parent
+    let mut context = context.clone();
+    if !self.name.is_empty() {
+      context.set_parent_name(&self.name);
+    }
+    let context = &context;
+
     log::info!("Generate sequence");
     let sequence = self
       .sequence
@@ -44,6 +325,20 @@ impl Implementation for ComplexType {
       .map(|sequence| sequence.implement(namespace_definition, prefix, context))
       .unwrap_or_default();
 
+    log::info!("Generate all");
+    let all = self
+      .all
+      .as_ref()
+      .map(|all| all.implement(namespace_definition, prefix, context))
+      .unwrap_or_default();
+
+    log::info!("Generate choice");
+    let choice = self
+      .choice
+      .as_ref()
+      .map(|choice| choice.get_field_implementation(context, 0))
+      .unwrap_or_default();
+
     log::info!("Generate simple content");
     let simple_content = self
       .simple_content
@@ -51,84 +346,1936 @@ impl Implementation for ComplexType {
       .map(|simple_content| simple_content.implement(namespace_definition, prefix, context))
       .unwrap_or_default();
 
+    log::info!("Generate complex content");
     let complex_content = self
       .complex_content
       .as_ref()
-      .map(|complex_content| {
-        let complex_content_type = complex_content.get_field_implementation(context, prefix);
-        quote!(
-          #[yaserde(flatten)]
-          #complex_content_type,
-        )
-      })
+      .map(|complex_content| complex_content.implement(namespace_definition, prefix, context))
       .unwrap_or_default();
 
     let attributes: TokenStream = self
       .attributes
       .iter()
+      .chain(self.get_attribute_groups_attributes(context))
       .map(|attribute| attribute.implement(namespace_definition, prefix, context))
       .collect();
 
-    let sub_types_implementation = self
-      .sequence
-      .as_ref()
-      .map(|sequence| sequence.get_sub_types_implementation(context, namespace_definition, prefix))
-      .unwrap_or_default();
+    quote!(
+      #sequence
+      #all
+      #choice
+      #simple_content
+      #complex_content
+      #attributes
+    )
+  }
 
-    let docs = self
-      .annotation
+  /// The repeated child of a type only wrapping a list.
+  fn get_repeated_element(&self) -> Option<&Element> {
+    if !self.attributes.is_empty()
+      || !self.attribute_groups.is_empty()
+      || self.simple_content.is_some()
+      || self.complex_content.is_some()
+    {
+      return None;
+    }
+
+    match self.sequence.as_ref()?.elements.as_slice() {
+      [element] if element.is_multiple() && !element.name.is_empty() => Some(element),
+      _ => None,
+    }
+  }
+
+  fn get_list_wrapper_element(&self, context: &XsdContext) -> Option<&Element> {
+    if !context.has_list_wrappers() {
+      return None;
+    }
+
+    self.get_repeated_element()
+  }
+
+  fn is_simple_item(element: &Element, context: &XsdContext) -> bool {
+    element
+      .kind
       .as_ref()
-      .map(|annotation| annotation.implement(namespace_definition, prefix, context))
-      .unwrap_or_default();
+      .map(|kind| RustTypesMapping::is_xml_schema_type(context, kind))
+      .unwrap_or_default()
+      && element.fixed.is_none()
+  }
 
-    quote! {
-      #docs
+  /// Deserialize one occurrence of a repeated element from `reader` into `item`.
+  fn get_item_deserialization(element: &Element, context: &XsdContext) -> TokenStream {
+    let item_type = element.get_type_implementation(context);
+    let item_name = &element.name;
 
-      #[derive(Clone, Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize)]
-      #namespace_definition
-      pub struct #struct_name {
-        #sequence
-        #simple_content
-        #complex_content
-        #attributes
-      }
+    if ComplexType::is_simple_item(element, context) {
+      quote!(
+        reader.next_event()?;
+        let text_content = if let xml::reader::XmlEvent::Characters(ref text_content) = reader.peek()? {
+          text_content.to_string()
+        } else {
+          String::new()
+        };
+        if !text_content.is_empty() {
+          reader.next_event()?;
+        }
 
-      #sub_types_implementation
+        let item: #item_type = text_content
+          .parse()
+          .map_err(|_| format!("Unable to parse {:?} for element {}", text_content, #item_name))?;
+      )
+    } else {
+      quote!(
+        let item = <#item_type as yaserde::YaDeserialize>::deserialize(reader)?;
+      )
     }
   }
-}
 
-impl ComplexType {
-  pub fn get_field_implementation(
+  fn get_list_wrapper_implementation(
     &self,
     context: &XsdContext,
-    prefix: &Option<String>,
+    struct_name: &Ident,
+    element: &Element,
   ) -> TokenStream {
-    if self.sequence.is_some() {
-      self
-        .sequence
-        .as_ref()
-        .map(|sequence| sequence.get_field_implementation(context, prefix))
-        .unwrap_or_default()
+    let item_type = element.get_type_implementation(context);
+    let item_name = &element.name;
+    let name = &self.name;
+
+    let deserialize_item = ComplexType::get_item_deserialization(element, context);
+    let non_exhaustive = context.get_non_exhaustive_attribute();
+
+    let serialize_item = if ComplexType::is_simple_item(element, context) {
+      quote!(
+        writer.write(xml::writer::XmlEvent::start_element(#item_name)).map_err(|e| e.to_string())?;
+        let content = item.to_string();
+        writer.write(xml::writer::XmlEvent::characters(&content)).map_err(|e| e.to_string())?;
+        writer.write(xml::writer::XmlEvent::end_element()).map_err(|e| e.to_string())?;
+      )
     } else {
-      self
-        .simple_content
-        .as_ref()
-        .map(|simple_content| simple_content.get_field_implementation(context, prefix))
-        .unwrap_or_default()
+      quote!(
+        writer.set_start_event_name(Some(#item_name.to_string()));
+        writer.set_skip_start_end(false);
+        yaserde::YaSerialize::serialize(item, writer)?;
+      )
+    };
+
+    quote!(
+      #[derive(Clone, Debug, Default, PartialEq)]
+      #non_exhaustive
+      pub struct #struct_name(pub Vec<#item_type>);
+
+      impl std::ops::Deref for #struct_name {
+        type Target = Vec<#item_type>;
+
+        fn deref(&self) -> &Self::Target {
+          &self.0
+        }
+      }
+
+      impl std::ops::DerefMut for #struct_name {
+        fn deref_mut(&mut self) -> &mut Self::Target {
+          &mut self.0
+        }
+      }
+
+      impl From<Vec<#item_type>> for #struct_name {
+        fn from(items: Vec<#item_type>) -> Self {
+          #struct_name(items)
+        }
+      }
+
+      impl yaserde::YaDeserialize for #struct_name {
+        fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
+          if let xml::reader::XmlEvent::StartElement{..} = reader.peek()? {
+            reader.next_event()?;
+          }
+
+          let mut items = vec![];
+
+          loop {
+            match reader.peek()?.to_owned() {
+              xml::reader::XmlEvent::StartElement{ref name, ..} if name.local_name == #item_name => {
+                #deserialize_item
+                items.push(item);
+                // read EndElement
+                reader.next_event()?;
+              }
+              xml::reader::XmlEvent::StartElement{..} => {
+                reader.next_event()?;
+                reader.skip_element(|_event| {})?;
+              }
+              xml::reader::XmlEvent::EndElement{..} | xml::reader::XmlEvent::EndDocument => break,
+              _ => {
+                reader.next_event()?;
+              }
+            }
+          }
+
+          Ok(#struct_name(items))
+        }
+      }
+
+      impl yaserde::YaSerialize for #struct_name {
+        fn serialize<W: std::io::Write>(&self, writer: &mut yaserde::ser::Serializer<W>) -> Result<(), String> {
+          let skip_start_end = writer.skip_start_end();
+          let name = writer.get_start_event_name().unwrap_or_else(|| #name.to_string());
+
+          if !skip_start_end {
+            writer.write(xml::writer::XmlEvent::start_element(name.as_str())).map_err(|e| e.to_string())?;
+          }
+
+          for item in self.0.iter() {
+            #serialize_item
+          }
+
+          if !skip_start_end {
+            writer.write(xml::writer::XmlEvent::end_element()).map_err(|e| e.to_string())?;
+          }
+
+          Ok(())
+        }
+
+        fn serialize_attributes(&self, source_attributes: Vec<xml::attribute::OwnedAttribute>, source_namespace: xml::namespace::Namespace) -> Result<(Vec<xml::attribute::OwnedAttribute>, xml::namespace::Namespace), String> {
+          Ok((source_attributes, source_namespace))
+        }
+      }
+    )
+  }
+
+  /// Iterator over the repeated child of a list type, reading the document progressively.
+  fn get_stream_implementation(&self, context: &XsdContext, struct_name: &Ident) -> TokenStream {
+    if !context.has_streams() {
+      return quote!();
+    }
+
+    let Some(element) = self.get_repeated_element() else {
+      return quote!();
+    };
+
+    let stream_name = format_ident!("{}Stream", struct_name);
+    let item_type = element.get_type_implementation(context);
+    let item_name = &element.name;
+    let deserialize_item = ComplexType::get_item_deserialization(element, context);
+    let non_exhaustive = context.get_non_exhaustive_attribute();
+
+    quote!(
+      impl #struct_name {
+        /// Stream the repeated elements without loading the whole document.
+        pub fn stream<R: std::io::Read>(reader: R) -> #stream_name<R> {
+          #stream_name {
+            reader: yaserde::de::Deserializer::new_from_reader(reader),
+          }
+        }
+      }
+
+      #non_exhaustive
+      pub struct #stream_name<R: std::io::Read> {
+        reader: yaserde::de::Deserializer<R>,
+      }
+
+      impl<R: std::io::Read> #stream_name<R> {
+        fn next_item(&mut self) -> Result<Option<#item_type>, String> {
+          let reader = &mut self.reader;
+
+          loop {
+            match reader.peek()?.to_owned() {
+              xml::reader::XmlEvent::StartElement{ref name, ..} if reader.depth() == 1 && name.local_name == #item_name => {
+                #deserialize_item
+                // read EndElement
+                reader.next_event()?;
+                return Ok(Some(item));
+              }
+              xml::reader::XmlEvent::StartElement{..} if reader.depth() > 0 => {
+                reader.next_event()?;
+                reader.skip_element(|_event| {})?;
+              }
+              xml::reader::XmlEvent::EndDocument => return Ok(None),
+              _ => {
+                reader.next_event()?;
+              }
+            }
+          }
+        }
+      }
+
+      impl<R: std::io::Read> Iterator for #stream_name<R> {
+        type Item = Result<#item_type, String>;
+
+        fn next(&mut self) -> Option<Self::Item> {
+          self.next_item().transpose()
+        }
+      }
+    )
+  }
+
+  /// Visitor trait with an `on_element` callback per repeated element of the type, and a
+  /// `visit(reader, visitor)` parser handing each of them to the visitor without building the
+  /// whole structure.
+  fn get_visitor_implementation(&self, context: &XsdContext, struct_name: &Ident) -> TokenStream {
+    if !context.has_visitors() {
+      return quote!();
+    }
+
+    let elements: Vec<&Element> = self
+      .get_sequences()
+      .into_iter()
+      .flat_map(|sequence| sequence.elements.iter())
+      .filter(|element| element.is_multiple() && !element.name.is_empty())
+      .collect();
+
+    if elements.is_empty() {
+      return quote!();
+    }
+
+    let visitor_name = format_ident!("{}Visitor", struct_name);
+
+    let (callbacks, visits): (Vec<TokenStream>, Vec<TokenStream>) = elements
+      .iter()
+      .map(|element| {
+        let callback_name = format_ident!("on_{}", element.name.to_snake_case());
+        let item_type = element.get_type_implementation(context);
+        let item_name = &element.name;
+        let deserialize_item = ComplexType::get_item_deserialization(element, context);
+
+        let callback = quote!(
+          fn #callback_name(&mut self, _item: #item_type) {}
+        );
+
+        let visit = quote!(
+          xml::reader::XmlEvent::StartElement{ref name, ..} if reader.depth() == 1 && name.local_name == #item_name => {
+            #deserialize_item
+            // read EndElement
+            reader.next_event()?;
+            visitor.#callback_name(item);
+          }
+        );
+
+        (callback, visit)
+      })
+      .unzip();
+
+    quote!(
+      /// Callbacks of the repeated elements met by `visit`, the default ones ignoring them.
+      pub trait #visitor_name {
+        #(#callbacks)*
+      }
+
+      impl #struct_name {
+        /// Parse the document, handing each repeated element to the visitor instead of collecting them.
+        pub fn visit<R: std::io::Read, V: #visitor_name>(reader: R, visitor: &mut V) -> Result<(), String> {
+          let mut reader = yaserde::de::Deserializer::new_from_reader(reader);
+          let reader = &mut reader;
+
+          loop {
+            match reader.peek()?.to_owned() {
+              #(#visits)*
+              xml::reader::XmlEvent::StartElement{..} if reader.depth() > 0 => {
+                reader.next_event()?;
+                reader.skip_element(|_event| {})?;
+              }
+              xml::reader::XmlEvent::EndDocument => return Ok(()),
+              _ => {
+                reader.next_event()?;
+              }
+            }
+          }
+        }
+      }
+    )
+  }
+
+  /// Base type of the simple content, extended or restricted.
+  pub fn get_simple_content_base(&self) -> Option<&String> {
+    let simple_content = self.simple_content.as_ref()?;
+
+    match (&simple_content.extension, &simple_content.restriction) {
+      (Some(extension), _) => Some(&extension.base),
+      (None, Some(restriction)) => restriction.base.as_ref(),
+      (None, None) => None,
+    }
+  }
+
+  /// Names of the methods of the impl blocks of `struct_name` in `implementation`, including the
+  /// trait implementations.
+  fn get_method_names(struct_name: &Ident, implementation: &TokenStream) -> BTreeSet<String> {
+    let tokens: Vec<TokenTree> = implementation.clone().into_iter().collect();
+    let mut method_names = BTreeSet::new();
+
+    for (index, token) in tokens.iter().enumerate() {
+      let TokenTree::Group(body) = token else {
+        continue;
+      };
+
+      let is_impl_block = body.delimiter() == Delimiter::Brace
+        && matches!(&tokens[..index], [.., TokenTree::Ident(name)] if name == struct_name)
+        && tokens[..index]
+          .iter()
+          .rev()
+          .take_while(|token| !matches!(token, TokenTree::Group(_)))
+          .any(|token| matches!(token, TokenTree::Ident(keyword) if keyword == "impl"));
+
+      if !is_impl_block {
+        continue;
+      }
+
+      let body: Vec<TokenTree> = body.stream().into_iter().collect();
+      method_names.extend(body.windows(2).filter_map(|window| match window {
+        [TokenTree::Ident(keyword), TokenTree::Ident(name)] if keyword == "fn" => {
+          Some(name.to_string())
+        }
+        _ => None,
+      }));
+    }
+
+    method_names
+  }
+
+  /// Getters and setters of the fields, which become private. The getter of a field named after
+  /// one of the `generated_methods` is prefixed with `get_`.
+  fn get_accessors_implementation(
+    context: &XsdContext,
+    struct_name: &Ident,
+    fields: TokenStream,
+    generated_methods: &BTreeSet<String>,
+  ) -> (TokenStream, TokenStream) {
+    if !context.has_accessors() {
+      return (fields, quote!());
+    }
+
+    let mut parsed_fields: FieldsNamed =
+      syn::parse2(quote!({ #fields })).expect("[ComplexType] unable to parse fields");
+
+    let accessors = parsed_fields
+      .named
+      .iter_mut()
+      .map(|field| {
+        field.vis = Visibility::Inherited;
+
+        let field_name = field.ident.as_ref().unwrap();
+        let field_type = &field.ty;
+        let name = field_name.to_string();
+        let name = name.trim_start_matches("r#");
+        let setter_name = format_ident!("set_{}", name);
+
+        let getter_name = if generated_methods.contains(name) {
+          format_ident!("get_{}", name)
+        } else {
+          field_name.clone()
+        };
+
+        quote!(
+          pub fn #getter_name(&self) -> &#field_type {
+            &self.#field_name
+          }
+
+          pub fn #setter_name(&mut self, value: #field_type) {
+            self.#field_name = value;
+          }
+        )
+      })
+      .collect::<Vec<TokenStream>>();
+
+    let named = &parsed_fields.named;
+
+    (
+      quote!(#named),
+      quote!(
+        impl #struct_name {
+          #(#accessors)*
+        }
+      ),
+    )
+  }
+
+  /// `iter_<field>()` for every repeated element, attributes lists are skipped.
+  fn get_iterators_implementation(
+    context: &XsdContext,
+    struct_name: &Ident,
+    fields: &TokenStream,
+  ) -> TokenStream {
+    if !context.has_iterators() {
+      return quote!();
+    }
+
+    let parsed_fields: FieldsNamed =
+      syn::parse2(quote!({ #fields })).expect("[ComplexType] unable to parse fields");
+
+    let iterators = parsed_fields
+      .named
+      .iter()
+      .filter(|field| {
+        !field.attrs.iter().any(|attribute| {
+          attribute.path().is_ident("yaserde")
+            && attribute
+              .meta
+              .require_list()
+              .ok()
+              .and_then(|list| list.tokens.clone().into_iter().next())
+              .map(|token| token.to_string() == "attribute")
+              .unwrap_or_default()
+        })
+      })
+      .filter_map(|field| {
+        let field_type = &field.ty;
+        let field_type = quote!(#field_type).to_string();
+        let item_type = field_type
+          .strip_prefix("Vec < ")
+          .and_then(|item_type| item_type.strip_suffix(" >"))?;
+        let item_type: TokenStream = item_type
+          .parse()
+          .expect("[ComplexType] unable to parse item type");
+
+        let field_name = field.ident.as_ref().unwrap();
+        let iterator_name =
+          format_ident!("iter_{}", field_name.to_string().trim_start_matches("r#"));
+
+        Some(quote!(
+          pub fn #iterator_name(&self) -> impl Iterator<Item = &#item_type> {
+            self.#field_name.iter()
+          }
+        ))
+      })
+      .collect::<Vec<TokenStream>>();
+
+    if iterators.is_empty() {
+      return quote!();
     }
+
+    quote!(
+      impl #struct_name {
+        #(#iterators)*
+      }
+    )
   }
 
-  pub fn get_integrated_implementation(&self, parent_name: &str) -> TokenStream {
-    if self.simple_content.is_some() {
-      return quote!(String);
+  /// `with_capacity()` constructor pre-allocating the repeated elements with a bounded `maxOccurs`.
+  fn get_capacity_implementation(&self, struct_name: &Ident) -> TokenStream {
+    let capacities = self
+      .get_sequences()
+      .into_iter()
+      .flat_map(|sequence| sequence.elements.iter())
+      .filter(|element| element.is_multiple())
+      .filter(|element| !element.name.is_empty() || element.refers.is_some())
+      .filter_map(|element| match element.get_max_occurences() {
+        MaxOccurences::Number { value } if value > 1 => {
+          let field_name = Ident::new(&element.get_field_name(), Span::call_site());
+          let capacity = value as usize;
+          Some(quote!(#field_name: Vec::with_capacity(#capacity),))
+        }
+        _ => None,
+      })
+      .collect::<Vec<TokenStream>>();
+
+    if capacities.is_empty() {
+      return quote!();
     }
 
-    if self.sequence.is_some() {
-      let list_wrapper = Ident::new(parent_name, Span::call_site());
-      return quote!(#list_wrapper);
+    quote!(
+      impl #struct_name {
+        /// Default value with the repeated elements allocated for their `maxOccurs` bound.
+        #[allow(clippy::needless_update)]
+        pub fn with_capacity() -> Self {
+          #struct_name {
+            #(#capacities)*
+            ..Default::default()
+          }
+        }
+      }
+    )
+  }
+
+  /// Associated constants holding the `fixed` values of the attributes and elements, named after their fields.
+  fn get_fixed_constants_implementation(
+    &self,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> TokenStream {
+    let attributes = self
+      .get_attributes(context)
+      .into_iter()
+      .filter_map(|attribute| {
+        let attribute = attribute
+          .get_referenced_attribute(context)
+          .unwrap_or_else(|| attribute.clone());
+        let fixed = attribute.fixed.clone()?;
+        Some((attribute.get_field_name()?, fixed))
+      });
+
+    let elements = self
+      .get_sequences()
+      .into_iter()
+      .flat_map(|sequence| sequence.elements.iter())
+      .filter(|element| !element.name.is_empty() && !element.is_prohibited())
+      .filter_map(|element| Some((element.get_field_name(), element.fixed.clone()?)));
+
+    let constants = attributes
+      .chain(elements)
+      .map(|(field_name, fixed)| {
+        let constant_name = format_ident!(
+          "{}",
+          field_name.trim_start_matches("r#").to_shouty_snake_case()
+        );
+        quote!(pub const #constant_name: &str = #fixed;)
+      })
+      .collect::<Vec<TokenStream>>();
+
+    if constants.is_empty() {
+      return quote!();
     }
 
-    quote!(String)
+    quote!(
+      impl #struct_name {
+        #(#constants)*
+      }
+    )
+  }
+
+  /// `PartialEq` derive, or implementation leaving out the fields of the `partial_eq_ignore` option.
+  fn get_partial_eq_implementation(
+    context: &XsdContext,
+    struct_name: &Ident,
+    fields: &TokenStream,
+  ) -> (TokenStream, TokenStream) {
+    let parsed_fields: FieldsNamed =
+      syn::parse2(quote!({ #fields })).expect("[ComplexType] unable to parse fields");
+
+    let (ignored_fields, compared_fields): (Vec<&Ident>, Vec<&Ident>) = parsed_fields
+      .named
+      .iter()
+      .map(|field| field.ident.as_ref().unwrap())
+      .partition(|field_name| {
+        context.is_partial_eq_ignored(field_name.to_string().trim_start_matches("r#"))
+      });
+
+    if ignored_fields.is_empty() {
+      return (quote!(PartialEq,), quote!());
+    }
+
+    let comparison = if compared_fields.is_empty() {
+      quote!(true)
+    } else {
+      quote!(#(self.#compared_fields == other.#compared_fields)&&*)
+    };
+
+    (
+      quote!(),
+      quote!(
+        impl PartialEq for #struct_name {
+          fn eq(&self, other: &Self) -> bool {
+            #comparison
+          }
+        }
+      ),
+    )
+  }
+
+  /// Manual `Debug` printing the binary fields as their size, when `redact_binary` is enabled.
+  fn get_debug_implementation(
+    &self,
+    context: &XsdContext,
+    struct_name: &Ident,
+    fields: &TokenStream,
+  ) -> (TokenStream, TokenStream) {
+    if !context.has_redact_binary() {
+      return (quote!(Debug,), quote!());
+    }
+
+    let element_encodings = self
+      .get_sequences()
+      .into_iter()
+      .flat_map(|sequence| sequence.elements.iter())
+      .filter_map(|element| {
+        let kind = element.kind.as_ref()?;
+        let encoding = RustTypesMapping::get_binary_encoding(context, kind)?;
+        Some((element.get_field_name(), encoding))
+      });
+
+    let attribute_encodings = self
+      .get_attributes(context)
+      .into_iter()
+      .filter_map(|attribute| {
+        let referenced_attribute = attribute.get_referenced_attribute(context);
+        let kind = referenced_attribute
+          .as_ref()
+          .unwrap_or(attribute)
+          .kind
+          .as_ref()?;
+        let encoding = RustTypesMapping::get_binary_encoding(context, kind)?;
+        Some((attribute.get_field_name()?, encoding))
+      });
+
+    let encodings: BTreeMap<String, BinaryEncoding> =
+      element_encodings.chain(attribute_encodings).collect();
+
+    let parsed_fields: FieldsNamed =
+      syn::parse2(quote!({ #fields })).expect("[ComplexType] unable to parse fields");
+
+    let mut has_binary_fields = false;
+
+    let debug_fields = parsed_fields
+      .named
+      .iter()
+      .map(|field| {
+        let field_name = field.ident.as_ref().unwrap();
+        let name = field_name.to_string().trim_start_matches("r#").to_string();
+        let field_type = &field.ty;
+        let field_type = quote!(#field_type).to_string();
+
+        let (item_type, wrapper) = if let Some(item_type) = field_type
+          .strip_prefix("Option < ")
+          .and_then(|item_type| item_type.strip_suffix(" >"))
+        {
+          (item_type, Some("Option"))
+        } else if let Some(item_type) = field_type
+          .strip_prefix("Vec < ")
+          .and_then(|item_type| item_type.strip_suffix(" >"))
+          .filter(|item_type| *item_type != "u8")
+        {
+          (item_type, Some("Vec"))
+        } else {
+          (field_type.as_str(), None)
+        };
+
+        let byte_count = match (encodings.get(&name), item_type) {
+          (Some(encoding), _) => encoding.get_byte_count_implementation(),
+          (None, "Vec < u8 >") => quote!(value.len()),
+          (None, _) => return quote!(.field(#name, &self.#field_name)),
+        };
+
+        has_binary_fields = true;
+
+        let value = match wrapper {
+          Some("Option") => quote!(self.#field_name.as_ref().map(|value| Redacted(#byte_count))),
+          Some(_) => quote!(self
+            .#field_name
+            .iter()
+            .map(|value| Redacted(#byte_count))
+            .collect::<Vec<_>>()),
+          None => quote!({
+            let value = &self.#field_name;
+            Redacted(#byte_count)
+          }),
+        };
+
+        quote!(.field(#name, &#value))
+      })
+      .collect::<Vec<TokenStream>>();
+
+    if !has_binary_fields {
+      return (quote!(Debug,), quote!());
+    }
+
+    let name = struct_name.to_string();
+
+    (
+      quote!(),
+      quote!(
+        impl std::fmt::Debug for #struct_name {
+          fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            struct Redacted(usize);
+
+            impl std::fmt::Debug for Redacted {
+              fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "<{} bytes>", self.0)
+              }
+            }
+
+            f.debug_struct(#name)
+              #(#debug_fields)*
+              .finish()
+          }
+        }
+      ),
+    )
+  }
+
+  /// `new` constructor taking every field, running `validate` when the type declares constraints.
+  fn get_constructor_implementation(
+    context: &XsdContext,
+    struct_name: &Ident,
+    fields: &TokenStream,
+    validation: &TokenStream,
+  ) -> TokenStream {
+    if !context.has_validating_constructors() {
+      return quote!();
+    }
+
+    let parsed_fields: FieldsNamed =
+      syn::parse2(quote!({ #fields })).expect("[ComplexType] unable to parse fields");
+
+    let parameters = parsed_fields.named.iter().map(|field| {
+      let field_name = field.ident.as_ref().unwrap();
+      let field_type = &field.ty;
+      quote!(#field_name: #field_type)
+    });
+
+    let field_names = parsed_fields
+      .named
+      .iter()
+      .map(|field| field.ident.as_ref().unwrap());
+
+    let validate = match (validation.is_empty(), context.has_validation_errors()) {
+      (true, _) => None,
+      (false, false) => Some(quote!(value.validate()?;)),
+      (false, true) => Some(quote!(
+        value.validate().map_err(|errors| {
+          errors
+            .iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+        })?;
+      )),
+    };
+
+    quote!(
+      impl #struct_name {
+        #[allow(clippy::too_many_arguments)]
+        pub fn new(#(#parameters),*) -> Result<Self, String> {
+          let value = #struct_name {
+            #(#field_names),*
+          };
+
+          #validate
+
+          Ok(value)
+        }
+      }
+    )
+  }
+
+  /// `validate` method checking the `xs:assert` constraints, then the nested structures which
+  /// have a `validate` method. Inline types are named after the structure of their element.
+  pub fn get_validation_implementation(
+    &self,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> TokenStream {
+    let name = if self.name.is_empty() {
+      struct_name.to_string()
+    } else {
+      self.name.clone()
+    };
+
+    let checks = self.get_checks(context, &name);
+
+    let nested_elements = self.get_validated_elements(context, context.get_validated_types());
+    let nested_values = nested_elements.iter().map(|element| {
+      let field_name = Ident::new(&element.get_field_name(), Span::call_site());
+
+      if element.is_multiple() || element.get_min_occurences() == 0 {
+        quote!(self.#field_name.iter())
+      } else {
+        quote!(std::iter::once(&self.#field_name))
+      }
+    });
+
+    if self.asserts.is_empty() && checks.is_empty() && nested_elements.is_empty() {
+      return quote!();
+    }
+
+    if !context.has_validation_errors() {
+      let checks = checks.iter().map(|(_, check)| check);
+
+      return quote!(
+        impl #struct_name {
+          /// Check the facets of the content, the occurrences of the repeated elements and the assertions declared in the schema,
+          /// then the nested structures.
+          pub fn validate(&self) -> Result<(), String> {
+            #(#checks)*
+            #(
+              for value in #nested_values {
+                value.validate()?;
+              }
+            )*
+            Ok(())
+          }
+        }
+      );
+    }
+
+    // each check returns its first failure, it is run apart to go on with the next ones
+    let checks = checks.iter().map(|(path, check)| {
+      quote!(
+        let check = || -> Result<(), String> {
+          #check
+          Ok(())
+        };
+
+        if let Err(message) = check() {
+          errors.push(xml_schema::ValidationError {
+            path: #path.to_string(),
+            message,
+          });
+        }
+      )
+    });
+
+    quote!(
+      impl #struct_name {
+        /// Check the facets of the content, the occurrences of the repeated elements and the assertions declared in the schema,
+        /// then the nested structures, returning every failure.
+        pub fn validate(&self) -> Result<(), Vec<xml_schema::ValidationError>> {
+          let mut errors = vec![];
+
+          #({ #checks })*
+
+          #(
+            for value in #nested_values {
+              if let Err(nested_errors) = value.validate() {
+                errors.extend(nested_errors);
+              }
+            }
+          )*
+
+          if errors.is_empty() {
+            Ok(())
+          } else {
+            Err(errors)
+          }
+        }
+      }
+    )
+  }
+
+  /// Checks of the facets, occurrences and assertions declared by the type, with the path of the
+  /// field they concern.
+  fn get_checks(&self, context: &XsdContext, name: &str) -> Vec<(String, TokenStream)> {
+    let facets_check = self
+      .simple_content
+      .as_ref()
+      .and_then(|simple_content| {
+        let encoding = simple_content.get_binary_encoding(context);
+        Some(
+          simple_content
+            .restriction
+            .as_ref()?
+            .get_facets_check(encoding),
+        )
+      })
+      .filter(|facets_check| !facets_check.is_empty())
+      .map(|facets_check| {
+        (
+          format!("{name}.base"),
+          quote!({
+            let value = &self.base;
+            #facets_check
+          }),
+        )
+      });
+
+    let checks = self.asserts.iter().filter_map(|assert| {
+      let checks = assert.get_comparisons().and_then(|comparisons| {
+        comparisons
+          .iter()
+          .map(|comparison| {
+            let left_type = self.get_operand_type(context, &comparison.left);
+            let right_type = self.get_operand_type(context, &comparison.right);
+            if left_type.is_some() && right_type.is_some() && left_type != right_type {
+              return None;
+            }
+
+            let left =
+              self.get_operand_implementation(context, &comparison.left, right_type.as_deref())?;
+            let right =
+              self.get_operand_implementation(context, &comparison.right, left_type.as_deref())?;
+            let operator = comparison.get_operator_implementation();
+
+            Some(quote!(
+              match (#left, #right) {
+                (Some(left), Some(right)) => left #operator right,
+                _ => false,
+              }
+            ))
+          })
+          .collect::<Option<Vec<TokenStream>>>()
+      });
+
+      let Some(checks) = checks else {
+        log::warn!(
+          "Unsupported assertion {:?} on {}, it will not be validated",
+          assert.test,
+          self.name
+        );
+        return None;
+      };
+
+      let test = &assert.test;
+
+      Some((
+        name.to_string(),
+        quote!(
+          if !(#(#checks)&&*) {
+            return Err(format!("{} does not satisfy the assertion {:?}", #name, #test));
+          }
+        ),
+      ))
+    });
+
+    let occurrences_checks = self
+      .get_sequences()
+      .into_iter()
+      .flat_map(|sequence| sequence.elements.iter())
+      .filter_map(|element| {
+        let check = element.get_occurrences_check()?;
+        Some((format!("{name}.{}", element.get_field_name()), check))
+      });
+
+    facets_check
+      .into_iter()
+      .chain(occurrences_checks)
+      .chain(checks)
+      .collect()
+  }
+
+  /// Elements of the sequences whose structure has a `validate` method, the named types of
+  /// `validated_types` having one.
+  fn get_validated_elements(
+    &self,
+    context: &XsdContext,
+    validated_types: &BTreeSet<String>,
+  ) -> Vec<&Element> {
+    self
+      .get_sequences()
+      .into_iter()
+      .flat_map(|sequence| sequence.elements.iter())
+      .filter(|element| element.has_validation(context, validated_types))
+      .collect()
+  }
+
+  /// Rust type of an assertion operand, `None` for a number which takes the type it is compared with.
+  fn get_operand_type(&self, context: &XsdContext, operand: &Operand) -> Option<String> {
+    let rust_type = match operand {
+      Operand::Attribute(name) => self
+        .get_attributes(context)
+        .into_iter()
+        .find(|attribute| attribute.name.as_deref() == Some(name))?
+        .kind
+        .as_ref()
+        .map(|kind| RustTypesMapping::get(context, kind))?,
+      Operand::Element(name) => self.get_element(name)?.get_type_implementation(context),
+      Operand::Number(_) => return None,
+      Operand::Text(_) => return Some("String".to_string()),
+    };
+
+    Some(rust_type.to_string())
+  }
+
+  /// `Option<&T>` expression of an assertion operand compared with an operand of `other_type`,
+  /// `None` if it can not be resolved.
+  fn get_operand_implementation(
+    &self,
+    context: &XsdContext,
+    operand: &Operand,
+    other_type: Option<&str>,
+  ) -> Option<TokenStream> {
+    let (field_name, optional) = match operand {
+      Operand::Attribute(name) => self.get_attribute_field(context, name)?,
+      Operand::Element(name) => self.get_element_field(name)?,
+      Operand::Number(value) => {
+        let value = Operand::get_number_implementation(value, other_type)?;
+        return Some(quote!(Some(&(#value))));
+      }
+      Operand::Text(value) => return Some(quote!(Some(&String::from(#value)))),
+    };
+
+    if optional {
+      Some(quote!(self.#field_name.as_ref()))
+    } else {
+      Some(quote!(Some(&self.#field_name)))
+    }
+  }
+
+  /// Field of the attribute `name`, and whether it is optional.
+  pub fn get_attribute_field(&self, context: &XsdContext, name: &str) -> Option<(Ident, bool)> {
+    let attribute = self
+      .get_attributes(context)
+      .into_iter()
+      .find(|attribute| attribute.name.as_deref() == Some(name))?;
+    let field_name = Ident::new(&attribute.get_field_name()?, Span::call_site());
+    let rust_type = attribute
+      .kind
+      .as_ref()
+      .map(|kind| RustTypesMapping::get(context, kind))
+      .unwrap_or_default();
+
+    Some((field_name, attribute.is_optional(&rust_type)))
+  }
+
+  /// Field of the single child element `name`, and whether it is optional.
+  pub fn get_element_field(&self, name: &str) -> Option<(Ident, bool)> {
+    let element = self
+      .get_element(name)
+      .filter(|element| !element.is_multiple())?;
+    let field_name = Ident::new(&element.get_field_name(), Span::call_site());
+
+    Some((field_name, element.get_min_occurences() == 0))
+  }
+
+  /// Child element `name` declared by the sequences of this type.
+  pub fn get_element(&self, name: &str) -> Option<&Element> {
+    self
+      .get_sequences()
+      .into_iter()
+      .flat_map(|sequence| sequence.elements.iter())
+      .find(|element| element.name == name && !element.is_prohibited())
+  }
+
+  /// Sequences declared by this type, including those of its complex content extension or restriction.
+  pub fn get_sequences(&self) -> Vec<&Sequence> {
+    let complex_content_sequences = self
+      .complex_content
+      .iter()
+      .flat_map(|complex_content| {
+        complex_content
+          .extension
+          .iter()
+          .chain(&complex_content.restriction)
+      })
+      .flat_map(|extension| extension.sequences.iter());
+
+    self
+      .sequence
+      .iter()
+      .chain(self.all.iter())
+      .chain(complex_content_sequences)
+      .collect()
+  }
+
+  /// Elements of the choices of this type, with whether their choice repeats.
+  pub fn get_choice_elements(&self) -> Vec<(&Element, bool)> {
+    self
+      .choice
+      .iter()
+      .chain(self.sequence.iter().flat_map(|sequence| &sequence.choices))
+      .flat_map(|choice| {
+        choice
+          .elements
+          .iter()
+          .map(move |element| (element, choice.is_multiple()))
+      })
+      .collect()
+  }
+
+  /// Attributes declared by this type, including those of its simple or complex content derivation.
+  pub fn get_attributes<'a>(&'a self, context: &'a XsdContext) -> Vec<&'a Attribute> {
+    let simple_content_attributes = self
+      .simple_content
+      .iter()
+      .flat_map(|simple_content| simple_content.get_attributes(context));
+
+    let complex_content_attributes = self
+      .complex_content
+      .iter()
+      .flat_map(|complex_content| {
+        complex_content
+          .extension
+          .iter()
+          .chain(&complex_content.restriction)
+      })
+      .flat_map(|extension| extension.attributes.iter());
+
+    self
+      .attributes
+      .iter()
+      .chain(self.get_attribute_groups_attributes(context))
+      .chain(simple_content_attributes)
+      .chain(complex_content_attributes)
+      .collect()
+  }
+
+  /// Attributes of the referenced attribute groups, followed by those of the schema `defaultAttributes` group.
+  fn get_attribute_groups_attributes<'a>(&'a self, context: &'a XsdContext) -> Vec<&'a Attribute> {
+    let references = self
+      .attribute_groups
+      .iter()
+      .filter_map(|attribute_group| attribute_group.reference.as_deref());
+
+    let local_name = |reference: &str| {
+      reference
+        .split(':')
+        .next_back()
+        .unwrap_or(reference)
+        .to_string()
+    };
+
+    // the default group is not added twice when it is also referenced explicitly
+    let default_attributes = context
+      .get_default_attributes()
+      .filter(|_| self.default_attributes_apply != Some(false))
+      .filter(|default_attributes| {
+        !references
+          .clone()
+          .any(|reference| local_name(reference) == local_name(default_attributes))
+      });
+
+    references
+      .chain(default_attributes)
+      .filter_map(|reference| {
+        let attribute_group = context.get_attribute_group(reference);
+        if attribute_group.is_none() {
+          log::warn!("Unable to find the attribute group {:?}", reference);
+        }
+        attribute_group
+      })
+      .flat_map(|attribute_group| attribute_group.attributes.iter())
+      .collect()
+  }
+
+  /// Schema default values of the fields, indexed by field name.
+  fn get_field_defaults(&self, context: &XsdContext) -> BTreeMap<String, TokenStream> {
+    let mut context = context.clone();
+    context.set_parent_name(&self.name);
+    let context = &context;
+
+    let mut field_defaults = BTreeMap::new();
+
+    if *context.inheritance() == Inheritance::Inline {
+      let base = self
+        .complex_content
+        .as_ref()
+        .and_then(|complex_content| complex_content.extension.as_ref())
+        .and_then(|extension| context.get_complex_type(&extension.base))
+        .filter(|base| base.name != self.name);
+
+      if let Some(base) = base {
+        field_defaults.extend(base.get_field_defaults(context));
+      }
+    }
+
+    for element in self
+      .get_sequences()
+      .iter()
+      .flat_map(|sequence| sequence.elements.iter())
+    {
+      if let Some(default) = element.get_default_implementation(context) {
+        field_defaults.insert(element.get_field_name(), default);
+      }
+    }
+
+    for attribute in self.get_attributes(context) {
+      if let (Some(field_name), Some(default)) = (
+        attribute.get_field_name(),
+        attribute.get_default_implementation(context),
+      ) {
+        field_defaults.insert(field_name, default);
+      }
+    }
+
+    field_defaults
+  }
+
+  /// Up-cast conversions into each ancestor, only available when base fields are inlined.
+  fn get_conversions_implementation(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+  ) -> TokenStream {
+    if self.complex_content.is_none() || *context.inheritance() != Inheritance::Inline {
+      return quote!();
+    }
+
+    let struct_name = Ident::new(
+      &self.name.replace('.', "_").to_upper_camel_case(),
+      Span::call_site(),
+    );
+
+    context
+      .get_ancestors(&self.name)
+      .iter()
+      .map(|ancestor| {
+        let ancestor_name = Ident::new(
+          &ancestor.name.replace('.', "_").to_upper_camel_case(),
+          Span::call_site(),
+        );
+
+        let ancestor_fields =
+          ancestor.get_content_implementation(namespace_definition, prefix, context);
+        let captures_unknown = ComplexType::get_known_elements(context, &ancestor_fields).is_some();
+        let fields: FieldsNamed = syn::parse2(quote!({ #ancestor_fields }))
+          .expect("[ComplexType] unable to parse ancestor fields");
+
+        let fields_move = fields.named.iter().map(|field| {
+          let field_name = &field.ident;
+          quote!(#field_name: value.#field_name,)
+        });
+        let unknown_move = captures_unknown.then(|| quote!(unknown: value.unknown,));
+
+        let down_cast = captures_unknown.then(|| {
+          self.get_down_cast_implementation(
+            namespace_definition,
+            prefix,
+            context,
+            &struct_name,
+            &ancestor_name,
+            &fields,
+          )
+        });
+
+        quote!(
+          impl From<#struct_name> for #ancestor_name {
+            fn from(value: #struct_name) -> Self {
+              #ancestor_name {
+                #(#fields_move)*
+                #unknown_move
+              }
+            }
+          }
+
+          #down_cast
+        )
+      })
+      .collect()
+  }
+
+  /// Down-cast conversion from an ancestor, with the `capture_unknown` option. The elements of the
+  /// extension are the ones the ancestor captured as unknown; the conversion fails when one of the
+  /// required ones is missing.
+  fn get_down_cast_implementation(
+    &self,
+    namespace_definition: &TokenStream,
+    prefix: &Option<String>,
+    context: &XsdContext,
+    struct_name: &Ident,
+    ancestor_name: &Ident,
+    ancestor_fields: &FieldsNamed,
+  ) -> TokenStream {
+    let fields = self.get_content_implementation(namespace_definition, prefix, context);
+    let fields: FieldsNamed =
+      syn::parse2(quote!({ #fields })).expect("[ComplexType] unable to parse fields");
+
+    let is_inherited = |field: &&syn::Field| {
+      ancestor_fields
+        .named
+        .iter()
+        .any(|ancestor_field| ancestor_field.ident == field.ident)
+    };
+
+    let base_move = fields.named.iter().filter(is_inherited).map(|field| {
+      let field_name = &field.ident;
+      quote!(#field_name: value.#field_name,)
+    });
+
+    let extension_fields: Vec<&syn::Field> = fields
+      .named
+      .iter()
+      .filter(|field| !is_inherited(field))
+      .collect();
+
+    let extension_move = extension_fields.iter().map(|field| {
+      let field_name = &field.ident;
+      quote!(#field_name: extension.#field_name,)
+    });
+
+    let required_fields = extension_fields.iter().filter(|field| match &field.ty {
+      syn::Type::Path(path) => path
+        .path
+        .segments
+        .last()
+        .map(|segment| segment.ident != "Option" && segment.ident != "Vec")
+        .unwrap_or(true),
+      _ => true,
+    });
+    let required_names = ComplexType::get_known_elements(context, &quote!(#(#required_fields,)*))
+      .map(|(names, _)| names)
+      .unwrap_or_default();
+
+    let required_check = (!required_names.is_empty()).then(|| {
+      quote!(
+        let names = xml_schema::de::child_names(&content)?;
+        for required in [#(#required_names),*] {
+          if !names.iter().any(|name| name == required) {
+            return Err(format!(
+              "{} is not a {}, the element {} is missing",
+              stringify!(#ancestor_name),
+              stringify!(#struct_name),
+              required
+            ));
+          }
+        }
+      )
+    });
+
+    let root_name = struct_name.to_string();
+
+    quote!(
+      impl std::convert::TryFrom<#ancestor_name> for #struct_name {
+        type Error = String;
+
+        fn try_from(value: #ancestor_name) -> Result<Self, Self::Error> {
+          let content = format!("<{0}>{1}</{0}>", #root_name, value.unknown.concat());
+          #required_check
+          let extension: #struct_name = yaserde::de::from_str(&content)?;
+
+          Ok(#struct_name {
+            #(#base_move)*
+            #(#extension_move)*
+            unknown: extension.unknown,
+          })
+        }
+      }
+    )
+  }
+
+  /// (De)serialization of `Box<Self>`, used by the fields referencing their own type.
+  /// Recursive types are held through a `Box`, and are deserialized from a copy of their element
+  /// renamed as the type, as yaserde mistakes a root element named like one of the fields for it.
+  /// With the `strict_order` option, the order of the sequence elements is checked on this copy,
+  /// yaserde accepting any order.
+  fn get_recursive_implementation(
+    &self,
+    context: &XsdContext,
+    namespace_definition: &TokenStream,
+    struct_name: &Ident,
+    fields: &TokenStream,
+  ) -> (TokenStream, TokenStream) {
+    let is_recursive = context.contains_type(&self.name, &self.name);
+    let sequence_order = self.get_sequence_order(context);
+    let known_elements = ComplexType::get_known_elements(context, fields);
+
+    let mut parsed_fields: FieldsNamed =
+      syn::parse2(quote!({ #fields })).expect("[ComplexType] unable to parse fields");
+
+    // repeated choices are flattened vectors, read from the copy of the element instead
+    let (choice_fields, content_fields): (Vec<_>, Vec<_>) = parsed_fields
+      .named
+      .into_iter()
+      .partition(ComplexType::is_repeated_choice);
+
+    if !is_recursive
+      && sequence_order.is_none()
+      && known_elements.is_none()
+      && choice_fields.is_empty()
+    {
+      return (quote!(yaserde_derive::YaDeserialize,), quote!());
+    }
+
+    parsed_fields.named = content_fields.into_iter().collect();
+    let fields = &parsed_fields.named;
+
+    let root_name = struct_name.to_string();
+    let content_name = Ident::new(&format!("{struct_name}Content"), Span::call_site());
+
+    let fields_move = parsed_fields.named.iter().map(|field| {
+      let field_name = field.ident.as_ref().unwrap();
+      quote!(#field_name: content.#field_name,)
+    });
+
+    let choice_names: Vec<_> = choice_fields.iter().map(|field| &field.ident).collect();
+    let choice_types = choice_fields
+      .iter()
+      .map(|field| Choice::get_single_type(&field.ty));
+
+    let unknown_elements = known_elements.map(|(names, choice_types)| {
+      if choice_types.is_empty() {
+        quote!(let unknown = xml_schema::de::unknown_elements(&content, &[#(#names),*])?;)
+      } else {
+        quote!(
+          let known = [&[#(#names),*][..], #(<#choice_types>::ELEMENTS),*].concat();
+          let unknown = xml_schema::de::unknown_elements(&content, &known)?;
+        )
+      }
+    });
+    let unknown_move = unknown_elements.is_some().then(|| quote!(unknown,));
+
+    let order_check = sequence_order
+      .map(|names| quote!(xml_schema::de::check_sequence_order(&content, &[#(#names),*])?;));
+
+    let box_implementation = is_recursive.then(|| {
+      quote!(
+        impl yaserde::YaDeserialize for Box<#struct_name> {
+          fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
+            <#struct_name as yaserde::YaDeserialize>::deserialize(reader).map(Box::new)
+          }
+        }
+
+        impl yaserde::YaSerialize for Box<#struct_name> {
+          fn serialize<W: std::io::Write>(&self, writer: &mut yaserde::ser::Serializer<W>) -> Result<(), String> {
+            yaserde::YaSerialize::serialize(self.as_ref(), writer)
+          }
+
+          fn serialize_attributes(&self, source_attributes: Vec<xml::attribute::OwnedAttribute>, source_namespace: xml::namespace::Namespace) -> Result<(Vec<xml::attribute::OwnedAttribute>, xml::namespace::Namespace), String> {
+            self.as_ref().serialize_attributes(source_attributes, source_namespace)
+          }
+        }
+      )
+    });
+
+    let implementation = quote!(
+      #[derive(yaserde_derive::YaDeserialize)]
+      #[yaserde(rename = #root_name)]
+      #namespace_definition
+      struct #content_name {
+        #fields
+      }
+
+      impl yaserde::YaDeserialize for #struct_name {
+        fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
+          let start_depth = reader.depth();
+          let mut writer = xml::writer::EmitterConfig::new().create_writer(vec![]);
+
+          loop {
+            let is_root = reader.depth() == start_depth;
+            let is_root_end = reader.depth() == start_depth + 1;
+
+            let event = match reader.peek()?.to_owned() {
+              xml::reader::XmlEvent::StartElement { mut name, attributes, namespace } if is_root => {
+                name.local_name = #root_name.to_string();
+                xml::reader::XmlEvent::StartElement { name, attributes, namespace }
+              }
+              xml::reader::XmlEvent::EndElement { mut name } if is_root_end => {
+                name.local_name = #root_name.to_string();
+                xml::reader::XmlEvent::EndElement { name }
+              }
+              xml::reader::XmlEvent::EndDocument => {
+                return Err(format!("unexpected end of document in {}", #root_name));
+              }
+              event => event,
+            };
+
+            if let Some(event) = event.as_writer_event() {
+              writer.write(event).map_err(|e| e.to_string())?;
+            }
+
+            // the end element is left to the parent, as for derived types
+            if is_root_end && matches!(event, xml::reader::XmlEvent::EndElement { .. }) {
+              break;
+            }
+
+            reader.next_event()?;
+          }
+
+          let content = String::from_utf8(writer.into_inner()).map_err(|e| e.to_string())?;
+          #order_check
+          #unknown_elements
+          #(let #choice_names = xml_schema::de::choice_elements(&content, <#choice_types>::ELEMENTS)?;)*
+          let content: #content_name = xml_schema::de::from_str(&content)?;
+
+          Ok(#struct_name {
+            #(#fields_move)*
+            #(#choice_names,)*
+            #unknown_move
+          })
+        }
+      }
+
+      #box_implementation
+    );
+
+    (quote!(), implementation)
+  }
+
+  /// Flattened `Vec` field, only generated for the repeated choices.
+  fn is_repeated_choice(field: &syn::Field) -> bool {
+    let is_vec = matches!(
+      &field.ty,
+      syn::Type::Path(path) if path.path.segments.last().is_some_and(|segment| segment.ident == "Vec")
+    );
+
+    let is_flatten = field
+      .attrs
+      .iter()
+      .filter(|attribute| attribute.path().is_ident("yaserde"))
+      .any(|attribute| {
+        let mut is_flatten = false;
+        let _ = attribute.parse_nested_meta(|meta| {
+          is_flatten |= meta.path.is_ident("flatten");
+          if meta.input.peek(Token![=]) {
+            meta.value()?.parse::<Expr>()?;
+          }
+          Ok(())
+        });
+        is_flatten
+      });
+
+    is_vec && is_flatten
+  }
+
+  /// Names of the elements matched by the fields, and types of the choices whose `ELEMENTS` are
+  /// matched too, the other children being captured in `unknown` with the `capture_unknown`
+  /// option. Types with other flattened fields, whose elements are not known from their fields,
+  /// are left out.
+  fn get_known_elements(
+    context: &XsdContext,
+    fields: &TokenStream,
+  ) -> Option<(Vec<String>, Vec<syn::Type>)> {
+    if !context.has_capture_unknown() {
+      return None;
+    }
+
+    let parsed_fields: FieldsNamed =
+      syn::parse2(quote!({ #fields })).expect("[ComplexType] unable to parse fields");
+
+    let mut names = vec![];
+    let mut choice_types = vec![];
+
+    for field in &parsed_fields.named {
+      let mut name = field
+        .ident
+        .as_ref()
+        .map(|ident| ident.to_string().trim_start_matches("r#").to_string());
+      let mut is_element = true;
+      let mut is_flatten = false;
+
+      for attribute in field
+        .attrs
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("yaserde"))
+      {
+        let _ = attribute.parse_nested_meta(|meta| {
+          if meta.path.is_ident("rename") {
+            let rename: LitStr = meta.value()?.parse()?;
+            name = Some(rename.value());
+          } else if meta.path.is_ident("attribute") || meta.path.is_ident("text") {
+            is_element = false;
+          } else if meta.path.is_ident("flatten") {
+            is_flatten = true;
+          } else if meta.input.peek(Token![=]) {
+            meta.value()?.parse::<Expr>()?;
+          }
+          Ok(())
+        });
+      }
+
+      if is_flatten && Choice::is_field(field) {
+        choice_types.push(Choice::get_single_type(&field.ty));
+      } else if is_flatten {
+        return None;
+      } else if is_element {
+        names.extend(name);
+      }
+    }
+
+    Some((names, choice_types))
+  }
+
+  /// `unknown` field holding the child elements matching no field, with the `capture_unknown` option.
+  fn get_unknown_field_implementation(context: &XsdContext, fields: TokenStream) -> TokenStream {
+    if ComplexType::get_known_elements(context, &fields).is_none() {
+      return fields;
+    }
+
+    quote!(
+      #fields
+      /// Child elements matching no field, as XML fragments. They are not serialized.
+      #[yaserde(skip_serializing)]
+      pub unknown: Vec<String>,
+    )
+  }
+
+  /// Names of the elements of the sequence, in the order their occurrences are expected on
+  /// deserialization, with the `strict_order` option. Only sequences of two elements or more,
+  /// without groups or choices, are checked.
+  fn get_sequence_order(&self, context: &XsdContext) -> Option<Vec<String>> {
+    if !context.has_strict_order() {
+      return None;
+    }
+
+    let sequence = self
+      .sequence
+      .as_ref()
+      .filter(|sequence| sequence.groups.is_empty() && sequence.choices.is_empty())?;
+
+    let names: Vec<String> = sequence
+      .elements
+      .iter()
+      .filter(|element| !element.is_prohibited())
+      .filter_map(|element| {
+        if !element.name.is_empty() {
+          return Some(element.name.clone());
+        }
+
+        let refers = element.refers.as_deref()?;
+        Some(refers.split(':').next_back().unwrap_or(refers).to_string())
+      })
+      .collect();
+
+    (names.len() > 1).then_some(names)
+  }
+
+  /// Trait shared by a base type and its extensions, to handle the family as `Box<dyn BaseTrait>`.
+  fn get_extension_traits_implementation(&self, context: &XsdContext) -> TokenStream {
+    if !context.has_extension_traits() {
+      return quote!();
+    }
+
+    let struct_name = Ident::new(
+      &self.name.replace('.', "_").to_upper_camel_case(),
+      Span::call_site(),
+    );
+
+    let is_base = context.has_extensions(&self.name) && !self.is_final_for_extension();
+
+    let trait_definition = if is_base {
+      let trait_name = format_ident!("{}Trait", struct_name);
+      let to_base = format_ident!("to_{}", self.name.replace('.', "_").to_snake_case());
+      let into_boxed = format_ident!("into_boxed_{}", self.name.replace('.', "_").to_snake_case());
+      let documentation = format!(" Types extending `{}`, directly or not.", self.name);
+      let to_base_documentation = format!(" Copy of the `{}` part of the value.", self.name);
+
+      quote!(
+        #[doc = #documentation]
+        pub trait #trait_name: std::fmt::Debug {
+          #[doc = #to_base_documentation]
+          fn #to_base(&self) -> #struct_name;
+
+          fn #into_boxed(self) -> Box<dyn #trait_name>
+          where
+            Self: Sized + 'static,
+          {
+            Box::new(self)
+          }
+        }
+      )
+    } else {
+      quote!()
+    };
+
+    let family = is_base.then_some(self).into_iter().chain(
+      context
+        .get_ancestors(&self.name)
+        .into_iter()
+        .filter(|ancestor| !ancestor.is_final_for_extension()),
+    );
+
+    let implementations = family.map(|base| {
+      let base_name = Ident::new(
+        &base.name.replace('.', "_").to_upper_camel_case(),
+        Span::call_site(),
+      );
+      let trait_name = format_ident!("{}Trait", base_name);
+      let to_base = format_ident!("to_{}", base.name.replace('.', "_").to_snake_case());
+
+      let conversion = if base.name == self.name {
+        quote!(self.clone())
+      } else if *context.inheritance() == Inheritance::Inline {
+        quote!(self.clone().into())
+      } else {
+        quote!(self.base.#to_base())
+      };
+
+      quote!(
+        impl #trait_name for #struct_name {
+          fn #to_base(&self) -> #base_name {
+            #conversion
+          }
+        }
+      )
+    });
+
+    quote!(
+      #trait_definition
+      #(#implementations)*
+    )
+  }
+
+  /// Whether the type gets a `validate` method, i.e. it declares facets, bounded occurrences or
+  /// assertions, or holds a structure which does. The named types of `validated_types` have one.
+  pub fn has_validation(&self, context: &XsdContext, validated_types: &BTreeSet<String>) -> bool {
+    if !self.name.is_empty() && validated_types.contains(&self.name) {
+      return true;
+    }
+
+    if self.get_list_wrapper_element(context).is_some() {
+      return false;
+    }
+
+    !self.asserts.is_empty()
+      || !self.get_checks(context, &self.name).is_empty()
+      || !self
+        .get_validated_elements(context, validated_types)
+        .is_empty()
+  }
+
+  /// Whether `final` forbids the extension of this type, it then gets no trait family.
+  pub fn is_final_for_extension(&self) -> bool {
+    self
+      .final_derivations
+      .as_deref()
+      .unwrap_or_default()
+      .split_whitespace()
+      .any(|derivation| derivation == "extension" || derivation == "#all")
+  }
+
+  pub fn get_integrated_implementation(
+    &self,
+    context: &XsdContext,
+    parent_name: &str,
+  ) -> TokenStream {
+    if let Some(simple_content) = &self.simple_content {
+      return simple_content.get_content_type(context);
+    }
+
+    // the inline type is generated by the element, even without content
+    let struct_name = Ident::new(
+      &parent_name.replace('.', "_").to_upper_camel_case(),
+      Span::call_site(),
+    );
+    quote!(#struct_name)
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::xsd::schema::Schema;
+  use std::str::FromStr;
+
+  #[test]
+  fn method_names() {
+    let struct_name = Ident::new("Book", Span::call_site());
+    let implementation = quote!(
+      impl Book {
+        pub fn new(title: String) -> Self {
+          fn title() {}
+          Book { title }
+        }
+      }
+
+      impl std::fmt::Display for Book {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+          Ok(())
+        }
+      }
+
+      impl Shelf {
+        pub fn iter_book(&self) {}
+      }
+    );
+
+    assert_eq!(
+      ComplexType::get_method_names(&struct_name, &implementation),
+      BTreeSet::from(["fmt".to_string(), "new".to_string()])
+    );
+  }
+
+  #[test]
+  fn private_fields_with_accessors() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap()
+        .with_accessors(true);
+
+    let struct_name = Ident::new("Book", Span::call_site());
+    let fields = quote!(
+      #[yaserde(rename = "title")]
+      pub title: String,
+    );
+
+    let (fields, accessors) =
+      ComplexType::get_accessors_implementation(&context, &struct_name, fields, &BTreeSet::new());
+
+    let expected_fields = TokenStream::from_str(
+      r#"
+        #[yaserde(rename = "title")]
+        title: String,
+      "#,
+    )
+    .unwrap();
+
+    let expected_accessors = TokenStream::from_str(
+      r#"
+        impl Book {
+          pub fn title(&self) -> &String {
+            &self.title
+          }
+
+          pub fn set_title(&mut self, value: String) {
+            self.title = value;
+          }
+        }
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(fields.to_string(), expected_fields.to_string());
+    assert_eq!(accessors.to_string(), expected_accessors.to_string());
+  }
+
+  #[test]
+  fn repeated_fields_iterators() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap()
+        .with_iterators(true);
+
+    let struct_name = Ident::new("Library", Span::call_site());
+    let fields = quote!(
+      #[yaserde(rename = "book")]
+      pub book: Vec<xml_schema_types::Book>,
+      #[yaserde(rename = "name")]
+      pub name: String,
+      #[yaserde(attribute)]
+      pub tags: Vec<String>,
+    );
+
+    let iterators = ComplexType::get_iterators_implementation(&context, &struct_name, &fields);
+
+    let expected = TokenStream::from_str(
+      r#"
+        impl Library {
+          pub fn iter_book(&self) -> impl Iterator<Item = &xml_schema_types::Book> {
+            self.book.iter()
+          }
+        }
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(iterators.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn non_exhaustive_struct() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap()
+        .with_non_exhaustive(true);
+
+    let complex_type = ComplexType {
+      name: "Empty".to_string(),
+      ..Default::default()
+    };
+
+    let implementation = complex_type
+      .implement(&TokenStream::new(), &None, &context)
+      .to_string();
+
+    assert!(implementation.contains("# [non_exhaustive] pub struct Empty"));
+  }
+
+  #[test]
+  fn final_base_extension_traits() {
+    let content = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+      <xs:complexType name="Shape" final="extension restriction">
+        <xs:sequence><xs:element name="id" type="xs:string" /></xs:sequence>
+      </xs:complexType>
+      <xs:complexType name="Circle">
+        <xs:complexContent>
+          <xs:extension base="Shape">
+            <xs:sequence><xs:element name="radius" type="xs:int" /></xs:sequence>
+          </xs:extension>
+        </xs:complexContent>
+      </xs:complexType>
+    </xs:schema>"#;
+
+    let schema: Schema = yaserde::de::from_str(content).unwrap();
+    let context = XsdContext::new(content)
+      .unwrap()
+      .with_schema(&schema)
+      .with_extension_traits(true);
+
+    assert!(schema.complex_type[0].is_final_for_extension());
+
+    let base_implementation = schema.complex_type[0]
+      .implement(&TokenStream::new(), &None, &context)
+      .to_string();
+    let derived_implementation = schema.complex_type[1]
+      .implement(&TokenStream::new(), &None, &context)
+      .to_string();
+
+    assert!(!base_implementation.contains("ShapeTrait"));
+    assert!(!derived_implementation.contains("ShapeTrait"));
   }
 }
@@ -0,0 +1,21 @@
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(
+  rename = "documentation"
+  prefix = "xs",
+  namespace = "xs: http://www.w3.org/2001/XMLSchema"
+)]
+pub struct Documentation {
+  #[yaserde(rename = "lang", attribute)]
+  pub lang: Option<String>,
+  #[yaserde(text)]
+  pub content: String,
+}
+
+impl Documentation {
+  pub fn get_doc_content(&self) -> String {
+    match &self.lang {
+      Some(lang) => format!("[{lang}] {}", self.content),
+      None => self.content.clone(),
+    }
+  }
+}
@@ -1,10 +1,18 @@
 use crate::xsd::{
-  annotation::Annotation, complex_type::ComplexType, max_occurences::MaxOccurences,
-  rust_types_mapping::RustTypesMapping, simple_type::SimpleType, Implementation, XsdContext,
+  annotation::Annotation,
+  complex_type::ComplexType,
+  identity_constraint::{ConstraintKind, IdentityConstraint},
+  max_occurences::MaxOccurences,
+  qualification::Qualification,
+  restriction::Restriction,
+  rust_types_mapping::RustTypesMapping,
+  simple_type::SimpleType,
+  Implementation, XsdContext,
 };
 use heck::{ToSnakeCase, ToUpperCamelCase};
 use proc_macro2::{Span, TokenStream};
-use syn::Ident;
+use std::collections::{BTreeMap, BTreeSet};
+use syn::{FieldsNamed, Ident};
 
 #[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
 #[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
@@ -25,6 +33,26 @@ pub struct Element {
   pub simple_type: Option<SimpleType>,
   #[yaserde(rename = "annotation")]
   pub annotation: Option<Annotation>,
+  #[yaserde(attribute)]
+  pub default: Option<String>,
+  #[yaserde(attribute)]
+  pub fixed: Option<String>,
+  #[yaserde(rename = "key")]
+  pub keys: Vec<IdentityConstraint>,
+  #[yaserde(rename = "keyref")]
+  pub keyrefs: Vec<IdentityConstraint>,
+  #[yaserde(rename = "unique")]
+  pub uniques: Vec<IdentityConstraint>,
+  #[yaserde(rename = "substitutionGroup", attribute)]
+  pub substitution_group: Option<String>,
+  #[yaserde(attribute)]
+  pub block: Option<String>,
+  #[yaserde(rename = "abstract", attribute)]
+  pub is_abstract: Option<bool>,
+  #[yaserde(attribute)]
+  pub form: Option<Qualification>,
+  #[yaserde(attribute)]
+  pub nillable: Option<bool>,
 }
 
 impl Implementation for Element {
@@ -39,7 +67,7 @@ impl Implementation for Element {
       Span::call_site(),
     );
 
-    let (fields, extra_structs) = if let Some(kind) = &self.kind {
+    let (fields, extra_structs) = if let Some(kind) = self.get_kind(context) {
       let subtype_mode = if RustTypesMapping::is_xs_string(context, kind) {
         quote!(text)
       } else {
@@ -47,22 +75,54 @@ impl Implementation for Element {
       };
 
       let extern_type = RustTypesMapping::get(context, kind);
+      let module = if RustTypesMapping::is_xml_schema_type(context, kind) {
+        quote!()
+      } else {
+        context.get_types_path()
+      };
+
+      let placeholder_attribute = if context.has_default(kind) {
+        quote!()
+      } else {
+        let placeholder = Restriction::get_placeholder_name(&self.name, "content");
+        quote!(, default=#placeholder)
+      };
 
       (
         quote!(
-          #[yaserde(#subtype_mode)]
-          pub content: xml_schema_types::#extern_type,
+          #[yaserde(#subtype_mode #placeholder_attribute)]
+          pub content: #module #extern_type,
         ),
         quote!(),
       )
     } else {
+      // the choices of the inline type are named after the element
+      let mut context = context.clone();
+      if self
+        .complex_type
+        .as_ref()
+        .is_some_and(|complex_type| complex_type.has_choices())
+      {
+        context.set_parent_name(&self.name);
+      }
+
       let fields_definition = self
         .complex_type
         .iter()
-        .map(|complex_type| complex_type.get_field_implementation(context, prefix))
+        .map(|complex_type| {
+          complex_type.get_content_implementation(namespace_definition, prefix, &context)
+        })
         .collect();
 
-      (fields_definition, quote!())
+      let choices = self
+        .complex_type
+        .iter()
+        .map(|complex_type| {
+          complex_type.get_choices_implementation(namespace_definition, prefix, &context)
+        })
+        .collect();
+
+      (fields_definition, choices)
     };
 
     let docs = self
@@ -71,31 +131,286 @@ impl Implementation for Element {
       .map(|annotation| annotation.implement(namespace_definition, prefix, context))
       .unwrap_or_default();
 
+    let validation = self
+      .complex_type
+      .as_ref()
+      .filter(|_| self.get_kind(context).is_none())
+      .map(|complex_type| complex_type.get_validation_implementation(context, &struct_name))
+      .unwrap_or_default();
+
+    let identity_validation = self.get_identity_validation_implementation(context, &struct_name);
+    let validated_parsing = self.get_validated_parsing_implementation(context, &struct_name);
+
+    let non_exhaustive = context.get_non_exhaustive_attribute();
+
+    // keep the element name on serialization when the structure name differs
+    let rename = if struct_name == self.name {
+      quote!()
+    } else {
+      let name = &self.name;
+      quote!(#[yaserde(rename = #name)])
+    };
+
+    let parsed_fields: FieldsNamed =
+      syn::parse2(quote!({ #fields })).expect("[Element] unable to parse fields");
+    let placeholders = Restriction::get_placeholders(&parsed_fields);
+
+    let (derive_default, default_implementation) = if placeholders.is_empty() {
+      (quote!(Default,), quote!())
+    } else {
+      let fields_default = parsed_fields.named.iter().map(|field| {
+        let field_name = field.ident.as_ref().unwrap();
+        let value = placeholders
+          .iter()
+          .find(|(name, _, _)| name == field_name)
+          .map(|(_, function, _)| quote!(#function()))
+          .unwrap_or_else(|| quote!(Default::default()));
+
+        quote!(#field_name: #value,)
+      });
+
+      (
+        quote!(),
+        quote!(
+          impl Default for #struct_name {
+            fn default() -> Self {
+              #struct_name {
+                #(#fields_default)*
+              }
+            }
+          }
+        ),
+      )
+    };
+
+    let placeholders_implementation = Restriction::get_placeholders_implementation(&placeholders);
+
     quote! {
       #docs
-      #[derive(Clone, Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize)]
+      #[derive(Clone, Debug, #derive_default PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize)]
+      #rename
       #namespace_definition
+      #non_exhaustive
       pub struct #struct_name {
         #fields
       }
 
+      #default_implementation
+
+      #placeholders_implementation
+
+      #validation
+
+      impl std::str::FromStr for #struct_name {
+        type Err = xml_schema::XsdError;
+
+        /// Parse a whole XML document holding this element as root, the errors naming the path
+        /// and position of the element which failed.
+        fn from_str(content: &str) -> Result<Self, Self::Err> {
+          xml_schema::de::from_str_located(content).map_err(xml_schema::XsdError::Parse)
+        }
+      }
+
+      impl #struct_name {
+        /// Serialize as a whole XML document, declaring the namespaces of the schema.
+        pub fn to_xml_string(&self) -> Result<String, xml_schema::XsdError> {
+          yaserde::ser::to_string(self).map_err(xml_schema::XsdError::Serialization)
+        }
+      }
+
+      #identity_validation
+
+      #validated_parsing
+
       #extra_structs
     }
   }
 }
 
 impl Element {
+  /// `from_xml_validated` parsing a document, then running the `validate` of its structure and
+  /// the identity constraints of the element, when it has any of them.
+  fn get_validated_parsing_implementation(
+    &self,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> TokenStream {
+    let validated_types = context.get_validated_types();
+
+    let content_validation = match self.get_kind(context) {
+      Some(kind) => context
+        .get_complex_type(kind)
+        .filter(|complex_type| complex_type.has_validation(context, validated_types))
+        .map(|complex_type| (complex_type.name.clone(), quote!(value.content))),
+      None => self
+        .complex_type
+        .as_ref()
+        .filter(|complex_type| complex_type.has_validation(context, validated_types))
+        .map(|_| (self.name.clone(), quote!(value))),
+    }
+    .map(|(type_name, value)| {
+      if context.has_validation_errors() {
+        quote!(if let Err(content_errors) = #value.validate() {
+          errors.extend(content_errors);
+        })
+      } else {
+        quote!(
+          if let Err(message) = #value.validate() {
+            errors.push(xml_schema::ValidationError {
+              path: #type_name.to_string(),
+              message,
+            });
+          }
+        )
+      }
+    });
+
+    let has_identity_constraints =
+      !self.keys.is_empty() || !self.uniques.is_empty() || !self.keyrefs.is_empty();
+
+    let name = &self.name;
+    let identity_validation = has_identity_constraints.then(|| {
+      quote!(
+        if let Err(message) = value.validate_identity() {
+          errors.push(xml_schema::ValidationError {
+            path: #name.to_string(),
+            message,
+          });
+        }
+      )
+    });
+
+    let validation = if content_validation.is_none() && identity_validation.is_none() {
+      quote!(Ok(value))
+    } else {
+      quote!(
+        let mut errors = vec![];
+
+        #content_validation
+        #identity_validation
+
+        if errors.is_empty() {
+          Ok(value)
+        } else {
+          Err(xml_schema::XsdError::Validation(errors))
+        }
+      )
+    };
+
+    quote!(
+      impl #struct_name {
+        /// Parse a whole XML document holding this element as root, then check it against the
+        /// constraints of the schema, returning every failure.
+        pub fn from_xml_validated(content: &str) -> Result<Self, xml_schema::XsdError> {
+          let value: Self = xml_schema::de::from_str_located(content).map_err(xml_schema::XsdError::Parse)?;
+          #validation
+        }
+      }
+    )
+  }
+
+  /// `validate_identity` method checking the `xs:key`, `xs:keyref` and `xs:unique` constraints.
+  fn get_identity_validation_implementation(
+    &self,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> TokenStream {
+    let constraints = self
+      .keys
+      .iter()
+      .map(|constraint| (ConstraintKind::Key, constraint))
+      .chain(
+        self
+          .uniques
+          .iter()
+          .map(|constraint| (ConstraintKind::Unique, constraint)),
+      )
+      .chain(
+        self
+          .keyrefs
+          .iter()
+          .map(|constraint| (ConstraintKind::KeyRef, constraint)),
+      )
+      .collect::<Vec<_>>();
+
+    if constraints.is_empty() {
+      return quote!();
+    }
+
+    let root = match (&self.complex_type, &self.kind) {
+      (Some(complex_type), _) => Some((complex_type, quote!(self))),
+      (None, Some(kind)) => context
+        .get_complex_type(kind)
+        .map(|complex_type| (complex_type, quote!(&self.content))),
+      _ => None,
+    };
+
+    let Some((complex_type, root)) = root else {
+      log::warn!(
+        "Identity constraints of {} are not validated, its type is not a complex type",
+        self.name
+      );
+      return quote!();
+    };
+
+    let mutability = (!self.keys.is_empty() || !self.uniques.is_empty())
+      .then_some(quote!(mut))
+      .unwrap_or_default();
+
+    let checks = constraints.iter().filter_map(|(kind, constraint)| {
+      let check = constraint.get_check_implementation(*kind, context, complex_type, &root);
+
+      if check.is_none() {
+        log::warn!(
+          "Unsupported identity constraint {} on {}, it will not be validated",
+          constraint.name,
+          self.name
+        );
+      }
+
+      check
+    });
+
+    quote!(
+      impl #struct_name {
+        /// Check the identity constraints (`xs:key`, `xs:keyref` and `xs:unique`) declared in the schema.
+        pub fn validate_identity(&self) -> Result<(), String> {
+          let #mutability identities: std::collections::BTreeMap<&str, std::collections::BTreeSet<Vec<String>>> =
+            std::collections::BTreeMap::new();
+
+          #(#checks)*
+
+          Ok(())
+        }
+      }
+    )
+  }
+
   pub fn get_subtypes_implementation(
     &self,
     namespace_definition: &TokenStream,
     prefix: &Option<String>,
     context: &XsdContext,
   ) -> TokenStream {
+    if let Some(fixed_type_name) = self.get_fixed_type_name(context) {
+      return self.get_fixed_implementation(context, &fixed_type_name);
+    }
+
+    let anonymous_type_attribute = context.get_anonymous_type_attribute();
+
+    if let Some(restriction) = self.get_inline_restriction() {
+      let type_name = self.get_inline_type_name(context);
+      let implementation =
+        restriction.implement_childs(namespace_definition, prefix, context, &type_name);
+      return quote!(#anonymous_type_attribute #implementation);
+    }
+
     if self.complex_type.is_none() {
       return quote!();
     }
 
-    self.implement(namespace_definition, prefix, context)
+    let implementation = self.implement(namespace_definition, prefix, context);
+    quote!(#anonymous_type_attribute #implementation)
   }
 
   pub fn get_field_implementation(
@@ -104,84 +419,795 @@ impl Element {
     prefix: &Option<String>,
   ) -> TokenStream {
     let refers = self.get_refers();
-    if self.name.is_empty() && refers.is_none() {
+    if (self.name.is_empty() && refers.is_none()) || self.is_prohibited() {
       return quote!();
     }
 
-    let multiple = self.max_occurences.is_some()
-      && self.max_occurences != Some(MaxOccurences::Number { value: 1 });
+    let multiple = self.is_multiple();
+    let name = self.get_field_name();
 
-    let name = if self.name.to_lowercase() == "type" {
-      "kind".to_string()
-    } else if !self.name.is_empty() {
-      self.name.to_snake_case()
+    log::info!("Generate element {:?}", name);
+
+    let attribute_name = Ident::new(&name, Span::call_site());
+    let yaserde_rename = if !self.name.is_empty() {
+      &self.name
     } else {
-      refers
-        .expect("[Element] refers should be defined")
-        .to_snake_case()
+      refers.expect("[Element] refers should be defined")
     };
 
-    log::info!("Generate element {:?}", name);
+    let rust_type = self.get_type_implementation(context);
 
-    let name = if multiple {
-      format!("{name}_list")
+    let referenced_element = self.get_referenced_element(context);
+
+    // members of a substitution group are matched by their own element name
+    if let Some(head) =
+      referenced_element.filter(|head| !head.get_substitution_members(context).is_empty())
+    {
+      if multiple {
+        log::warn!(
+          "Repeated references to the substitution group {} only accept its head",
+          head.name
+        );
+      } else {
+        let module = if context.is_in_sub_module() {
+          quote!()
+        } else {
+          context.get_types_path()
+        };
+        let substitution_type = head.get_substitution_type_name();
+
+        let rust_type = if self.get_min_occurences() == 0 {
+          quote!(Option<#module #substitution_type>)
+        } else {
+          quote!(#module #substitution_type)
+        };
+
+        return quote! {
+          #[yaserde(flatten)]
+          pub #attribute_name: #rust_type,
+        };
+      }
+    }
+    let kind = self
+      .kind
+      .as_ref()
+      .or_else(|| referenced_element.and_then(|element| element.get_kind(context)))
+      .or_else(|| {
+        self
+          .complex_type
+          .as_ref()
+          .and_then(|complex_type| complex_type.get_simple_content_base())
+      });
+
+    // structures of global elements are generated beside the sub module, not inside
+    let is_global_structure = referenced_element
+      .map(|element| element.kind.is_none() && element.complex_type.is_some())
+      .unwrap_or_default();
+
+    let is_built_in = kind
+      .map(|kind| {
+        RustTypesMapping::is_xs_string(context, kind) || RustTypesMapping::is_xs_int(context, kind)
+      })
+      .unwrap_or_default();
+
+    let module = if context.is_in_sub_module() || is_global_structure || is_built_in {
+      quote!()
     } else {
-      name
+      context.get_types_path()
     };
 
-    let attribute_name = Ident::new(&name, Span::call_site());
-    let yaserde_rename = if !self.name.is_empty() {
-      &self.name
+    // a recursive reference needs an indirection, a `Vec` already provides it
+    let rust_type = if self.is_recursive(context) {
+      quote!(Box<#module#rust_type>)
     } else {
-      refers.expect("[Element] refers should be defined")
+      quote!(#module#rust_type)
+    };
+
+    let rust_type = if self.is_nillable(context) {
+      quote!(xml_schema::Nillable<#rust_type>)
+    } else {
+      rust_type
+    };
+
+    let rust_type = if multiple {
+      quote!(Vec<#rust_type>)
+    } else if self.get_min_occurences() == 0 {
+      quote!(Option<#rust_type>)
+    } else {
+      rust_type
+    };
+
+    let reference_prefix = refers.and_then(|refers| context.get_reference_prefix(refers));
+
+    // global elements are always qualified, local ones follow their form
+    let is_unqualified = refers.is_none()
+      && self
+        .form
+        .as_ref()
+        .or_else(|| context.get_element_form_default())
+        == Some(&Qualification::Unqualified);
+
+    let (yaserde_rename, prefix) = match (&reference_prefix, refers) {
+      (Some(_), Some(refers)) => (
+        refers.split(':').next_back().unwrap_or(refers),
+        &reference_prefix,
+      ),
+      _ if is_unqualified => (yaserde_rename, &None),
+      _ => (yaserde_rename, prefix),
+    };
+
+    let prefix_attribute = prefix
+      .as_ref()
+      .map(|prefix| quote!(, prefix=#prefix))
+      .unwrap_or_default();
+
+    let placeholder_attribute = if let Some(function) = self.get_default_function_name(context) {
+      quote!(, default=#function)
+    } else if multiple || self.get_min_occurences() == 0 || self.has_default(context) {
+      quote!()
+    } else {
+      let placeholder = Restriction::get_placeholder_name(
+        context.parent_name().unwrap_or_default(),
+        &attribute_name.to_string(),
+      );
+      quote!(, default=#placeholder)
     };
 
-    let rust_type = if let Some(complex_type) = &self.complex_type {
-      complex_type.get_integrated_implementation(&self.name)
+    quote! {
+      #[yaserde(rename=#yaserde_rename #prefix_attribute #placeholder_attribute)]
+      pub #attribute_name: #rust_type,
+    }
+  }
+
+  /// Whether the type of a single occurrence implements `Default`.
+  fn has_default(&self, context: &XsdContext) -> bool {
+    if self.fixed.is_some() || self.is_nillable(context) {
+      return true;
+    }
+
+    if let Some(restriction) = self.get_inline_restriction() {
+      return restriction.is_enumeration() || restriction.has_valid_default(context);
+    }
+
+    self
+      .kind
+      .as_ref()
+      .map(|kind| context.has_default(kind))
+      .unwrap_or(true)
+  }
+
+  /// Rust type of a single occurrence of the element.
+  pub fn get_type_implementation(&self, context: &XsdContext) -> TokenStream {
+    if let Some(fixed_type_name) = self.get_fixed_type_name(context) {
+      quote!(#fixed_type_name)
+    } else if self.get_inline_restriction().is_some() {
+      let type_name = self.get_inline_type_name(context);
+      quote!(#type_name)
+    } else if let Some(complex_type) = &self.complex_type {
+      complex_type.get_integrated_implementation(context, &self.name)
     } else if let Some(simple_type) = &self.simple_type {
       simple_type.get_type_implementation(context, &Some(self.name.to_owned()))
     } else if let Some(kind) = &self.kind {
       RustTypesMapping::get(context, kind)
-    } else if let Some(refers) = refers {
-      RustTypesMapping::get(context, refers)
+    } else if let Some(refers) = self.get_refers() {
+      let referenced_element = self.get_referenced_element(context);
+
+      match referenced_element {
+        Some(element) if element.get_kind(context).is_some() => {
+          RustTypesMapping::get(context, element.get_kind(context).unwrap())
+        }
+        Some(element) if element.complex_type.is_some() => {
+          let struct_name = Ident::new(
+            &element.name.replace('.', "_").to_upper_camel_case(),
+            Span::call_site(),
+          );
+
+          if context.is_in_sub_module() {
+            quote!(super::#struct_name)
+          } else {
+            quote!(#struct_name)
+          }
+        }
+        _ => RustTypesMapping::get(context, refers),
+      }
     } else {
       panic!(
         "[Element] {:?} unimplemented type: {:?}",
         self.name, self.kind,
       );
+    }
+  }
+
+  pub fn get_field_name(&self) -> String {
+    if let Some(field_name) = self
+      .annotation
+      .as_ref()
+      .and_then(|annotation| annotation.get_field_name())
+    {
+      return field_name;
+    }
+
+    let name = if self.name.to_lowercase() == "type" {
+      "kind".to_string()
+    } else if !self.name.is_empty() {
+      self.name.to_snake_case()
+    } else {
+      let refers = self
+        .get_refers()
+        .expect("[Element] refers should be defined");
+      refers
+        .split(':')
+        .next_back()
+        .unwrap_or(refers)
+        .to_snake_case()
+    };
+
+    if self.is_multiple() {
+      format!("{name}_list")
+    } else {
+      name
+    }
+  }
+
+  /// Expression building the schema `default` value of the field, for built-in types and lists
+  /// of built-in types only.
+  pub fn get_default_implementation(&self, context: &XsdContext) -> Option<TokenStream> {
+    let default = self.default.as_ref()?;
+    let kind = self.kind.as_ref()?;
+
+    if self.is_multiple()
+      || self.fixed.is_some()
+      || self.is_nillable(context)
+      || self.get_inline_restriction().is_some()
+    {
+      return None;
+    }
+
+    let default = if RustTypesMapping::is_xml_schema_type(context, kind) {
+      RustTypesMapping::get_literal(kind, default)?
+    } else {
+      // the literal of a list holds its whitespace separated items
+      let item_type = context
+        .get_simple_type(kind)
+        .and_then(|simple_type| simple_type.list.as_ref())
+        .map(|list| &list.item_type)
+        .filter(|item_type| RustTypesMapping::is_xml_schema_type(context, item_type))?;
+
+      let items = default
+        .split_whitespace()
+        .map(|item| RustTypesMapping::get_literal(item_type, item))
+        .collect::<Option<Vec<TokenStream>>>()?;
+
+      quote!(From::from(vec![#(#items),*]))
+    };
+
+    if self.get_min_occurences() == 0 {
+      Some(quote!(Some(#default)))
+    } else {
+      Some(default)
+    }
+  }
+
+  /// Function giving the schema `default` value of an optional element when it is absent,
+  /// named after the structure holding the field.
+  fn get_default_function_name(&self, context: &XsdContext) -> Option<String> {
+    if self.get_min_occurences() != 0 {
+      return None;
+    }
+
+    let parent_name = context.parent_name().filter(|name| !name.is_empty())?;
+    self.get_default_implementation(context)?;
+
+    Some(format!(
+      "__{}_{}_default",
+      parent_name.to_snake_case(),
+      self.get_field_name()
+    ))
+  }
+
+  /// Functions named by the `#[yaserde(default)]` of optional elements, returning their schema `default` value.
+  pub fn get_default_functions_implementation(
+    fields: &FieldsNamed,
+    field_defaults: &BTreeMap<String, TokenStream>,
+  ) -> TokenStream {
+    Restriction::get_default_functions(fields, "_default")
+      .iter()
+      .filter_map(|(field_name, function, rust_type)| {
+        let value = field_defaults.get(&field_name.to_string())?;
+
+        Some(quote!(
+          fn #function() -> #rust_type {
+            #value
+          }
+        ))
+      })
+      .collect()
+  }
+
+  /// Check of the number of occurrences against `minOccurs` and a bounded `maxOccurs`, for repeated elements.
+  pub fn get_occurrences_check(&self) -> Option<TokenStream> {
+    if !self.is_multiple() || (self.name.is_empty() && self.get_refers().is_none()) {
+      return None;
+    }
+
+    let min = self.get_min_occurences() as usize;
+    let max = match self.get_max_occurences() {
+      MaxOccurences::Number { value } => Some(value as usize),
+      MaxOccurences::Unbounded => None,
     };
 
-    let module = (!context.is_in_sub_module()
-      && !self
+    if min <= 1 && max.is_none() {
+      return None;
+    }
+
+    let field_name = Ident::new(&self.get_field_name(), Span::call_site());
+    let name = if self.name.is_empty() {
+      self.get_refers().unwrap_or_default()
+    } else {
+      &self.name
+    };
+
+    let min_check = (min > 1).then(|| {
+      quote!(
+        if self.#field_name.len() < #min {
+          return Err(format!(
+            "{} expects at least {} occurrences, found {}",
+            #name,
+            #min,
+            self.#field_name.len()
+          ));
+        }
+      )
+    });
+
+    let max_check = max.map(|max| {
+      quote!(
+        if self.#field_name.len() > #max {
+          return Err(format!(
+            "{} expects at most {} occurrences, found {}",
+            #name,
+            #max,
+            self.#field_name.len()
+          ));
+        }
+      )
+    });
+
+    Some(quote!(
+      #min_check
+      #max_check
+    ))
+  }
+
+  /// Global element declaration of the schema this element refers to, its occurrences stay the ones of the reference.
+  fn get_referenced_element<'a>(&self, context: &'a XsdContext) -> Option<&'a Element> {
+    if !self.name.is_empty() {
+      return None;
+    }
+
+    context.get_element(self.get_refers()?)
+  }
+
+  /// `block="substitution"` (or `#all`) only accepts the head element itself.
+  pub fn blocks_substitution(&self) -> bool {
+    self
+      .block
+      .as_ref()
+      .map(|block| {
+        block
+          .split_whitespace()
+          .any(|blocked| blocked == "substitution" || blocked == "#all")
+      })
+      .unwrap_or_default()
+  }
+
+  /// Global elements which can substitute this one, directly or through another member.
+  pub fn get_substitution_members<'a>(&self, context: &'a XsdContext) -> Vec<&'a Element> {
+    if self.blocks_substitution() {
+      return vec![];
+    }
+
+    let mut members = vec![];
+    let mut heads = vec![self.name.as_str()];
+
+    while let Some(head) = heads.pop() {
+      for member in context.get_substitution_members(head) {
+        if member.name != self.name && !members.contains(&member) {
+          members.push(member);
+          heads.push(member.name.as_str());
+        }
+      }
+    }
+
+    members
+  }
+
+  /// A `nillable` element of a built-in type parsed from its text, its content can be left
+  /// empty with `xsi:nil`.
+  pub fn is_nillable(&self, context: &XsdContext) -> bool {
+    if self.nillable != Some(true) {
+      return false;
+    }
+
+    let is_built_in = self.fixed.is_none()
+      && self.get_inline_restriction().is_none()
+      && self
         .kind
         .as_ref()
+        .filter(|kind| RustTypesMapping::is_xml_schema_type(context, kind))
         .map(|kind| {
-          RustTypesMapping::is_xs_string(context, kind)
-            || RustTypesMapping::is_xs_int(context, kind)
+          !matches!(
+            kind.split(':').next_back(),
+            Some("QName" | "NOTATION" | "IDREFS")
+          )
         })
-        .unwrap_or_default())
-    .then_some(quote!(xml_schema_types::))
-    .unwrap_or_default();
+        .unwrap_or_default();
 
-    let rust_type = if multiple {
-      quote!(Vec<#module#rust_type>)
-    } else if self.min_occurences == Some(0) {
-      quote!(Option<#module#rust_type>)
-    } else {
-      quote!(#module#rust_type)
-    };
+    if !is_built_in {
+      log::warn!(
+        "Element {} is nillable, only elements of built-in types support xsi:nil",
+        self.name
+      );
+    }
 
-    let prefix_attribute = prefix
+    is_built_in
+  }
+
+  /// Whether the structure held by the element has a `validate` method, the named types of
+  /// `validated_types` having one.
+  pub fn has_validation(&self, context: &XsdContext, validated_types: &BTreeSet<String>) -> bool {
+    if (self.name.is_empty() && self.get_refers().is_none())
+      || self.is_prohibited()
+      || self.is_nillable(context)
+    {
+      return false;
+    }
+
+    let referenced_element = self.get_referenced_element(context);
+
+    // a single reference to a substitution group holds the enumeration of its members
+    if !self.is_multiple()
+      && referenced_element.is_some_and(|head| !head.get_substitution_members(context).is_empty())
+    {
+      return false;
+    }
+
+    let element = referenced_element.unwrap_or(self);
+
+    if let Some(kind) = element.get_kind(context) {
+      return context
+        .get_complex_type(kind)
+        .is_some_and(|complex_type| validated_types.contains(&complex_type.name));
+    }
+
+    element
+      .complex_type
       .as_ref()
-      .map(|prefix| quote!(, prefix=#prefix))
-      .unwrap_or_default();
+      .filter(|complex_type| complex_type.get_simple_content_base().is_none())
+      .is_some_and(|complex_type| complex_type.has_validation(context, validated_types))
+  }
 
-    quote! {
-      #[yaserde(rename=#yaserde_rename #prefix_attribute)]
-      pub #attribute_name: #rust_type,
+  /// An abstract element, or an element of an abstract type, can not appear in a document.
+  pub fn is_abstract(&self, context: &XsdContext) -> bool {
+    self.is_abstract == Some(true)
+      || self
+        .kind
+        .as_ref()
+        .and_then(|kind| context.get_complex_type(kind))
+        .map(|complex_type| complex_type.is_abstract == Some(true))
+        .unwrap_or_default()
+  }
+
+  /// Declared type, a member of a substitution group without its own type has the type of its
+  /// first head.
+  pub fn get_kind<'a>(&'a self, context: &'a XsdContext) -> Option<&'a String> {
+    let mut element = self;
+    let mut visited = vec![];
+
+    while element.kind.is_none() && element.complex_type.is_none() && element.simple_type.is_none()
+    {
+      visited.push(element.name.as_str());
+      element = context
+        .get_element(element.get_substitution_heads().first()?)
+        .filter(|head| !visited.contains(&head.name.as_str()))?;
+    }
+
+    element.kind.as_ref()
+  }
+
+  /// Heads of the `substitutionGroup`, XSD 1.1 allows a whitespace-separated list of them.
+  pub fn get_substitution_heads(&self) -> Vec<&str> {
+    self
+      .substitution_group
+      .as_deref()
+      .map(|substitution_group| substitution_group.split_whitespace().collect())
+      .unwrap_or_default()
+  }
+
+  fn get_substitution_type_name(&self) -> Ident {
+    Ident::new(
+      &format!("{}_substitution", self.name.replace('.', "_")).to_upper_camel_case(),
+      Span::call_site(),
+    )
+  }
+
+  /// Enumeration of the head element and the members of its substitution group, abstract ones excluded.
+  pub fn get_substitution_implementation(&self, context: &XsdContext) -> TokenStream {
+    let members = self.get_substitution_members(context);
+    if members.is_empty() {
+      return quote!();
+    }
+
+    let enum_name = self.get_substitution_type_name();
+    let head_name = &self.name;
+    let non_exhaustive = context.get_non_exhaustive_attribute();
+
+    let variants: Vec<(Ident, &str, TokenStream)> = std::iter::once(self)
+      .chain(members)
+      .filter(|element| !element.is_abstract(context))
+      .map(|element| {
+        let variant_name = Ident::new(
+          &element.name.replace('.', "_").to_upper_camel_case(),
+          Span::call_site(),
+        );
+        let reference = Element {
+          refers: Some(element.name.clone()),
+          ..Default::default()
+        };
+
+        (
+          variant_name,
+          element.name.as_str(),
+          reference.get_type_implementation(context),
+        )
+      })
+      .collect();
+
+    let Some((default_variant, _, _)) = variants.first() else {
+      log::warn!(
+        "No concrete element in the {} substitution group",
+        self.name
+      );
+      return quote!();
+    };
+
+    let variants_definition = variants
+      .iter()
+      .map(|(variant_name, _, rust_type)| quote!(#variant_name(#rust_type),));
+
+    let deserialize_variants = variants.iter().map(|(variant_name, name, rust_type)| {
+      quote!(
+        #name => {
+          return <#rust_type as yaserde::YaDeserialize>::deserialize(reader).map(#enum_name::#variant_name);
+        }
+      )
+    });
+
+    let serialize_variants = variants.iter().map(|(variant_name, name, _)| {
+      quote!(
+        #enum_name::#variant_name(value) => {
+          writer.set_start_event_name(Some(#name.to_string()));
+          writer.set_skip_start_end(false);
+          yaserde::YaSerialize::serialize(value, writer)
+        }
+      )
+    });
+
+    quote!(
+      #[derive(Clone, Debug, PartialEq)]
+      #non_exhaustive
+      pub enum #enum_name {
+        #(#variants_definition)*
+      }
+
+      impl Default for #enum_name {
+        fn default() -> Self {
+          #enum_name::#default_variant(Default::default())
+        }
+      }
+
+      impl yaserde::YaDeserialize for #enum_name {
+        fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
+          loop {
+            match reader.peek()?.to_owned() {
+              xml::reader::XmlEvent::StartElement { name, .. } => match name.local_name.as_str() {
+                #(#deserialize_variants)*
+                _ => {
+                  reader.next_event()?;
+                }
+              },
+              xml::reader::XmlEvent::EndDocument => {
+                return Err(format!("No element of the {} substitution group found", #head_name));
+              }
+              _ => {
+                reader.next_event()?;
+              }
+            }
+          }
+        }
+      }
+
+      impl yaserde::YaSerialize for #enum_name {
+        fn serialize<W: std::io::Write>(&self, writer: &mut yaserde::ser::Serializer<W>) -> Result<(), String> {
+          match self {
+            #(#serialize_variants)*
+          }
+        }
+
+        fn serialize_attributes(&self, source_attributes: Vec<xml::attribute::OwnedAttribute>, source_namespace: xml::namespace::Namespace) -> Result<(Vec<xml::attribute::OwnedAttribute>, xml::namespace::Namespace), String> {
+          Ok((source_attributes, source_namespace))
+        }
+      }
+    )
+  }
+
+  /// Single occurrence whose type holds back the complex type being generated.
+  /// In a global complex type, only the references selected to break the cycles are recursive.
+  pub fn is_recursive(&self, context: &XsdContext) -> bool {
+    if self.is_multiple() {
+      return false;
+    }
+
+    match (&self.kind, context.parent_name()) {
+      (Some(kind), Some(parent_name)) if context.get_complex_type(kind).is_some() => {
+        if let Some(is_boxed) = context.is_boxed_reference(parent_name, &self.name) {
+          return is_boxed;
+        }
+
+        let name = kind.split(':').next_back().unwrap_or(kind);
+        name == parent_name || context.reaches_type(name, parent_name)
+      }
+      _ => false,
     }
   }
 
+  /// `minOccurs`, 1 when absent.
+  pub fn get_min_occurences(&self) -> u64 {
+    self.min_occurences.unwrap_or(1)
+  }
+
+  /// `maxOccurs`, 1 when absent.
+  pub fn get_max_occurences(&self) -> MaxOccurences {
+    self.max_occurences.clone().unwrap_or_default()
+  }
+
+  pub fn is_multiple(&self) -> bool {
+    self.get_max_occurences() != MaxOccurences::Number { value: 1 } && !self.is_prohibited()
+  }
+
+  /// `maxOccurs="0"` removes the element, like a restriction leaving out an optional element of its base.
+  pub fn is_prohibited(&self) -> bool {
+    self.get_max_occurences() == MaxOccurences::Number { value: 0 }
+  }
+
+  /// Name of the dedicated type guarding a `fixed` value, prefixed by the parent type.
+  fn get_fixed_type_name(&self, context: &XsdContext) -> Option<Ident> {
+    self.fixed.as_ref()?;
+
+    Some(self.get_inline_type_name(context))
+  }
+
+  /// Inline restriction with facets or enumerations, it takes precedence over the `type` attribute.
+  /// Its base defaults to the `type` of the element.
+  fn get_inline_restriction(&self) -> Option<Restriction> {
+    let restriction = self.simple_type.as_ref()?.restriction.as_ref()?;
+
+    if !restriction.is_enumeration() && !restriction.has_facets() {
+      return None;
+    }
+
+    Some(Restriction {
+      base: restriction.base.clone().or_else(|| self.kind.clone()),
+      ..restriction.clone()
+    })
+  }
+
+  /// Name of a type dedicated to this element, prefixed by the parent type.
+  fn get_inline_type_name(&self, context: &XsdContext) -> Ident {
+    let type_name = format!(
+      "{}_{}",
+      context.parent_name().unwrap_or_default(),
+      self.name
+    )
+    .replace('.', "_")
+    .to_upper_camel_case();
+    Ident::new(&type_name, Span::call_site())
+  }
+
+  fn get_fixed_implementation(&self, context: &XsdContext, struct_name: &Ident) -> TokenStream {
+    let fixed = self
+      .fixed
+      .as_ref()
+      .expect("[Element] fixed should be defined");
+    let name = &self.name;
+
+    let content_type = self
+      .kind
+      .as_ref()
+      .map(|kind| RustTypesMapping::get(context, kind))
+      .unwrap_or_else(|| quote!(String));
+
+    let non_exhaustive = context.get_non_exhaustive_attribute();
+
+    let fixed_content = match &self.kind {
+      Some(kind) if !RustTypesMapping::is_xml_schema_type(context, kind) => {
+        quote!(#fixed.parse().unwrap())
+      }
+      Some(kind) => RustTypesMapping::get_literal(kind, fixed)
+        .unwrap_or_else(|| panic!("[Element] fixed {} values are not supported", kind)),
+      None => quote!(#fixed.to_string()),
+    };
+
+    quote!(
+      #[derive(Clone, Debug, PartialEq)]
+      #non_exhaustive
+      pub struct #struct_name {
+        pub content: #content_type,
+      }
+
+      impl Default for #struct_name {
+        fn default() -> Self {
+          #struct_name {
+            content: #fixed_content,
+          }
+        }
+      }
+
+      impl yaserde::YaDeserialize for #struct_name {
+        fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
+          if let xml::reader::XmlEvent::StartElement{..} = reader.peek()? {
+            reader.next_event()?;
+          }
+
+          let text_content = if let xml::reader::XmlEvent::Characters(ref text_content) = reader.peek()? {
+            text_content.trim().to_string()
+          } else {
+            String::new()
+          };
+
+          let fixed = Self::default();
+          let content: #content_type = text_content
+            .parse()
+            .map_err(|_| format!("Unable to parse {:?} for element {}", text_content, #name))?;
+
+          if content != fixed.content {
+            return Err(format!("Element {} is fixed to {:?}, found {:?}", #name, #fixed, text_content));
+          }
+
+          if !text_content.is_empty() {
+            reader.next_event()?;
+          }
+
+          Ok(fixed)
+        }
+      }
+
+      impl yaserde::YaSerialize for #struct_name {
+        fn serialize<W: std::io::Write>(&self, writer: &mut yaserde::ser::Serializer<W>) -> Result<(), String> {
+          let skip_start_end = writer.skip_start_end();
+          let name = writer.get_start_event_name().unwrap_or_else(|| #name.to_string());
+
+          if !skip_start_end {
+            writer.write(xml::writer::XmlEvent::start_element(name.as_str())).map_err(|e| e.to_string())?;
+          }
+
+          let content = self.content.to_string();
+          writer.write(xml::writer::XmlEvent::characters(&content)).map_err(|e| e.to_string())?;
+
+          if !skip_start_end {
+            writer.write(xml::writer::XmlEvent::end_element()).map_err(|e| e.to_string())?;
+          }
+
+          Ok(())
+        }
+
+        fn serialize_attributes(&self, source_attributes: Vec<xml::attribute::OwnedAttribute>, source_namespace: xml::namespace::Namespace) -> Result<(Vec<xml::attribute::OwnedAttribute>, xml::namespace::Namespace), String> {
+          Ok((source_attributes, source_namespace))
+        }
+      }
+    )
+  }
+
   fn get_refers(&self) -> Option<&str> {
     self.refers.as_ref().and_then(|refers| {
       if refers.is_empty() {
@@ -196,6 +1222,7 @@ impl Element {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::xsd::documentation::Documentation;
   use std::str::FromStr;
 
   static DERIVES: &str =
@@ -203,6 +1230,47 @@ mod tests {
 
   static DOCS: &str = r#"#[doc = "Loudness measured in Decibels"]"#;
 
+  static DOCUMENT: &str = r#"
+    impl std::str::FromStr for Volume {
+      type Err = xml_schema::XsdError;
+
+      #[doc = r" Parse a whole XML document holding this element as root, the errors naming the path"]
+      #[doc = r" and position of the element which failed."]
+      fn from_str(content: &str) -> Result<Self, Self::Err> {
+        xml_schema::de::from_str_located(content).map_err(xml_schema::XsdError::Parse)
+      }
+    }
+
+    impl Volume {
+      #[doc = r" Serialize as a whole XML document, declaring the namespaces of the schema."]
+      pub fn to_xml_string(&self) -> Result<String, xml_schema::XsdError> {
+        yaserde::ser::to_string(self).map_err(xml_schema::XsdError::Serialization)
+      }
+    }
+
+    impl Volume {
+      #[doc = r" Parse a whole XML document holding this element as root, then check it against the"]
+      #[doc = r" constraints of the schema, returning every failure."]
+      pub fn from_xml_validated(content: &str) -> Result<Self, xml_schema::XsdError> {
+        let value: Self = xml_schema::de::from_str_located(content).map_err(xml_schema::XsdError::Parse) ? ;
+        Ok(value)
+      }
+    }"#;
+
+  #[test]
+  fn blocked_substitution() {
+    let element = |block: Option<&str>| Element {
+      name: "shape".to_string(),
+      block: block.map(|block| block.to_string()),
+      ..Default::default()
+    };
+
+    assert!(!element(None).blocks_substitution());
+    assert!(!element(Some("extension")).blocks_substitution());
+    assert!(element(Some("extension substitution")).blocks_substitution());
+    assert!(element(Some("#all")).blocks_substitution());
+  }
+
   #[test]
   fn extern_type() {
     let element = Element {
@@ -213,10 +1281,24 @@ mod tests {
       max_occurences: None,
       complex_type: None,
       simple_type: None,
+      default: None,
+      fixed: None,
+      keys: vec![],
+      keyrefs: vec![],
+      uniques: vec![],
+      substitution_group: None,
+      block: None,
+      is_abstract: None,
+      form: None,
+      nillable: None,
       annotation: Some(Annotation {
         id: None,
         attributes: vec![],
-        documentation: vec!["Loudness measured in Decibels".to_string()],
+        documentation: vec![Documentation {
+          lang: None,
+          content: "Loudness measured in Decibels".to_string(),
+        }],
+        appinfos: vec![],
       }),
     };
 
@@ -230,10 +1312,12 @@ mod tests {
       r#"
         {DOCS}
         {DERIVES}
+        #[yaserde(rename = "volume")]
         pub struct Volume {{
           #[yaserde(flatten)]
           pub content: xml_schema_types::VolumeType,
-        }}"#
+        }}
+        {DOCUMENT}"#
     ))
     .unwrap();
 
@@ -250,10 +1334,24 @@ mod tests {
       max_occurences: None,
       complex_type: None,
       simple_type: None,
+      default: None,
+      fixed: None,
+      keys: vec![],
+      keyrefs: vec![],
+      uniques: vec![],
+      substitution_group: None,
+      block: None,
+      is_abstract: None,
+      form: None,
+      nillable: None,
       annotation: Some(Annotation {
         id: None,
         attributes: vec![],
-        documentation: vec!["Loudness measured in Decibels".to_string()],
+        documentation: vec![Documentation {
+          lang: None,
+          content: "Loudness measured in Decibels".to_string(),
+        }],
+        appinfos: vec![],
       }),
     };
 
@@ -267,10 +1365,12 @@ mod tests {
       r#"
         {DOCS}
         {DERIVES}
+        #[yaserde(rename = "volume")]
         pub struct Volume {{
           #[yaserde(text)]
-          pub content: xml_schema_types::String,
-        }}"#
+          pub content: String,
+        }}
+        {DOCUMENT}"#
     ))
     .unwrap();
 
@@ -289,6 +1389,16 @@ mod tests {
       complex_type: None,
       simple_type: None,
       annotation: None,
+      default: None,
+      fixed: None,
+      keys: vec![],
+      keyrefs: vec![],
+      uniques: vec![],
+      substitution_group: None,
+      block: None,
+      is_abstract: None,
+      form: None,
+      nillable: None,
     };
 
     let context =
@@ -314,6 +1424,16 @@ mod tests {
       complex_type: None,
       simple_type: None,
       annotation: None,
+      default: None,
+      fixed: None,
+      keys: vec![],
+      keyrefs: vec![],
+      uniques: vec![],
+      substitution_group: None,
+      block: None,
+      is_abstract: None,
+      form: None,
+      nillable: None,
     };
 
     let implementation = element.get_field_implementation(&context, &None);
@@ -324,5 +1444,102 @@ mod tests {
     .unwrap();
 
     assert_eq!(implementation.to_string(), expected.to_string());
+
+    let context = context.with_types_path(&Some("crate::generated".to_string()));
+
+    let implementation = element.get_field_implementation(&context, &None);
+
+    let expected = TokenStream::from_str(
+      r#"#[yaserde(rename = "OwnedType")] pub owned_type_list : Vec < crate :: generated :: OwnedType > ,"#
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn default_occurrences() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let element = |min_occurences, max_occurences| Element {
+      name: "title".to_string(),
+      kind: Some("xs:string".to_string()),
+      min_occurences,
+      max_occurences,
+      ..Default::default()
+    };
+
+    // <xs:element name="title" type="xs:string" />
+    let implicit = element(None, None);
+    assert_eq!(implicit.get_min_occurences(), 1);
+    assert_eq!(
+      implicit.get_max_occurences(),
+      MaxOccurences::Number { value: 1 }
+    );
+    assert!(!implicit.is_multiple());
+
+    let expected =
+      TokenStream::from_str(r#"#[yaserde(rename = "title")] pub title : String ,"#).unwrap();
+
+    assert_eq!(
+      implicit
+        .get_field_implementation(&context, &None)
+        .to_string(),
+      expected.to_string()
+    );
+
+    // <xs:element name="title" type="xs:string" minOccurs="1" maxOccurs="1" />
+    let explicit = element(Some(1), Some(MaxOccurences::Number { value: 1 }));
+    assert_eq!(
+      explicit
+        .get_field_implementation(&context, &None)
+        .to_string(),
+      expected.to_string()
+    );
+
+    // <xs:element name="title" type="xs:string" minOccurs="0" />
+    let optional = element(Some(0), None);
+    let expected =
+      TokenStream::from_str(r#"#[yaserde(rename = "title")] pub title : Option < String > ,"#)
+        .unwrap();
+
+    assert_eq!(
+      optional
+        .get_field_implementation(&context, &None)
+        .to_string(),
+      expected.to_string()
+    );
+  }
+
+  #[test]
+  fn hidden_anonymous_types() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap()
+        .with_hide_anonymous_types(true);
+
+    // <xs:element name="volume"><xs:complexType/></xs:element>
+    let element = Element {
+      name: "volume".to_string(),
+      complex_type: Some(ComplexType::default()),
+      ..Default::default()
+    };
+
+    let implementation = element
+      .get_subtypes_implementation(&TokenStream::new(), &None, &context)
+      .to_string();
+    assert!(implementation.starts_with("# [doc (hidden)] # [derive"));
+
+    let named_type = ComplexType {
+      name: "Volume".to_string(),
+      ..Default::default()
+    };
+
+    let implementation = named_type
+      .implement(&TokenStream::new(), &None, &context)
+      .to_string();
+    assert!(!implementation.contains("hidden"));
   }
 }
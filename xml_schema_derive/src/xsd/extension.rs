@@ -38,6 +38,10 @@ impl Implementation for Extension {
 
     let inner_attribute = if format!("{rust_type}") == "String" {
       quote!(#[yaserde(text)])
+    } else if !RustTypesMapping::is_xml_schema_type(context, &self.base)
+      && context.get_complex_type(&self.base).is_none()
+    {
+      quote!(#[yaserde(flatten)])
     } else {
       TokenStream::new()
     };
@@ -66,7 +70,7 @@ impl Extension {
 
         quote!(
           ,
-          #[serde(flatten)]
+          #[yaserde(flatten)]
           pub extension : #group_type
         )
       })
@@ -123,6 +127,10 @@ mod tests {
           reference: None,
           required: Required::Required,
           simple_type: None,
+          default: None,
+          fixed: None,
+          form: None,
+          annotation: None,
         },
         Attribute {
           name: Some("attribute_2".to_string()),
@@ -130,6 +138,10 @@ mod tests {
           reference: None,
           required: Required::Optional,
           simple_type: None,
+          default: None,
+          fixed: None,
+          form: None,
+          annotation: None,
         },
       ],
       sequences: vec![],
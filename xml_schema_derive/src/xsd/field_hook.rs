@@ -0,0 +1,81 @@
+use proc_macro2::TokenStream;
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+/// Hook invoked for every generated element field, returning extra attributes for the field.
+pub trait FieldHook: std::fmt::Debug {
+  fn get_attributes(&self, field_name: &str, field_type: &str) -> TokenStream;
+}
+
+/// Hook of the `field_attributes` option, adding attributes to the fields by their outer type
+/// name (`Option`, `Vec`, `String`...), `*` matching every field.
+#[derive(Debug, Default)]
+pub struct OuterTypeAttributes(BTreeMap<String, TokenStream>);
+
+impl OuterTypeAttributes {
+  pub fn new(field_attributes: &BTreeMap<String, String>) -> Result<Self, String> {
+    field_attributes
+      .iter()
+      .map(|(outer_type, attributes)| {
+        TokenStream::from_str(attributes)
+          .map(|attributes| (outer_type.to_string(), attributes))
+          .map_err(|error| format!("invalid field attributes {attributes:?}: {error}"))
+      })
+      .collect::<Result<_, _>>()
+      .map(OuterTypeAttributes)
+  }
+}
+
+impl FieldHook for OuterTypeAttributes {
+  fn get_attributes(&self, _field_name: &str, field_type: &str) -> TokenStream {
+    let outer_type = field_type
+      .split(|c: char| c == '<' || c.is_whitespace())
+      .next()
+      .unwrap_or_default();
+    let outer_type = outer_type.rsplit("::").next().unwrap_or(outer_type);
+
+    self
+      .0
+      .iter()
+      .filter(|(name, _)| name.as_str() == "*" || name.as_str() == outer_type)
+      .map(|(_, attributes)| attributes.clone())
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn outer_type_attributes() {
+    let outer_type_attributes = OuterTypeAttributes::new(&BTreeMap::from([
+      ("Option".to_string(), "#[optional]".to_string()),
+      ("*".to_string(), "#[any]".to_string()),
+    ]))
+    .unwrap();
+
+    assert_eq!(
+      outer_type_attributes
+        .get_attributes("name", "Option < String >")
+        .to_string(),
+      "# [any] # [optional]"
+    );
+    assert_eq!(
+      outer_type_attributes
+        .get_attributes("items", "Vec < std :: option :: Option < i32 > >")
+        .to_string(),
+      "# [any]"
+    );
+  }
+
+  #[test]
+  fn invalid_attributes() {
+    let outer_type_attributes = OuterTypeAttributes::new(&BTreeMap::from([(
+      "Option".to_string(),
+      "#[unclosed(".to_string(),
+    )]));
+
+    assert!(outer_type_attributes.is_err());
+  }
+}
@@ -36,9 +36,12 @@ impl Implementation for Group {
       .map(|sequence| sequence.get_field_implementation(context, prefix))
       .unwrap_or_default();
 
+    let non_exhaustive = context.get_non_exhaustive_attribute();
+
     quote!(
-      #[derive(Clone, Debug, Default, PartialEq, serde::Deserialize, serde::Serialize)]
+      #[derive(Clone, Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize)]
       #namespace_definition
+      #non_exhaustive
       pub struct #struct_name {
         #fields
       }
@@ -85,7 +88,7 @@ mod tests {
 
     let implementation = format!("{}", group.implement(&TokenStream::new(), &None, &context));
 
-    assert_eq!(implementation, "# [derive (Clone , Debug , Default , PartialEq , serde :: Deserialize , serde :: Serialize)] \
+    assert_eq!(implementation, "# [derive (Clone , Debug , Default , PartialEq , yaserde_derive :: YaDeserialize , yaserde_derive :: YaSerialize)] \
 pub struct Groupthing { \
 # [yaserde (rename = \"CX_X\")] pub cx_x : xml_schema_types :: Asdfg , \
 # [yaserde (rename = \"CY_X\")] pub cy_x : xml_schema_types :: Asdfg , }");
@@ -0,0 +1,156 @@
+use crate::xsd::{complex_type::ComplexType, XsdContext};
+use proc_macro2::{Span, TokenStream};
+use syn::Ident;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct IdentityConstraint {
+  #[yaserde(attribute)]
+  pub name: String,
+  #[yaserde(attribute)]
+  pub refer: Option<String>,
+  pub selector: XPath,
+  #[yaserde(rename = "field")]
+  pub fields: Vec<XPath>,
+}
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct XPath {
+  #[yaserde(attribute)]
+  pub xpath: String,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ConstraintKind {
+  Key,
+  KeyRef,
+  Unique,
+}
+
+impl IdentityConstraint {
+  /// Check of the constraint, evaluated from `root` of type `complex_type`.
+  /// Only child element paths (`a/b`) are supported as selector, and `@attribute` or child elements as fields.
+  pub fn get_check_implementation(
+    &self,
+    kind: ConstraintKind,
+    context: &XsdContext,
+    complex_type: &ComplexType,
+    root: &TokenStream,
+  ) -> Option<TokenStream> {
+    let (nodes, node_type) = self.get_selector_implementation(context, complex_type, root)?;
+
+    let values = self
+      .fields
+      .iter()
+      .map(|field| IdentityConstraint::get_field_implementation(context, node_type, &field.xpath))
+      .collect::<Option<Vec<TokenStream>>>()?;
+
+    let name = &self.name;
+
+    let check = match kind {
+      ConstraintKind::Key | ConstraintKind::Unique => {
+        let missing = if kind == ConstraintKind::Key {
+          quote!(return Err(format!("Missing field of the key {}", #name));)
+        } else {
+          quote!()
+        };
+
+        quote!(
+          let mut values = std::collections::BTreeSet::new();
+          for node in #nodes {
+            let value: Option<Vec<String>> = vec![#(#values),*].into_iter().collect();
+            match value {
+              Some(value) => {
+                if !values.insert(value.clone()) {
+                  return Err(format!("Duplicate value {:?} for {}", value, #name));
+                }
+              }
+              None => {
+                #missing
+              }
+            }
+          }
+          identities.insert(#name, values);
+        )
+      }
+      ConstraintKind::KeyRef => {
+        let refer = self.refer.as_ref()?;
+        let refer = refer.split(':').next_back().unwrap_or(refer);
+
+        quote!(
+          for node in #nodes {
+            let value: Option<Vec<String>> = vec![#(#values),*].into_iter().collect();
+            if let Some(value) = value {
+              let exists = identities
+                .get(#refer)
+                .map(|values| values.contains(&value))
+                .unwrap_or_default();
+
+              if !exists {
+                return Err(format!("Value {:?} of {} does not refer to any {}", value, #name, #refer));
+              }
+            }
+          }
+        )
+      }
+    };
+
+    Some(quote!({ #check }))
+  }
+
+  /// Iterator over the nodes matched by the selector, with their type.
+  fn get_selector_implementation<'a>(
+    &self,
+    context: &'a XsdContext,
+    complex_type: &'a ComplexType,
+    root: &TokenStream,
+  ) -> Option<(TokenStream, &'a ComplexType)> {
+    let mut nodes = quote!(std::iter::once(#root));
+    let mut node_type = complex_type;
+
+    for step in self
+      .selector
+      .xpath
+      .trim_start_matches("./")
+      .split('/')
+      .filter(|step| *step != ".")
+    {
+      let element = node_type.get_element(step)?;
+      let field_name = Ident::new(&element.get_field_name(), Span::call_site());
+
+      nodes = if element.is_multiple() || element.get_min_occurences() == 0 {
+        quote!(#nodes.flat_map(|node| node.#field_name.iter()))
+      } else {
+        quote!(#nodes.map(|node| &node.#field_name))
+      };
+
+      node_type = element.complex_type.as_ref().or_else(|| {
+        element
+          .kind
+          .as_ref()
+          .and_then(|kind| context.get_complex_type(kind))
+      })?;
+    }
+
+    Some((nodes, node_type))
+  }
+
+  /// `Option<String>` expression of a field of `node`.
+  fn get_field_implementation(
+    context: &XsdContext,
+    node_type: &ComplexType,
+    xpath: &str,
+  ) -> Option<TokenStream> {
+    let (field_name, optional) = match xpath.strip_prefix('@') {
+      Some(attribute) => node_type.get_attribute_field(context, attribute)?,
+      None => node_type.get_element_field(xpath)?,
+    };
+
+    if optional {
+      Some(quote!(node.#field_name.as_ref().map(|value| value.to_string())))
+    } else {
+      Some(quote!(Some(node.#field_name.to_string())))
+    }
+  }
+}
@@ -0,0 +1,33 @@
+use crate::xsd::schema::Schema;
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(
+  rename = "include"
+  prefix = "xs",
+  namespace = "xs: http://www.w3.org/2001/XMLSchema"
+)]
+pub struct Include {
+  #[yaserde(rename = "schemaLocation", attribute)]
+  pub schema_location: String,
+}
+
+impl Include {
+  /// Merge the definitions of the included schema into `schema`.
+  pub fn merge_into(schema: &mut Schema, included: &Schema) {
+    schema.elements.extend(included.elements.iter().cloned());
+    schema
+      .simple_type
+      .extend(included.simple_type.iter().cloned());
+    schema
+      .complex_type
+      .extend(included.complex_type.iter().cloned());
+    schema
+      .attributes
+      .extend(included.attributes.iter().cloned());
+    schema
+      .attribute_group
+      .extend(included.attribute_group.iter().cloned());
+    schema.group.extend(included.group.iter().cloned());
+    schema.notations.extend(included.notations.iter().cloned());
+  }
+}
@@ -18,24 +18,44 @@ impl Implementation for List {
   ) -> TokenStream {
     let list_type = RustTypesMapping::get(context, &self.item_type);
 
+    let non_exhaustive = context.get_non_exhaustive_attribute();
+    let name = struct_name.to_string();
+
     quote!(
       #[derive(Clone, Debug, Default, PartialEq)]
+      #non_exhaustive
       pub struct #struct_name {
         pub items: Vec<#list_type>
       }
 
+      impl std::ops::Deref for #struct_name {
+        type Target = Vec<#list_type>;
+
+        fn deref(&self) -> &Vec<#list_type> {
+          &self.items
+        }
+      }
+
+      impl From<Vec<#list_type>> for #struct_name {
+        fn from(items: Vec<#list_type>) -> Self {
+          #struct_name { items }
+        }
+      }
+
       impl yaserde::YaDeserialize for #struct_name {
         fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
           loop {
             match reader.next_event()? {
               xml::reader::XmlEvent::StartElement{..} => {}
               xml::reader::XmlEvent::Characters(ref text_content) => {
-                let items: Vec<#list_type> =
-                  text_content
-                    .split(' ')
-                    .map(|item| item.to_owned())
-                    .map(|item| item.parse().unwrap())
-                    .collect();
+                let items = text_content
+                  .split_whitespace()
+                  .map(|item| {
+                    item
+                      .parse::<#list_type>()
+                      .map_err(|_| format!("Unable to parse {:?} in {}", item, #name))
+                  })
+                  .collect::<Result<Vec<#list_type>, String>>()?;
 
                 return Ok(#struct_name {items});
               }
@@ -95,16 +115,34 @@ mod tests {
           pub items: Vec <String>
         }
 
+        impl std::ops::Deref for Parent {
+          type Target = Vec<String> ;
+
+          fn deref(&self) -> &Vec<String> {
+            &self.items
+          }
+        }
+
+        impl From<Vec<String>> for Parent {
+          fn from(items: Vec<String>) -> Self {
+            Parent { items }
+          }
+        }
+
         impl yaserde::YaDeserialize for Parent {
           fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
             loop {
               match reader.next_event()? {
                 xml::reader::XmlEvent::StartElement{..} => { }
                 xml::reader::XmlEvent::Characters(ref text_content) => {
-                  let items: Vec<String> = text_content.split(' ')
-                    .map(|item| item.to_owned())
-                    .map(|item| item.parse().unwrap())
-                    .collect();
+                  let items = text_content
+                    .split_whitespace()
+                    .map(|item| {
+                      item
+                        .parse :: <String>()
+                        .map_err(|_| format!("Unable to parse {:?} in {}", item, "Parent"))
+                    })
+                    .collect :: <Result<Vec<String> , String>>() ? ;
 
                   return Ok(Parent{items});
                 }
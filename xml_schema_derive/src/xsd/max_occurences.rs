@@ -2,13 +2,17 @@ use std::io::Read;
 use xml::reader::XmlEvent;
 use yaserde::YaDeserialize;
 
-#[derive(Clone, Debug, Default, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub enum MaxOccurences {
-  #[default]
   Unbounded,
-  Number {
-    value: u32,
-  },
+  Number { value: u32 },
+}
+
+/// An absent `maxOccurs` is 1.
+impl Default for MaxOccurences {
+  fn default() -> Self {
+    MaxOccurences::Number { value: 1 }
+  }
 }
 
 impl YaDeserialize for MaxOccurences {
@@ -1,15 +1,26 @@
 mod annotation;
+mod appinfo;
+mod assert;
 mod attribute;
 mod attribute_group;
+mod choice;
 mod complex_content;
 mod complex_type;
+mod documentation;
 mod element;
+mod enumeration;
 mod extension;
+mod facet;
+mod field_hook;
 mod group;
+mod identity_constraint;
 mod import;
+mod include;
 mod list;
 mod max_occurences;
+mod notation;
 mod qualification;
+mod redefine;
 mod restriction;
 mod rust_types_mapping;
 mod schema;
@@ -23,10 +34,15 @@ use heck::ToSnakeCase;
 use proc_macro2::{Ident, TokenStream};
 use std::collections::BTreeMap;
 use std::fs;
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
 use syn::Visibility;
 use xsd_context::XsdContext;
 use yaserde::de::from_str;
 
+pub use field_hook::OuterTypeAttributes;
+pub use xsd_context::Inheritance;
+
 trait Implementation {
   fn implement(
     &self,
@@ -57,30 +73,63 @@ pub struct Xsd {
 }
 
 impl Xsd {
-  pub fn new(
+  pub fn new_from_file(
     name: String,
     vis: Visibility,
-    content: &str,
+    source: &str,
     module_namespace_mappings: &BTreeMap<String, String>,
   ) -> Result<Self, String> {
-    let context = XsdContext::new(content)?;
-    let context = context.with_module_namespace_mappings(module_namespace_mappings);
-    let schema: schema::Schema = from_str(content)?;
+    let content = Xsd::load_content(source)?;
+    let schema = Xsd::load_schema(source, &content)?;
+    let mut includes = vec![];
+    Xsd::load_includes(source, &schema, &mut includes)?;
+    let imports = Xsd::load_imports(source, &schema)?;
 
-    Ok(Xsd {
+    Xsd::new(
       name,
       vis,
-      context,
+      &content,
       schema,
-    })
+      &includes,
+      &imports,
+      module_namespace_mappings,
+    )
   }
 
-  pub fn new_from_file(
+  fn new(
     name: String,
     vis: Visibility,
-    source: &str,
+    content: &str,
+    mut schema: schema::Schema,
+    includes: &[(String, schema::Schema)],
+    imports: &[(String, schema::Schema)],
     module_namespace_mappings: &BTreeMap<String, String>,
   ) -> Result<Self, String> {
+    for (_, included_schema) in includes {
+      include::Include::merge_into(&mut schema, included_schema);
+    }
+
+    let mut context = XsdContext::new(content)?
+      .with_module_namespace_mappings(module_namespace_mappings)
+      .with_schema(&schema);
+
+    for (_, included_schema) in includes {
+      context = context.with_included_schema(included_schema);
+    }
+
+    for (imported_content, imported_schema) in imports {
+      context = context.with_imported_schema(imported_content, imported_schema)?;
+    }
+
+    Ok(Xsd {
+      name,
+      vis,
+      context,
+      schema,
+    })
+  }
+
+  fn load_content(source: &str) -> Result<String, String> {
     let content = if source.starts_with("http://") || source.starts_with("https://") {
       log::info!("Load HTTP schema {}", source);
       reqwest::blocking::get(source)
@@ -101,7 +150,192 @@ impl Xsd {
       content
     };
 
-    Xsd::new(name, vis, &content, module_namespace_mappings)
+    Ok(content)
+  }
+
+  /// Parse the schema, merging the schemas it redefines.
+  fn load_schema(source: &str, content: &str) -> Result<schema::Schema, String> {
+    let mut schema: schema::Schema = from_str(content)?;
+
+    for redefine in std::mem::take(&mut schema.redefines) {
+      let location = Xsd::resolve_location(source, &redefine.schema_location);
+      log::info!("Redefine schema {}", location);
+
+      let redefined_content = Xsd::load_content(&location)?;
+      let redefined_schema = Xsd::load_schema(&location, &redefined_content)?;
+
+      redefine.merge_into(&mut schema, redefined_schema)?;
+    }
+
+    Ok(schema)
+  }
+
+  /// Location and parsed schema of the included schemas, the schemas they include themselves
+  /// following them. A schema included several times is only loaded once.
+  fn load_includes(
+    source: &str,
+    schema: &schema::Schema,
+    includes: &mut Vec<(String, schema::Schema)>,
+  ) -> Result<(), String> {
+    for include in &schema.includes {
+      let location = Xsd::resolve_location(source, &include.schema_location);
+      if location == source || includes.iter().any(|(included, _)| *included == location) {
+        continue;
+      }
+
+      log::info!("Include schema {}", location);
+
+      let content = Xsd::load_content(&location)?;
+      let included_schema = Xsd::load_schema(&location, &content)?;
+      includes.push((location.clone(), included_schema.clone()));
+
+      Xsd::load_includes(&location, &included_schema, includes)?;
+    }
+
+    Ok(())
+  }
+
+  /// Content and parsed schema of the imports declaring their location.
+  fn load_imports(
+    source: &str,
+    schema: &schema::Schema,
+  ) -> Result<Vec<(String, schema::Schema)>, String> {
+    schema
+      .imports
+      .iter()
+      .filter_map(|import| import.schema_location.as_ref())
+      .map(|schema_location| {
+        let location = Xsd::resolve_location(source, schema_location);
+        log::info!("Import schema {}", location);
+
+        let content = Xsd::load_content(&location)?;
+        let schema = Xsd::load_schema(&location, &content)?;
+        Ok((content, schema))
+      })
+      .collect()
+  }
+
+  fn resolve_location(source: &str, location: &str) -> String {
+    if location.starts_with("http://") || location.starts_with("https://") {
+      return location.to_string();
+    }
+
+    if source.starts_with("http://") || source.starts_with("https://") {
+      let base = source
+        .rsplit_once('/')
+        .map(|(base, _)| base)
+        .unwrap_or(source);
+      return format!("{base}/{location}");
+    }
+
+    Path::new(source)
+      .parent()
+      .map(|parent| parent.join(location))
+      .unwrap_or_else(|| PathBuf::from(location))
+      .to_string_lossy()
+      .to_string()
+  }
+
+  pub fn with_inheritance(mut self, inheritance: Inheritance) -> Self {
+    self.context = self.context.with_inheritance(inheritance);
+    self
+  }
+
+  pub fn with_list_wrappers(mut self, list_wrappers: bool) -> Self {
+    self.context = self.context.with_list_wrappers(list_wrappers);
+    self
+  }
+
+  pub fn with_accessors(mut self, accessors: bool) -> Self {
+    self.context = self.context.with_accessors(accessors);
+    self
+  }
+
+  pub fn with_non_exhaustive(mut self, non_exhaustive: bool) -> Self {
+    self.context = self.context.with_non_exhaustive(non_exhaustive);
+    self
+  }
+
+  pub fn with_redact_binary(mut self, redact_binary: bool) -> Self {
+    self.context = self.context.with_redact_binary(redact_binary);
+    self
+  }
+
+  pub fn with_validating_constructors(mut self, validating_constructors: bool) -> Self {
+    self.context = self
+      .context
+      .with_validating_constructors(validating_constructors);
+    self
+  }
+
+  pub fn with_validation_errors(mut self, validation_errors: bool) -> Self {
+    self.context = self.context.with_validation_errors(validation_errors);
+    self
+  }
+
+  pub fn with_strict_order(mut self, strict_order: bool) -> Self {
+    self.context = self.context.with_strict_order(strict_order);
+    self
+  }
+
+  pub fn with_capture_unknown(mut self, capture_unknown: bool) -> Self {
+    self.context = self.context.with_capture_unknown(capture_unknown);
+    self
+  }
+
+  pub fn with_partial_eq_ignore(mut self, field_names: &[String]) -> Self {
+    self.context = self.context.with_partial_eq_ignore(field_names);
+    self
+  }
+
+  pub fn with_hide_anonymous_types(mut self, hide_anonymous_types: bool) -> Self {
+    self.context = self.context.with_hide_anonymous_types(hide_anonymous_types);
+    self
+  }
+
+  pub fn with_root_elements(mut self, root_elements: bool) -> Self {
+    self.context = self.context.with_root_elements(root_elements);
+    self
+  }
+
+  pub fn with_prune_unused(mut self, prune_unused: bool) -> Self {
+    self.context = self.context.with_prune_unused(prune_unused);
+    self
+  }
+
+  pub fn with_iterators(mut self, iterators: bool) -> Self {
+    self.context = self.context.with_iterators(iterators);
+    self
+  }
+
+  pub fn with_streams(mut self, streams: bool) -> Self {
+    self.context = self.context.with_streams(streams);
+    self
+  }
+
+  pub fn with_visitors(mut self, visitors: bool) -> Self {
+    self.context = self.context.with_visitors(visitors);
+    self
+  }
+
+  pub fn with_field_hook(mut self, field_hook: Rc<dyn field_hook::FieldHook>) -> Self {
+    self.context = self.context.with_field_hook(field_hook);
+    self
+  }
+
+  pub fn with_extension_traits(mut self, extension_traits: bool) -> Self {
+    self.context = self.context.with_extension_traits(extension_traits);
+    self
+  }
+
+  pub fn with_types_path(mut self, types_path: &Option<String>) -> Self {
+    self.context = self.context.with_types_path(types_path);
+    self
+  }
+
+  pub fn with_namespace_prefixes(mut self, namespace_prefixes: &BTreeMap<String, String>) -> Self {
+    self.context = self.context.with_namespace_prefixes(namespace_prefixes);
+    self
   }
 
   pub fn implement(&self, target_prefix: &Option<String>) -> TokenStream {
@@ -113,6 +347,7 @@ impl Xsd {
     let vis = &self.vis;
 
     quote! {
+        #[allow(unknown_lints, non_local_definitions)]
         mod #mod_name {
             #schema
         }
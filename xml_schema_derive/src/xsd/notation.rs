@@ -0,0 +1,126 @@
+use crate::xsd::XsdContext;
+use heck::ToUpperCamelCase;
+use proc_macro2::{Ident, Span, TokenStream};
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
+pub struct Notation {
+  #[yaserde(attribute)]
+  pub name: String,
+  #[yaserde(attribute)]
+  pub public: Option<String>,
+  #[yaserde(attribute)]
+  pub system: Option<String>,
+}
+
+impl Notation {
+  /// Enumeration of the declared notations, used for `NOTATION` typed values.
+  pub fn get_enumeration_implementation(
+    context: &XsdContext,
+    notations: &[Notation],
+  ) -> TokenStream {
+    if notations.is_empty() {
+      return quote!();
+    }
+
+    let variants = notations.iter().enumerate().map(|(index, notation)| {
+      let name = &notation.name;
+      let variant_name = name.replace('.', "_").to_upper_camel_case();
+      let variant_name = if variant_name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+        variant_name
+      } else {
+        format!("Value{variant_name}")
+      };
+      let variant_name = Ident::new(&variant_name, Span::call_site());
+
+      let default = (index == 0)
+        .then_some(quote!(#[default]))
+        .unwrap_or_default();
+
+      let docs = notation
+        .public
+        .iter()
+        .map(|public| format!("Public identifier: `{public}`"))
+        .chain(
+          notation
+            .system
+            .iter()
+            .map(|system| format!("System identifier: `{system}`")),
+        );
+
+      quote!(
+        #(#[doc = #docs])*
+        #default
+        #[yaserde(rename = #name)]
+        #variant_name,
+      )
+    });
+
+    let non_exhaustive = context.get_non_exhaustive_attribute();
+
+    quote!(
+      #[derive(Clone, Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize)]
+      #non_exhaustive
+      pub enum Notation {
+        #(#variants)*
+      }
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::str::FromStr;
+
+  #[test]
+  fn notations_enumeration() {
+    let notations = vec![
+      Notation {
+        name: "gif".to_string(),
+        public: Some("image/gif".to_string()),
+        system: None,
+      },
+      Notation {
+        name: "jpeg".to_string(),
+        public: None,
+        system: Some("viewer.exe".to_string()),
+      },
+    ];
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = Notation::get_enumeration_implementation(&context, &notations);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[derive(Clone, Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize)]
+        pub enum Notation {
+          #[doc = "Public identifier: `image/gif`"]
+          #[default]
+          #[yaserde(rename = "gif")]
+          Gif,
+          #[doc = "System identifier: `viewer.exe`"]
+          #[yaserde(rename = "jpeg")]
+          Jpeg,
+        }
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn no_notations() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    assert!(Notation::get_enumeration_implementation(&context, &[])
+      .to_string()
+      .is_empty());
+  }
+}
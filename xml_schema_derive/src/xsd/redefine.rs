@@ -0,0 +1,114 @@
+use crate::xsd::{complex_type::ComplexType, schema::Schema, simple_type::SimpleType};
+
+#[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
+#[yaserde(
+  rename = "redefine"
+  prefix = "xs",
+  namespace = "xs: http://www.w3.org/2001/XMLSchema"
+)]
+pub struct Redefine {
+  #[yaserde(rename = "schemaLocation", attribute)]
+  pub schema_location: String,
+  #[yaserde(rename = "simpleType")]
+  pub simple_type: Vec<SimpleType>,
+  #[yaserde(rename = "complexType")]
+  pub complex_type: Vec<ComplexType>,
+}
+
+impl Redefine {
+  /// Merge the redefined schema into `schema`, overriding the redefined types.
+  pub fn merge_into(&self, schema: &mut Schema, mut redefined: Schema) -> Result<(), String> {
+    for simple_type in &self.simple_type {
+      let original = redefined
+        .simple_type
+        .iter_mut()
+        .find(|original| original.name == simple_type.name)
+        .ok_or_else(|| self.missing_definition(&simple_type.name))?;
+
+      *original = simple_type.clone();
+    }
+
+    for complex_type in &self.complex_type {
+      let original = redefined
+        .complex_type
+        .iter_mut()
+        .find(|original| original.name == complex_type.name)
+        .ok_or_else(|| self.missing_definition(&complex_type.name))?;
+
+      *original = Redefine::redefine_complex_type(original, complex_type);
+    }
+
+    schema.elements.extend(redefined.elements);
+    schema.simple_type.extend(redefined.simple_type);
+    schema.complex_type.extend(redefined.complex_type);
+    schema.attributes.extend(redefined.attributes);
+    schema.attribute_group.extend(redefined.attribute_group);
+    schema.group.extend(redefined.group);
+
+    Ok(())
+  }
+
+  /// A redefinition extending the original type appends its content to the original one.
+  fn redefine_complex_type(original: &ComplexType, complex_type: &ComplexType) -> ComplexType {
+    let self_extension = complex_type
+      .complex_content
+      .as_ref()
+      .and_then(|complex_content| complex_content.extension.as_ref())
+      .filter(|extension| extension.base.split(':').next_back() == Some(&original.name));
+
+    let Some(extension) = self_extension else {
+      return complex_type.clone();
+    };
+
+    let mut redefined = original.clone();
+
+    let mut sequence = redefined.sequence.take().unwrap_or_default();
+    for extension_sequence in &extension.sequences {
+      sequence
+        .elements
+        .extend(extension_sequence.elements.iter().cloned());
+    }
+    redefined.sequence = Some(sequence);
+
+    redefined
+      .attributes
+      .extend(extension.attributes.iter().cloned());
+
+    if complex_type.annotation.is_some() {
+      redefined.annotation = complex_type.annotation.clone();
+    }
+
+    redefined
+  }
+
+  fn missing_definition(&self, name: &str) -> String {
+    format!(
+      "Unable to redefine {:?}, it is not defined in {:?}",
+      name, self.schema_location
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn redefine_missing_type() {
+    let redefine = Redefine {
+      schema_location: "base.xsd".to_string(),
+      complex_type: vec![ComplexType {
+        name: "Unknown".to_string(),
+        ..Default::default()
+      }],
+      ..Default::default()
+    };
+
+    let result = redefine.merge_into(&mut Schema::default(), Schema::default());
+
+    assert_eq!(
+      result,
+      Err(r#"Unable to redefine "Unknown", it is not defined in "base.xsd""#.to_string())
+    );
+  }
+}
@@ -1,14 +1,484 @@
-use crate::xsd::{rust_types_mapping::RustTypesMapping, XsdContext};
-use proc_macro2::TokenStream;
+use crate::xsd::{
+  attribute::Attribute,
+  enumeration::Enumeration,
+  facet::Facet,
+  rust_types_mapping::{BinaryEncoding, RustTypesMapping},
+  Implementation, XsdContext,
+};
+use heck::{ToSnakeCase, ToUpperCamelCase};
+use proc_macro2::{Ident, Span, TokenStream};
+use syn::{Expr, FieldsNamed, LitStr, Token, Type};
+
+/// Number of values above which an enumeration is parsed with a lookup table.
+const LOOKUP_THRESHOLD: usize = 32;
 
 #[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
 #[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
 pub struct Restriction {
   #[yaserde(rename = "base", attribute)]
   pub base: Option<String>,
+  #[yaserde(rename = "enumeration")]
+  pub enumerations: Vec<Enumeration>,
+  #[yaserde(rename = "length")]
+  pub length: Option<Facet>,
+  #[yaserde(rename = "minLength")]
+  pub min_length: Option<Facet>,
+  #[yaserde(rename = "maxLength")]
+  pub max_length: Option<Facet>,
+  #[yaserde(rename = "pattern")]
+  pub patterns: Vec<Facet>,
+  #[yaserde(rename = "whiteSpace")]
+  pub white_space: Option<Facet>,
+  #[yaserde(rename = "attribute")]
+  pub attributes: Vec<Attribute>,
+}
+
+impl Implementation for Restriction {
+  fn implement_childs(
+    &self,
+    _namespace_definition: &TokenStream,
+    _prefix: &Option<String>,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> TokenStream {
+    if self.is_enumeration() {
+      self.get_enumeration_implementation(context, struct_name)
+    } else {
+      self.get_newtype_implementation(context, struct_name)
+    }
+  }
 }
 
 impl Restriction {
+  fn get_enumeration_implementation(
+    &self,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> TokenStream {
+    let non_exhaustive = context.get_non_exhaustive_attribute();
+    let has_lookup = self.enumerations.len() > LOOKUP_THRESHOLD;
+
+    // the enumeration values are the only ones accepted, a pattern can not widen them
+    if !self.patterns.is_empty() {
+      log::warn!(
+        "Pattern facets of {} are not checked against its enumeration values, they are only documented",
+        struct_name
+      );
+    }
+    let docs = self.get_patterns_documentation();
+
+    let enumeration_variants = self.get_enumeration_variants();
+
+    let variants = enumeration_variants
+      .iter()
+      .enumerate()
+      .map(|(index, (value, variant_name))| {
+        let default = (index == 0)
+          .then_some(quote!(#[default]))
+          .unwrap_or_default();
+
+        let rename = (!has_lookup)
+          .then_some(quote!(#[yaserde(rename = #value)]))
+          .unwrap_or_default();
+
+        quote!(
+          #default
+          #rename
+          #variant_name,
+        )
+      });
+
+    if !has_lookup {
+      let display_variants = enumeration_variants
+        .iter()
+        .map(|(value, variant_name)| quote!(#struct_name::#variant_name => #value,));
+
+      return quote!(
+        #docs
+        #[derive(Clone, Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize)]
+        #non_exhaustive
+        pub enum #struct_name {
+          #(#variants)*
+        }
+
+        impl std::fmt::Display for #struct_name {
+          fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str(match self {
+              #(#display_variants)*
+            })
+          }
+        }
+      );
+    }
+
+    let lookup = self.get_lookup_implementation(struct_name);
+
+    quote!(
+      #docs
+      #[derive(Clone, Debug, Default, PartialEq)]
+      #non_exhaustive
+      pub enum #struct_name {
+        #(#variants)*
+      }
+
+      #lookup
+    )
+  }
+
+  fn get_patterns_documentation(&self) -> TokenStream {
+    self
+      .patterns
+      .iter()
+      .map(|pattern| {
+        let documentation = format!("Pattern: `{}`", pattern.value);
+        quote!(#[doc = #documentation])
+      })
+      .collect()
+  }
+
+  /// Parsing of a large enumeration by a binary search in its values sorted at generation time,
+  /// instead of the linear matching of the derived deserialization.
+  fn get_lookup_implementation(&self, struct_name: &Ident) -> TokenStream {
+    let name = struct_name.to_string();
+
+    let mut values = self.get_enumeration_variants();
+    values.sort_unstable_by_key(|(value, _)| *value);
+
+    let lookup = values
+      .iter()
+      .map(|(value, variant_name)| quote!((#value, #struct_name::#variant_name),));
+
+    let display_variants = values
+      .iter()
+      .map(|(value, variant_name)| quote!(#struct_name::#variant_name => #value,));
+
+    let text_implementation = Restriction::get_text_implementation(
+      struct_name,
+      quote!(text_content.parse()),
+      quote!(self.as_str().to_string()),
+    );
+
+    quote!(
+      impl #struct_name {
+        /// Variants by value, sorted for a binary search.
+        const LOOKUP: &'static [(&'static str, #struct_name)] = &[
+          #(#lookup)*
+        ];
+
+        pub fn as_str(&self) -> &'static str {
+          match self {
+            #(#display_variants)*
+          }
+        }
+      }
+
+      impl std::str::FromStr for #struct_name {
+        type Err = String;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+          #struct_name::LOOKUP
+            .binary_search_by(|(variant_value, _)| (*variant_value).cmp(value))
+            .map(|index| #struct_name::LOOKUP[index].1.clone())
+            .map_err(|_| format!("{:?} is not a value of {}", value, #name))
+        }
+      }
+
+      impl std::fmt::Display for #struct_name {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+          write!(f, "{}", self.as_str())
+        }
+      }
+
+      #text_implementation
+    )
+  }
+
+  pub fn get_variant_name(value: &str) -> Ident {
+    let variant_name = value.to_upper_camel_case();
+    let variant_name = if variant_name.starts_with(|c: char| c.is_ascii_alphabetic()) {
+      variant_name
+    } else {
+      format!("Value{variant_name}")
+    };
+    Ident::new(&variant_name, Span::call_site())
+  }
+
+  /// Distinct values of the enumeration with their variant, in declaration order.
+  /// Values normalized to the same name (`a-b` and `a_b`, `A` and `a`) get a numeric suffix
+  /// after the first one: `AB`, `AB2`.
+  fn get_enumeration_variants(&self) -> Vec<(&str, Ident)> {
+    let mut variants: Vec<(&str, Ident)> = vec![];
+
+    for enumeration in &self.enumerations {
+      let value = enumeration.value.as_str();
+      if variants.iter().any(|(other, _)| *other == value) {
+        continue;
+      }
+
+      let base_name = Restriction::get_variant_name(value);
+      let mut variant_name = base_name.clone();
+      let mut suffix = 1;
+      while variants.iter().any(|(_, other)| *other == variant_name) {
+        suffix += 1;
+        variant_name = Ident::new(&format!("{base_name}{suffix}"), Span::call_site());
+      }
+
+      variants.push((value, variant_name));
+    }
+
+    variants
+  }
+
+  /// Variant of the generated enumeration matching a schema value.
+  pub fn get_enumeration_variant(&self, value: &str) -> Option<Ident> {
+    self
+      .get_enumeration_variants()
+      .into_iter()
+      .find(|(variant_value, _)| *variant_value == value)
+      .map(|(_, variant_name)| variant_name)
+  }
+
+  /// Newtype validating the length facets, patterns are only documented.
+  /// The value is private so that it always goes through `new`.
+  fn get_newtype_implementation(&self, context: &XsdContext, struct_name: &Ident) -> TokenStream {
+    let content_type = self.get_type_implementation(context, &None);
+    let non_exhaustive = context.get_non_exhaustive_attribute();
+
+    let encoding = self
+      .base
+      .as_ref()
+      .and_then(|base| RustTypesMapping::get_binary_encoding(context, base));
+    let facets_check = self.get_facets_check(encoding);
+
+    if !self.patterns.is_empty() {
+      log::warn!(
+        "Pattern facets of {} are not validated, they are only documented",
+        struct_name
+      );
+    }
+
+    let docs = self.get_patterns_documentation();
+
+    let name = struct_name.to_string();
+    let white_space_normalization = self.get_white_space_normalization(&content_type);
+
+    let text_implementation = Restriction::get_text_implementation(
+      struct_name,
+      quote!({
+        let text_content = #white_space_normalization;
+        let value = text_content
+          .parse()
+          .map_err(|_| format!("Unable to parse {:?} for {}", text_content, #name))?;
+
+        #struct_name::new(value)
+      }),
+      quote!(self.0.to_string()),
+    );
+
+    // read-only access to string content, the value being private construction goes through `new`
+    let string_implementation = (content_type.to_string() == "String").then(|| {
+      quote!(
+        impl AsRef<str> for #struct_name {
+          fn as_ref(&self) -> &str {
+            &self.0
+          }
+        }
+
+        impl std::ops::Deref for #struct_name {
+          type Target = str;
+
+          fn deref(&self) -> &str {
+            &self.0
+          }
+        }
+      )
+    });
+
+    // without a valid default value, the holders of the type use a placeholder while deserializing
+    let (derive_default, placeholder) = if self.has_valid_default(context) {
+      (quote!(Default,), quote!())
+    } else {
+      let content_default = match &self.base {
+        Some(base)
+          if !RustTypesMapping::is_xml_schema_type(context, base) && !context.has_default(base) =>
+        {
+          quote!(<#content_type>::placeholder())
+        }
+        _ => quote!(Default::default()),
+      };
+
+      (
+        quote!(),
+        quote!(
+          /// Value standing for a missing element while deserializing, it does not satisfy the facets.
+          #[doc(hidden)]
+          pub fn placeholder() -> Self {
+            #struct_name(#content_default)
+          }
+        ),
+      )
+    };
+
+    quote!(
+      #docs
+      #[derive(Clone, Debug, #derive_default PartialEq)]
+      #non_exhaustive
+      pub struct #struct_name(#content_type);
+
+      impl #struct_name {
+        pub fn new(value: #content_type) -> Result<Self, String> {
+          #facets_check
+
+          Ok(#struct_name(value))
+        }
+
+        pub fn value(&self) -> &#content_type {
+          &self.0
+        }
+
+        pub fn into_inner(self) -> #content_type {
+          self.0
+        }
+
+        #placeholder
+      }
+
+      #string_implementation
+
+      #text_implementation
+    )
+  }
+
+  /// (De)serialization of a type held as text content, or as an attribute value.
+  /// `from_text` builds `Self` from `text_content`, `to_text` the `String` to write.
+  pub fn get_text_implementation(
+    struct_name: &Ident,
+    from_text: TokenStream,
+    to_text: TokenStream,
+  ) -> TokenStream {
+    let name = struct_name.to_string();
+
+    quote!(
+      impl yaserde::YaDeserialize for #struct_name {
+        fn deserialize<R: std::io::Read>(reader: &mut yaserde::de::Deserializer<R>) -> Result<Self, String> {
+          if let xml::reader::XmlEvent::StartElement{..} = reader.peek()? {
+            reader.next_event()?;
+          }
+
+          let text_content = if let xml::reader::XmlEvent::Characters(ref text_content) = reader.peek()? {
+            text_content.to_string()
+          } else {
+            String::new()
+          };
+
+          if !text_content.is_empty() {
+            reader.next_event()?;
+          }
+
+          #from_text
+        }
+      }
+
+      impl yaserde::YaSerialize for #struct_name {
+        fn serialize<W: std::io::Write>(&self, writer: &mut yaserde::ser::Serializer<W>) -> Result<(), String> {
+          let skip_start_end = writer.skip_start_end();
+          let name = writer.get_start_event_name().unwrap_or_else(|| #name.to_string());
+
+          if !skip_start_end {
+            writer.write(xml::writer::XmlEvent::start_element(name.as_str())).map_err(|e| e.to_string())?;
+          }
+
+          let content = #to_text;
+          writer.write(xml::writer::XmlEvent::characters(&content)).map_err(|e| e.to_string())?;
+
+          if !skip_start_end {
+            writer.write(xml::writer::XmlEvent::end_element()).map_err(|e| e.to_string())?;
+          }
+
+          Ok(())
+        }
+
+        fn serialize_attributes(&self, source_attributes: Vec<xml::attribute::OwnedAttribute>, source_namespace: xml::namespace::Namespace) -> Result<(Vec<xml::attribute::OwnedAttribute>, xml::namespace::Namespace), String> {
+          Ok((source_attributes, source_namespace))
+        }
+      }
+    )
+  }
+
+  /// Check of the length facets on `value`, returning an error when not satisfied.
+  /// The length of binary content is its number of decoded octets.
+  pub fn get_facets_check(&self, encoding: Option<BinaryEncoding>) -> TokenStream {
+    let unit = if encoding.is_some() { " bytes" } else { "" };
+
+    let length_check = |facet: &Option<Facet>, operator: TokenStream, message: &str| {
+      facet
+        .as_ref()
+        .map(|facet| {
+          let limit = facet
+            .value
+            .parse::<usize>()
+            .expect("[Restriction] length facet must be a positive integer");
+
+          let message = format!("{message}{unit}");
+
+          quote!(
+            if length #operator #limit {
+              return Err(format!(#message, value, #limit));
+            }
+          )
+        })
+        .unwrap_or_default()
+    };
+
+    let checks = [
+      length_check(&self.length, quote!(!=), "{:?} must have a length of {}"),
+      length_check(
+        &self.min_length,
+        quote!(<),
+        "{:?} must have a length of at least {}",
+      ),
+      length_check(
+        &self.max_length,
+        quote!(>),
+        "{:?} must have a length of at most {}",
+      ),
+    ];
+
+    if checks.iter().all(|check| check.is_empty()) {
+      return quote!();
+    }
+
+    let length = match encoding {
+      Some(encoding) => encoding.get_byte_count_implementation(),
+      None => quote!(value.to_string().chars().count()),
+    };
+
+    quote!(
+      let length = #length;
+      #(#checks)*
+    )
+  }
+
+  /// Normalization of `text_content` following the `whiteSpace` facet,
+  /// strings are preserved by default while other types are collapsed.
+  fn get_white_space_normalization(&self, content_type: &TokenStream) -> TokenStream {
+    let white_space = match &self.white_space {
+      Some(white_space) => white_space.value.as_str(),
+      None if content_type.to_string() == "String" => "preserve",
+      None => "collapse",
+    };
+
+    match white_space {
+      "preserve" => quote!(text_content),
+      "replace" => quote!(text_content.replace(['\t', '\n', '\r'], " ")),
+      "collapse" => quote!(text_content
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")),
+      white_space => panic!("[Restriction] unknown whiteSpace value {:?}", white_space),
+    }
+  }
+
   pub fn get_type_implementation(
     &self,
     context: &XsdContext,
@@ -20,4 +490,330 @@ impl Restriction {
       panic!("Missing base for restriction");
     }
   }
+
+  /// Whether the default value of the base type satisfies the length facets.
+  pub fn has_valid_default(&self, context: &XsdContext) -> bool {
+    let base = match &self.base {
+      Some(base) if RustTypesMapping::is_xml_schema_type(context, base) => base,
+      Some(base) => return context.has_default(base) && !self.has_length_facets(),
+      None => return true,
+    };
+
+    let default_length = match RustTypesMapping::get(context, base).to_string().as_str() {
+      "String" => 0,
+      "bool" => "false".len(),
+      "i8" | "u8" | "i16" | "u16" | "i32" | "u32" | "i64" | "u64" | "f32" | "f64" => "0".len(),
+      _ => return !self.has_length_facets(),
+    };
+
+    let limit = |facet: &Option<Facet>| {
+      facet
+        .as_ref()
+        .and_then(|facet| facet.value.parse::<usize>().ok())
+    };
+
+    limit(&self.length).map_or(true, |length| default_length == length)
+      && limit(&self.min_length).map_or(true, |min_length| default_length >= min_length)
+      && limit(&self.max_length).map_or(true, |max_length| default_length <= max_length)
+  }
+
+  fn has_length_facets(&self) -> bool {
+    self.length.is_some() || self.min_length.is_some() || self.max_length.is_some()
+  }
+
+  /// Placeholder functions named by the `#[yaserde(default)]` of the fields,
+  /// for the types without a valid default value.
+  pub fn get_placeholders(fields: &FieldsNamed) -> Vec<(Ident, Ident, Type)> {
+    Restriction::get_default_functions(fields, "_placeholder")
+  }
+
+  /// Functions named by the `#[yaserde(default)]` of the fields, ending with `suffix`.
+  pub fn get_default_functions(fields: &FieldsNamed, suffix: &str) -> Vec<(Ident, Ident, Type)> {
+    fields
+      .named
+      .iter()
+      .filter_map(|field| {
+        let mut placeholder = None;
+
+        for attribute in field
+          .attrs
+          .iter()
+          .filter(|attribute| attribute.path().is_ident("yaserde"))
+        {
+          let _ = attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("default") {
+              let function: LitStr = meta.value()?.parse()?;
+              placeholder = Some(function.value());
+            } else if meta.input.peek(Token![=]) {
+              meta.value()?.parse::<Expr>()?;
+            }
+            Ok(())
+          });
+        }
+
+        let placeholder = placeholder.filter(|placeholder| placeholder.ends_with(suffix))?;
+
+        Some((
+          field.ident.clone()?,
+          Ident::new(&placeholder, Span::call_site()),
+          field.ty.clone(),
+        ))
+      })
+      .collect()
+  }
+
+  /// Name of the placeholder function of a field holding a type without a valid default value.
+  pub fn get_placeholder_name(holder: &str, field_name: &str) -> String {
+    format!("__{}_{}_placeholder", holder.to_snake_case(), field_name)
+  }
+
+  pub fn get_placeholders_implementation(placeholders: &[(Ident, Ident, Type)]) -> TokenStream {
+    placeholders
+      .iter()
+      .map(|(_field_name, function, rust_type)| {
+        quote!(
+          fn #function() -> #rust_type {
+            <#rust_type>::placeholder()
+          }
+        )
+      })
+      .collect()
+  }
+
+  pub fn is_enumeration(&self) -> bool {
+    !self.enumerations.is_empty()
+  }
+
+  pub fn has_facets(&self) -> bool {
+    self.length.is_some()
+      || self.min_length.is_some()
+      || self.max_length.is_some()
+      || !self.patterns.is_empty()
+      || self.white_space.is_some()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use std::str::FromStr;
+
+  #[test]
+  fn enumeration_restriction() {
+    let restriction = Restriction {
+      base: Some("xs:string".to_string()),
+      enumerations: vec![
+        Enumeration {
+          value: "light-blue".to_string(),
+        },
+        Enumeration {
+          value: "2d".to_string(),
+        },
+      ],
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let struct_name = Ident::new("Color", Span::call_site());
+
+    let implementation =
+      restriction.implement_childs(&TokenStream::new(), &None, &context, &struct_name);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[derive(Clone, Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize)]
+        pub enum Color {
+          #[default]
+          #[yaserde(rename = "light-blue")]
+          LightBlue,
+          #[yaserde(rename = "2d")]
+          Value2d,
+        }
+
+        impl std::fmt::Display for Color {
+          fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str(match self {
+              Color::LightBlue => "light-blue",
+              Color::Value2d => "2d",
+            })
+          }
+        }
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn enumeration_with_pattern() {
+    let restriction = Restriction {
+      base: Some("xs:string".to_string()),
+      enumerations: vec![
+        Enumeration {
+          value: "A1".to_string(),
+        },
+        Enumeration {
+          value: "B2".to_string(),
+        },
+      ],
+      patterns: vec![Facet {
+        value: "[A-Z][0-9]".to_string(),
+      }],
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let struct_name = Ident::new("Grade", Span::call_site());
+
+    let implementation =
+      restriction.implement_childs(&TokenStream::new(), &None, &context, &struct_name);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[doc = "Pattern: `[A-Z][0-9]`"]
+        #[derive(Clone, Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize)]
+        pub enum Grade {
+          #[default]
+          #[yaserde(rename = "A1")]
+          A1,
+          #[yaserde(rename = "B2")]
+          B2,
+        }
+
+        impl std::fmt::Display for Grade {
+          fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str(match self {
+              Grade::A1 => "A1",
+              Grade::B2 => "B2",
+            })
+          }
+        }
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn enumeration_variant_collisions() {
+    let restriction = Restriction {
+      base: Some("xs:string".to_string()),
+      enumerations: ["a-b", "a_b", "A", "a", "a-b", "A2"]
+        .iter()
+        .map(|value| Enumeration {
+          value: value.to_string(),
+        })
+        .collect(),
+      ..Default::default()
+    };
+
+    let variants: Vec<(&str, String)> = restriction
+      .get_enumeration_variants()
+      .into_iter()
+      .map(|(value, variant_name)| (value, variant_name.to_string()))
+      .collect();
+
+    assert_eq!(
+      variants,
+      vec![
+        ("a-b", "AB".to_string()),
+        ("a_b", "AB2".to_string()),
+        ("A", "A".to_string()),
+        ("a", "A2".to_string()),
+        ("A2", "A22".to_string()),
+      ]
+    );
+    assert_eq!(
+      restriction
+        .get_enumeration_variant("a_b")
+        .map(|ident| ident.to_string()),
+      Some("AB2".to_string())
+    );
+  }
+
+  #[test]
+  fn non_exhaustive_enumeration() {
+    let restriction = Restriction {
+      base: Some("xs:string".to_string()),
+      enumerations: vec![Enumeration {
+        value: "red".to_string(),
+      }],
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap()
+        .with_non_exhaustive(true);
+
+    let struct_name = Ident::new("Color", Span::call_site());
+
+    let implementation =
+      restriction.implement_childs(&TokenStream::new(), &None, &context, &struct_name);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[derive(Clone, Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize)]
+        #[non_exhaustive]
+        pub enum Color {
+          #[default]
+          #[yaserde(rename = "red")]
+          Red,
+        }
+
+        impl std::fmt::Display for Color {
+          fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str(match self {
+              Color::Red => "red",
+            })
+          }
+        }
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[test]
+  fn valid_default() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let max_length = Restriction {
+      base: Some("xs:string".to_string()),
+      max_length: Some(Facet {
+        value: "4".to_string(),
+      }),
+      ..Default::default()
+    };
+    assert!(max_length.has_valid_default(&context));
+
+    let min_length = Restriction {
+      base: Some("xs:string".to_string()),
+      min_length: Some(Facet {
+        value: "2".to_string(),
+      }),
+      ..Default::default()
+    };
+    assert!(!min_length.has_valid_default(&context));
+
+    let length = Restriction {
+      base: Some("xs:int".to_string()),
+      length: Some(Facet {
+        value: "1".to_string(),
+      }),
+      ..Default::default()
+    };
+    assert!(length.has_valid_default(&context));
+  }
 }
@@ -1,26 +1,52 @@
 use crate::xsd::XsdContext;
 use heck::ToUpperCamelCase;
-use proc_macro2::TokenStream;
+use proc_macro2::{Literal, TokenStream};
+use quote::ToTokens;
+use std::str::FromStr;
 use syn::{parse_str, TypePath};
 
 #[derive(Debug)]
 pub struct RustTypesMapping {}
 
+/// Text encoding of the binary built-in types, kept as `String` fields.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BinaryEncoding {
+  Base64,
+  Hex,
+}
+
+impl BinaryEncoding {
+  /// Expression counting the bytes encoded by the `value` string.
+  pub fn get_byte_count_implementation(&self) -> TokenStream {
+    match self {
+      BinaryEncoding::Base64 => quote!(
+        value
+          .chars()
+          .filter(|c| !c.is_whitespace() && *c != '=')
+          .count()
+          * 3
+          / 4
+      ),
+      BinaryEncoding::Hex => quote!(value.chars().filter(|c| !c.is_whitespace()).count() / 2),
+    }
+  }
+}
+
 impl RustTypesMapping {
   pub fn get(context: &XsdContext, kind: &str) -> TokenStream {
     let items: Vec<&str> = kind.split(':').collect();
 
     if items.len() == 2 {
       if context.match_xml_schema_prefix(items.first().unwrap()) {
-        RustTypesMapping::basic_type(items.last().unwrap())
+        RustTypesMapping::schema_type(context, items.last().unwrap())
       } else {
         RustTypesMapping::extern_type(context, items)
       }
     } else if items.len() == 1 {
-      if context.has_xml_schema_prefix() {
-        RustTypesMapping::extern_type(context, items)
+      if RustTypesMapping::is_unprefixed_built_in(context, items.last().unwrap()) {
+        RustTypesMapping::schema_type(context, items.last().unwrap())
       } else {
-        RustTypesMapping::basic_type(items.last().unwrap())
+        RustTypesMapping::extern_type(context, items)
       }
     } else {
       panic!("Unknown type {}", kind)
@@ -34,7 +60,7 @@ impl RustTypesMapping {
       if context.match_xml_schema_prefix(items.first().unwrap()) {
         return *items.last().unwrap() == "string";
       }
-    } else if items.len() == 1 && !context.has_xml_schema_prefix() {
+    } else if items.len() == 1 && RustTypesMapping::is_unprefixed_built_in(context, items[0]) {
       return *items.last().unwrap() == "string";
     }
 
@@ -48,15 +74,143 @@ impl RustTypesMapping {
       if context.match_xml_schema_prefix(items.first().unwrap()) {
         return *items.last().unwrap() == "int";
       }
-    } else if items.len() == 1 && !context.has_xml_schema_prefix() {
+    } else if items.len() == 1 && RustTypesMapping::is_unprefixed_built_in(context, items[0]) {
       return *items.last().unwrap() == "int";
     }
 
     false
   }
 
-  fn basic_type(item: &str) -> TokenStream {
+  /// Encoding of the `base64Binary` and `hexBinary` built-in types.
+  pub fn get_binary_encoding(context: &XsdContext, kind: &str) -> Option<BinaryEncoding> {
+    if !RustTypesMapping::is_xml_schema_type(context, kind) {
+      return None;
+    }
+
+    match kind.split(':').next_back() {
+      Some("base64Binary") => Some(BinaryEncoding::Base64),
+      Some("hexBinary") => Some(BinaryEncoding::Hex),
+      _ => None,
+    }
+  }
+
+  pub fn is_xml_schema_type(context: &XsdContext, kind: &str) -> bool {
+    let items: Vec<&str> = kind.split(':').collect();
+
+    if items.len() == 2 {
+      context.match_xml_schema_prefix(items.first().unwrap())
+    } else {
+      items.len() == 1 && RustTypesMapping::is_unprefixed_built_in(context, items[0])
+    }
+  }
+
+  /// Whether an unprefixed type is a built-in one, when the XML Schema namespace is the default
+  /// namespace. The other unprefixed names are the types of the schema.
+  fn is_unprefixed_built_in(context: &XsdContext, item: &str) -> bool {
+    !context.has_xml_schema_prefix() && RustTypesMapping::get_basic_type(item).is_some()
+  }
+
+  /// Typed literal of a `default` or `fixed` value of a built-in type, normalised and checked
+  /// at generation time, so an invalid value fails the build instead of panicking at runtime.
+  /// Returns `None` for the types without a literal form (`QName` and `NOTATION`).
+  pub fn get_literal(kind: &str, value: &str) -> Option<TokenStream> {
+    let item = kind.split(':').next_back().unwrap_or(kind);
+    let collapsed = value.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    let literal = match item {
+      "bool" | "boolean" => match collapsed.as_str() {
+        "true" | "1" => quote!(true),
+        "false" | "0" => quote!(false),
+        _ => invalid_literal(kind, value),
+      },
+      "byte" => Literal::i8_suffixed(parse_literal(kind, &collapsed)).into_token_stream(),
+      "unsignedByte" => Literal::u8_suffixed(parse_literal(kind, &collapsed)).into_token_stream(),
+      "short" => Literal::i16_suffixed(parse_literal(kind, &collapsed)).into_token_stream(),
+      "unsignedShort" | "gYear" => {
+        Literal::u16_suffixed(parse_literal(kind, &collapsed)).into_token_stream()
+      }
+      "int" | "integer" => {
+        Literal::i32_suffixed(parse_literal(kind, &collapsed)).into_token_stream()
+      }
+      "unsignedInt" => Literal::u32_suffixed(parse_literal(kind, &collapsed)).into_token_stream(),
+      "long" => Literal::i64_suffixed(parse_literal(kind, &collapsed)).into_token_stream(),
+      "unsignedLong" | "nonNegativeInteger" => {
+        Literal::u64_suffixed(parse_literal(kind, &collapsed)).into_token_stream()
+      }
+      "positiveInteger" => match parse_literal::<u64>(kind, &collapsed) {
+        0 => invalid_literal(kind, value),
+        value => Literal::u64_suffixed(value).into_token_stream(),
+      },
+      "float" => match collapsed.as_str() {
+        "INF" | "+INF" => quote!(f32::INFINITY),
+        "-INF" => quote!(f32::NEG_INFINITY),
+        "NaN" => quote!(f32::NAN),
+        _ => Literal::f32_suffixed(parse_float_literal(kind, &collapsed)).into_token_stream(),
+      },
+      "double" => match collapsed.as_str() {
+        "INF" | "+INF" => quote!(f64::INFINITY),
+        "-INF" => quote!(f64::NEG_INFINITY),
+        "NaN" => quote!(f64::NAN),
+        _ => Literal::f64_suffixed(parse_float_literal(kind, &collapsed)).into_token_stream(),
+      },
+      "decimal" => {
+        let digits = collapsed.trim_start_matches(['+', '-']);
+        let (integer, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+        if integer.len() + fraction.len() == 0
+          || !integer
+            .chars()
+            .chain(fraction.chars())
+            .all(|c| c.is_ascii_digit())
+          || digits.len() + 1 < collapsed.len()
+        {
+          invalid_literal(kind, value)
+        }
+
+        quote!(#collapsed.to_string())
+      }
+      "string" | "anyType" => quote!(#value.to_string()),
+      "normalizedString" => {
+        let normalized = value.replace(['\t', '\n', '\r'], " ");
+        quote!(#normalized.to_string())
+      }
+      "IDREFS" => {
+        let references = collapsed
+          .split(' ')
+          .filter(|reference| !reference.is_empty());
+        quote!(vec![#(#references.to_string()),*])
+      }
+      "QName" | "NOTATION" => {
+        log::warn!(
+          "{} values have no literal form, the {:?} value is ignored",
+          kind,
+          value
+        );
+        return None;
+      }
+      _ => {
+        RustTypesMapping::basic_type(item);
+        quote!(#collapsed.to_string())
+      }
+    };
+
+    Some(literal)
+  }
+
+  fn schema_type(context: &XsdContext, item: &str) -> TokenStream {
     match item {
+      "NOTATION" if context.has_notations() => quote!(Notation),
+      _ => RustTypesMapping::basic_type(item),
+    }
+  }
+
+  fn basic_type(item: &str) -> TokenStream {
+    RustTypesMapping::get_basic_type(item)
+      .unwrap_or_else(|| panic!("Type {:?} not implemented", item))
+  }
+
+  fn get_basic_type(item: &str) -> Option<TokenStream> {
+    let rust_type = match item {
       "bool" => quote!(bool),
       "boolean" => quote!(bool),
       "positiveInteger" => quote!(u64),
@@ -81,12 +235,17 @@ impl RustTypesMapping {
       "base64Binary" => quote!(String),
       "duration" => quote!(String),
       "gYear" => quote!(u16),
+      // identifiers and references are kept as raw strings, without cross-reference validation
       "ID" => quote!(String),
       "IDREF" => quote!(String),
-      "IDREFS" => quote!(String),
+      "IDREFS" => quote!(Vec<String>),
       "anyType" => quote!(String),
-      _ => panic!("Type {:?} not implemented", item),
-    }
+      "QName" => quote!(xml_schema::QName),
+      "NOTATION" => quote!(String),
+      _ => return None,
+    };
+
+    Some(rust_type)
   }
 
   fn extern_type(context: &XsdContext, items: Vec<&str>) -> TokenStream {
@@ -108,7 +267,10 @@ impl RustTypesMapping {
 
     let module = if items.len() == 2 {
       let prefix = items.first().unwrap();
-      if let Some(module) = context.get_module(prefix) {
+      if context.is_target_namespace_prefix(prefix) {
+        // same namespace types are resolved as unprefixed ones
+        default_module
+      } else if let Some(module) = context.get_module(prefix) {
         module + "::"
       } else {
         default_module
@@ -123,6 +285,28 @@ impl RustTypesMapping {
   }
 }
 
+fn invalid_literal(kind: &str, value: &str) -> ! {
+  panic!("{:?} is not a valid {} value", value, kind)
+}
+
+fn parse_literal<T: FromStr>(kind: &str, value: &str) -> T {
+  value
+    .parse()
+    .unwrap_or_else(|_| invalid_literal(kind, value))
+}
+
+/// Rust accepts `inf` or `nan` spellings, the schema only the upper-case special values.
+fn parse_float_literal<T: FromStr>(kind: &str, value: &str) -> T {
+  if value
+    .chars()
+    .any(|c| c.is_ascii_alphabetic() && c != 'e' && c != 'E')
+  {
+    invalid_literal(kind, value)
+  }
+
+  parse_literal(kind, value)
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -153,8 +337,10 @@ mod tests {
     assert!(RustTypesMapping::get(&context, "xs:string").to_string() == "String");
     assert!(RustTypesMapping::get(&context, "xs:ID").to_string() == "String");
     assert!(RustTypesMapping::get(&context, "xs:IDREF").to_string() == "String");
-    assert!(RustTypesMapping::get(&context, "xs:IDREFS").to_string() == "String");
+    assert!(RustTypesMapping::get(&context, "xs:IDREFS").to_string() == "Vec < String >");
     assert!(RustTypesMapping::get(&context, "xs:anyType").to_string() == "String");
+    assert!(RustTypesMapping::get(&context, "xs:QName").to_string() == "xml_schema :: QName");
+    assert!(RustTypesMapping::get(&context, "xs:NOTATION").to_string() == "String");
 
     assert!(RustTypesMapping::get(&context, "other:type").to_string() == "Type");
 
@@ -174,6 +360,65 @@ mod tests {
     RustTypesMapping::get(&context, "xs:unknown");
   }
 
+  #[test]
+  fn literals() {
+    assert_eq!(
+      RustTypesMapping::get_literal("xs:boolean", " 1 ")
+        .unwrap()
+        .to_string(),
+      "true"
+    );
+    assert_eq!(
+      RustTypesMapping::get_literal("xs:int", "+7")
+        .unwrap()
+        .to_string(),
+      "7i32"
+    );
+    assert_eq!(
+      RustTypesMapping::get_literal("xs:byte", "-3")
+        .unwrap()
+        .to_string(),
+      "- 3i8"
+    );
+    assert_eq!(
+      RustTypesMapping::get_literal("xs:double", "INF")
+        .unwrap()
+        .to_string(),
+      "f64 :: INFINITY"
+    );
+    assert_eq!(
+      RustTypesMapping::get_literal("xs:float", "1.5e2")
+        .unwrap()
+        .to_string(),
+      "150f32"
+    );
+    assert_eq!(
+      RustTypesMapping::get_literal("xs:IDREFS", "a  b")
+        .unwrap()
+        .to_string(),
+      "vec ! [\"a\" . to_string () , \"b\" . to_string ()]"
+    );
+    assert!(RustTypesMapping::get_literal("xs:QName", "xs:int").is_none());
+  }
+
+  #[test]
+  #[should_panic]
+  fn invalid_decimal_literal() {
+    RustTypesMapping::get_literal("xs:decimal", "INF");
+  }
+
+  #[test]
+  #[should_panic]
+  fn invalid_float_literal() {
+    RustTypesMapping::get_literal("xs:double", "inf");
+  }
+
+  #[test]
+  #[should_panic]
+  fn out_of_range_literal() {
+    RustTypesMapping::get_literal("xs:unsignedByte", "256");
+  }
+
   #[test]
   fn extern_types() {
     let context = XsdContext::new(
@@ -205,6 +450,36 @@ mod tests {
     );
   }
 
+  #[test]
+  fn target_namespace_types() {
+    let content = r#"
+      <xs:schema
+        xmlns:xs="http://www.w3.org/2001/XMLSchema"
+        xmlns:tns="http://example.com"
+        targetNamespace="http://example.com"
+        >
+      </xs:schema>
+    "#;
+
+    let schema = yaserde::de::from_str(content).unwrap();
+
+    let mut mapping = BTreeMap::new();
+    mapping.insert(
+      "http://example.com".to_string(),
+      "rust_example_module".to_string(),
+    );
+
+    let context = XsdContext::new(content)
+      .unwrap()
+      .with_module_namespace_mappings(&mapping)
+      .with_schema(&schema);
+
+    assert_eq!(
+      RustTypesMapping::get(&context, "tns:MyType").to_string(),
+      "MyType"
+    );
+  }
+
   #[test]
   fn extern_types_in_default_namespace() {
     let context = XsdContext::new(
@@ -236,6 +511,30 @@ mod tests {
     );
   }
 
+  #[test]
+  fn binary_encoding() {
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    assert_eq!(
+      RustTypesMapping::get_binary_encoding(&context, "xs:base64Binary"),
+      Some(BinaryEncoding::Base64)
+    );
+    assert_eq!(
+      RustTypesMapping::get_binary_encoding(&context, "xs:hexBinary"),
+      Some(BinaryEncoding::Hex)
+    );
+    assert_eq!(
+      RustTypesMapping::get_binary_encoding(&context, "xs:string"),
+      None
+    );
+    assert_eq!(
+      RustTypesMapping::get_binary_encoding(&context, "other:hexBinary"),
+      None
+    );
+  }
+
   #[test]
   fn is_xs_string() {
     let context = XsdContext::new(
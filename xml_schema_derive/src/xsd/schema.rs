@@ -1,8 +1,12 @@
 use crate::xsd::{
-  attribute, attribute_group, complex_type, element, group, import, qualification, simple_type,
+  annotation, attribute, attribute_group, complex_type, element, group, import, include, notation,
+  qualification, redefine, rust_types_mapping::RustTypesMapping, sequence, simple_type,
   Implementation, XsdContext,
 };
-use proc_macro2::TokenStream;
+use heck::ToUpperCamelCase;
+use proc_macro2::{Span, TokenStream};
+use std::collections::{BTreeMap, BTreeSet};
+use syn::Ident;
 
 #[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
 #[yaserde(
@@ -17,8 +21,18 @@ pub struct Schema {
   pub element_form_default: Option<qualification::Qualification>,
   #[yaserde(rename = "attributeFormDefault", attribute)]
   pub attribute_form_default: Option<qualification::Qualification>,
+  #[yaserde(attribute)]
+  pub version: Option<String>,
+  #[yaserde(rename = "defaultAttributes", attribute)]
+  pub default_attributes: Option<String>,
+  #[yaserde(rename = "annotation")]
+  pub annotation: Option<annotation::Annotation>,
   #[yaserde(rename = "import")]
   pub imports: Vec<import::Import>,
+  #[yaserde(rename = "include")]
+  pub includes: Vec<include::Include>,
+  #[yaserde(rename = "redefine")]
+  pub redefines: Vec<redefine::Redefine>,
   #[yaserde(rename = "element")]
   pub elements: Vec<element::Element>,
   #[yaserde(rename = "simpleType")]
@@ -31,6 +45,8 @@ pub struct Schema {
   pub attribute_group: Vec<attribute_group::AttributeGroup>,
   #[yaserde(rename = "group")]
   pub group: Vec<group::Group>,
+  #[yaserde(rename = "notation")]
+  pub notations: Vec<notation::Notation>,
 }
 
 impl Implementation for Schema {
@@ -40,15 +56,42 @@ impl Implementation for Schema {
     target_prefix: &Option<String>,
     context: &XsdContext,
   ) -> TokenStream {
-    let namespace_definition = generate_namespace_definition(target_prefix, &self.target_namespace);
+    let target_prefix = &target_prefix.clone().or_else(|| {
+      self
+        .target_namespace
+        .as_ref()
+        .and_then(|target_namespace| context.get_namespace_prefix(target_namespace))
+    });
+
+    let namespace_definition = generate_namespace_definition(
+      target_prefix,
+      &self.target_namespace,
+      &context.get_namespace_prefixes(),
+      context.get_default_namespace(),
+    );
 
     log::info!("Generate elements");
     let elements: TokenStream = self
       .elements
       .iter()
-      .map(|element| element.implement(&namespace_definition, target_prefix, context))
+      .map(|element| {
+        let included_context = context.get_included_context(&element.name);
+        let context = included_context.as_ref().unwrap_or(context);
+        element.implement(&namespace_definition, target_prefix, context)
+      })
       .collect();
 
+    let reachable_types = context
+      .has_prune_unused()
+      .then(|| self.get_reachable_types(context));
+
+    let is_reachable = |name: &str| {
+      reachable_types
+        .as_ref()
+        .map(|reachable_types| reachable_types.contains(name))
+        .unwrap_or(true)
+    };
+
     log::info!("Generate simple types");
     let simple_types: TokenStream = {
       let mut context = context.clone();
@@ -57,6 +100,7 @@ impl Implementation for Schema {
       self
         .simple_type
         .iter()
+        .filter(|simple_type| is_reachable(&simple_type.name))
         .map(|simple_type| simple_type.implement(&namespace_definition, target_prefix, &context))
         .collect()
     };
@@ -69,7 +113,12 @@ impl Implementation for Schema {
       self
         .complex_type
         .iter()
-        .map(|complex_type| complex_type.implement(&namespace_definition, target_prefix, &context))
+        .filter(|complex_type| is_reachable(&complex_type.name))
+        .map(|complex_type| {
+          let included_context = context.get_included_context(&complex_type.name);
+          let context = included_context.as_ref().unwrap_or(&context);
+          complex_type.implement(&namespace_definition, target_prefix, context)
+        })
         .collect()
     };
 
@@ -81,28 +130,321 @@ impl Implementation for Schema {
       self
         .group
         .iter()
-        .map(|group| group.implement(&namespace_definition, target_prefix, &context))
+        .filter(|group| {
+          group
+            .name
+            .as_ref()
+            .map(|name| is_reachable(name))
+            .unwrap_or(true)
+        })
+        .map(|group| {
+          let included_context = group
+            .name
+            .as_ref()
+            .and_then(|name| context.get_included_context(name));
+          let context = included_context.as_ref().unwrap_or(&context);
+          group.implement(&namespace_definition, target_prefix, context)
+        })
+        .collect()
+    };
+
+    log::info!("Generate substitution groups");
+    let substitution_groups: TokenStream = {
+      let mut context = context.clone();
+      context.set_is_in_sub_module(true);
+
+      self
+        .elements
+        .iter()
+        .map(|element| element.get_substitution_implementation(&context))
         .collect()
     };
 
+    let root_elements = self.get_root_elements_implementation(context);
+
+    log::info!("Generate notations");
+    let notations = notation::Notation::get_enumeration_implementation(context, &self.notations);
+
+    let schema_doc = self
+      .annotation
+      .as_ref()
+      .map(|annotation| annotation.get_module_documentation())
+      .unwrap_or_default();
+
+    let version_doc = self
+      .version
+      .as_ref()
+      .map(|version| {
+        let separator = (!schema_doc.is_empty()).then(|| quote!(#![doc = ""]));
+        let doc = format!(" Types generated from the schema version {version}.");
+        quote!(
+          #separator
+          #![doc = #doc]
+
+          /// `version` attribute of the schema.
+          pub const SCHEMA_VERSION: &str = #version;
+        )
+      })
+      .unwrap_or_default();
+
     quote!(
       pub mod xml_schema_types {
+        #schema_doc
+        #version_doc
+
+        #notations
         #simple_types
         #complex_types
         #groups
+        #substitution_groups
       }
 
       #elements
+
+      #root_elements
+    )
+  }
+}
+
+impl Schema {
+  /// `RootElement` enumeration of the global elements, identifying the root of a document from
+  /// its first tag, without deserializing it.
+  fn get_root_elements_implementation(&self, context: &XsdContext) -> TokenStream {
+    if !context.has_root_elements() {
+      return quote!();
+    }
+
+    let namespace = self
+      .target_namespace
+      .as_ref()
+      .map(|namespace| quote!(Some(#namespace)))
+      .unwrap_or_else(|| quote!(None));
+
+    let (variants, matches): (Vec<TokenStream>, Vec<TokenStream>) = self
+      .elements
+      .iter()
+      .filter(|element| !element.is_abstract(context))
+      .map(|element| {
+        let name = &element.name;
+        let variant_name = Ident::new(
+          &name.replace('.', "_").to_upper_camel_case(),
+          Span::call_site(),
+        );
+
+        (
+          quote!(#variant_name,),
+          quote!((#namespace, #name) => Ok(RootElement::#variant_name),),
+        )
+      })
+      .unzip();
+
+    if variants.is_empty() {
+      return quote!();
+    }
+
+    quote!(
+      /// Global elements of the schema, which can be the root of a document.
+      #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+      pub enum RootElement {
+        #(#variants)*
+      }
+
+      impl RootElement {
+        /// Root element of the document, read from its first tag without parsing its content.
+        pub fn from_document(content: &str) -> Result<Self, String> {
+          for event in xml::reader::EventReader::from_str(content) {
+            if let xml::reader::XmlEvent::StartElement { name, .. } = event.map_err(|e| e.to_string())? {
+              return match (name.namespace.as_deref(), name.local_name.as_str()) {
+                #(#matches)*
+                _ => Err(format!("{} is not a global element of the schema", name)),
+              };
+            }
+          }
+
+          Err("Missing root element".to_string())
+        }
+      }
     )
   }
+
+  /// Names of the types used, directly or not, by the global elements.
+  fn get_reachable_types(&self, context: &XsdContext) -> BTreeSet<String> {
+    let mut pending = vec![];
+    for element in &self.elements {
+      collect_element_types(context, element, &mut pending);
+    }
+
+    let mut reachable_types = BTreeSet::new();
+
+    while let Some(name) = pending.pop() {
+      if !reachable_types.insert(name.clone()) {
+        continue;
+      }
+
+      for complex_type in self.complex_type.iter().filter(|item| item.name == name) {
+        collect_complex_type_types(context, complex_type, &mut pending);
+      }
+
+      for simple_type in self.simple_type.iter().filter(|item| item.name == name) {
+        collect_simple_type_types(context, simple_type, &mut pending);
+      }
+
+      for group in self
+        .group
+        .iter()
+        .filter(|item| item.name.as_deref() == Some(&name))
+      {
+        if let Some(sequence) = &group.sequence {
+          collect_sequence_types(context, sequence, &mut pending);
+        }
+      }
+    }
+
+    log::info!("Reachable types: {:?}", reachable_types);
+
+    reachable_types
+  }
+}
+
+fn collect_type(context: &XsdContext, kind: &str, pending: &mut Vec<String>) {
+  if kind.is_empty() || RustTypesMapping::is_xml_schema_type(context, kind) {
+    return;
+  }
+
+  let name = kind.split(':').next_back().unwrap_or(kind);
+  pending.push(name.to_string());
+}
+
+fn collect_element_types(
+  context: &XsdContext,
+  element: &element::Element,
+  pending: &mut Vec<String>,
+) {
+  if let Some(kind) = &element.kind {
+    collect_type(context, kind, pending);
+  }
+
+  if let Some(complex_type) = &element.complex_type {
+    collect_complex_type_types(context, complex_type, pending);
+  }
+
+  if let Some(simple_type) = &element.simple_type {
+    collect_simple_type_types(context, simple_type, pending);
+  }
+}
+
+fn collect_complex_type_types(
+  context: &XsdContext,
+  complex_type: &complex_type::ComplexType,
+  pending: &mut Vec<String>,
+) {
+  for sequence in complex_type.get_sequences() {
+    collect_sequence_types(context, sequence, pending);
+  }
+
+  for attribute in complex_type.get_attributes(context) {
+    let attribute = attribute
+      .get_referenced_attribute(context)
+      .unwrap_or_else(|| attribute.clone());
+
+    if let Some(kind) = &attribute.kind {
+      collect_type(context, kind, pending);
+    }
+
+    if let Some(simple_type) = &attribute.simple_type {
+      collect_simple_type_types(context, simple_type, pending);
+    }
+  }
+
+  if let Some(base) = complex_type.get_simple_content_base() {
+    collect_type(context, base, pending);
+  }
+
+  if let Some(extension) = complex_type
+    .complex_content
+    .as_ref()
+    .and_then(|complex_content| complex_content.extension.as_ref())
+  {
+    collect_type(context, &extension.base, pending);
+
+    if let Some(reference) = extension
+      .group
+      .as_ref()
+      .and_then(|group| group.reference.as_ref())
+    {
+      collect_type(context, reference, pending);
+    }
+  }
+
+  if let Some(restriction) = complex_type
+    .complex_content
+    .as_ref()
+    .and_then(|complex_content| complex_content.restriction.as_ref())
+  {
+    collect_type(context, &restriction.base, pending);
+  }
+}
+
+fn collect_sequence_types(
+  context: &XsdContext,
+  sequence: &sequence::Sequence,
+  pending: &mut Vec<String>,
+) {
+  for element in &sequence.elements {
+    collect_element_types(context, element, pending);
+  }
+
+  for reference in sequence
+    .groups
+    .iter()
+    .filter_map(|group| group.reference.as_ref())
+  {
+    collect_type(context, reference, pending);
+  }
+}
+
+fn collect_simple_type_types(
+  context: &XsdContext,
+  simple_type: &simple_type::SimpleType,
+  pending: &mut Vec<String>,
+) {
+  if let Some(base) = simple_type
+    .restriction
+    .as_ref()
+    .and_then(|restriction| restriction.base.as_ref())
+  {
+    collect_type(context, base, pending);
+  }
+
+  if let Some(list) = &simple_type.list {
+    collect_type(context, &list.item_type, pending);
+  }
+
+  if let Some(union) = &simple_type.union {
+    for member_type in union.member_types.split_whitespace() {
+      collect_type(context, member_type, pending);
+    }
+  }
 }
 
 fn generate_namespace_definition(
   target_prefix: &Option<String>,
   target_namespace: &Option<String>,
+  namespace_prefixes: &BTreeMap<String, String>,
+  default_namespace: Option<&str>,
 ) -> TokenStream {
+  let other_namespaces: Vec<String> = namespace_prefixes
+    .iter()
+    .filter(|(namespace, _prefix)| Some(*namespace) != target_namespace.as_ref())
+    .map(|(namespace, prefix)| format!("{prefix}: {namespace}"))
+    .collect();
+
   match (target_prefix, target_namespace) {
-    (None, None) => quote!(),
+    (None, None) if other_namespaces.is_empty() => quote!(),
+    (None, None) => quote!(#[yaserde(#(namespace=#other_namespaces),*)]),
+    (None, Some(target_namespace)) if default_namespace == Some(target_namespace) => {
+      quote!(#[yaserde(namespace=#target_namespace #(, namespace=#other_namespaces)*)])
+    }
     (None, Some(_target_namespace)) => {
       panic!("undefined prefix attribute, a target namespace is defined")
     }
@@ -111,7 +453,7 @@ fn generate_namespace_definition(
     ),
     (Some(prefix), Some(target_namespace)) => {
       let namespace = format!("{prefix}: {target_namespace}");
-      quote!(#[yaserde(prefix=#prefix, namespace=#namespace)])
+      quote!(#[yaserde(prefix=#prefix, namespace=#namespace #(, namespace=#other_namespaces)*)])
     }
   }
 }
@@ -132,6 +474,97 @@ mod tests {
     assert_eq!(implementation, "pub mod xml_schema_types { }");
   }
 
+  #[test]
+  fn schema_version_documentation() {
+    let schema = Schema {
+      version: Some("1.2".to_string()),
+      ..Default::default()
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = format!("{}", schema.implement(&TokenStream::new(), &None, &context));
+    assert_eq!(
+      implementation,
+      r#"pub mod xml_schema_types { # ! [doc = " Types generated from the schema version 1.2."] # [doc = r" `version` attribute of the schema."] pub const SCHEMA_VERSION : & str = "1.2" ; }"#
+    );
+  }
+
+  #[test]
+  fn schema_annotation_documentation() {
+    let content = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema" version="1.2">
+      <xs:annotation>
+        <xs:documentation>Catalog of the library.</xs:documentation>
+      </xs:annotation>
+    </xs:schema>"#;
+
+    let schema: Schema = yaserde::de::from_str(content).unwrap();
+    let context = XsdContext::new(content).unwrap().with_schema(&schema);
+
+    let implementation = format!("{}", schema.implement(&TokenStream::new(), &None, &context));
+    assert_eq!(
+      implementation,
+      r#"pub mod xml_schema_types { # ! [doc = "Catalog of the library."] # ! [doc = ""] # ! [doc = " Types generated from the schema version 1.2."] # [doc = r" `version` attribute of the schema."] pub const SCHEMA_VERSION : & str = "1.2" ; }"#
+    );
+  }
+
+  #[test]
+  fn reachable_types() {
+    let content = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+      <xs:simpleType name="Code"><xs:restriction base="xs:string" /></xs:simpleType>
+      <xs:simpleType name="Codes"><xs:list itemType="Code" /></xs:simpleType>
+      <xs:complexType name="Item">
+        <xs:sequence><xs:element name="codes" type="Codes" /></xs:sequence>
+      </xs:complexType>
+      <xs:complexType name="Unused">
+        <xs:sequence><xs:element name="code" type="Code" /></xs:sequence>
+      </xs:complexType>
+      <xs:complexType name="Other" />
+      <xs:element name="Root" type="Item" />
+    </xs:schema>"#;
+
+    let schema: Schema = yaserde::de::from_str(content).unwrap();
+    let context = XsdContext::new(content).unwrap();
+
+    let reachable_types: Vec<String> = schema.get_reachable_types(&context).into_iter().collect();
+    assert_eq!(reachable_types, vec!["Code", "Codes", "Item"]);
+  }
+
+  #[test]
+  fn reachable_derivation_and_group_types() {
+    let content = r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema">
+      <xs:complexType name="Base">
+        <xs:sequence><xs:element name="label" type="xs:string" /></xs:sequence>
+      </xs:complexType>
+      <xs:complexType name="Restricted">
+        <xs:complexContent><xs:restriction base="Base" /></xs:complexContent>
+      </xs:complexType>
+      <xs:complexType name="Detail" />
+      <xs:group name="Details">
+        <xs:sequence><xs:element name="detail" type="Detail" /></xs:sequence>
+      </xs:group>
+      <xs:complexType name="Item">
+        <xs:sequence>
+          <xs:element name="restricted" type="Restricted" />
+          <xs:group ref="Details" />
+        </xs:sequence>
+      </xs:complexType>
+      <xs:complexType name="Unused" />
+      <xs:element name="Root" type="Item" />
+    </xs:schema>"#;
+
+    let schema: Schema = yaserde::de::from_str(content).unwrap();
+    let context = XsdContext::new(content).unwrap();
+
+    let reachable_types: Vec<String> = schema.get_reachable_types(&context).into_iter().collect();
+    assert_eq!(
+      reachable_types,
+      vec!["Base", "Detail", "Details", "Item", "Restricted"]
+    );
+  }
+
   #[test]
   #[should_panic]
   fn missing_prefix() {
@@ -164,6 +597,8 @@ mod tests {
     let definition = generate_namespace_definition(
       &Some("prefix".to_string()),
       &Some("http://example.com".to_string()),
+      &BTreeMap::new(),
+      None,
     );
 
     let implementation = format!("{definition}");
@@ -173,4 +608,43 @@ mod tests {
       r#"# [yaserde (prefix = "prefix" , namespace = "prefix: http://example.com")]"#
     );
   }
+
+  #[test]
+  fn generate_configured_namespaces() {
+    let namespace_prefixes = BTreeMap::from([
+      ("http://example.com".to_string(), "ex".to_string()),
+      ("http://other.com".to_string(), "other".to_string()),
+    ]);
+
+    let definition = generate_namespace_definition(
+      &Some("ex".to_string()),
+      &Some("http://example.com".to_string()),
+      &namespace_prefixes,
+      None,
+    );
+
+    let implementation = format!("{definition}");
+
+    assert_eq!(
+      implementation,
+      r#"# [yaserde (prefix = "ex" , namespace = "ex: http://example.com" , namespace = "other: http://other.com")]"#
+    );
+  }
+
+  #[test]
+  fn generate_default_namespace() {
+    let definition = generate_namespace_definition(
+      &None,
+      &Some("http://example.com".to_string()),
+      &BTreeMap::new(),
+      Some("http://example.com"),
+    );
+
+    let implementation = format!("{definition}");
+
+    assert_eq!(
+      implementation,
+      r#"# [yaserde (namespace = "http://example.com")]"#
+    );
+  }
 }
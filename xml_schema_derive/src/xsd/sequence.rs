@@ -1,12 +1,17 @@
-use crate::xsd::{element::Element, Implementation, XsdContext};
+use crate::xsd::{choice::Choice, element::Element, group::Group, Implementation, XsdContext};
 use log::info;
 use proc_macro2::TokenStream;
+use syn::FieldsNamed;
 
 #[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
 #[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
 pub struct Sequence {
   #[yaserde(rename = "element")]
   pub elements: Vec<Element>,
+  #[yaserde(rename = "group")]
+  pub groups: Vec<Group>,
+  #[yaserde(rename = "choice")]
+  pub choices: Vec<Choice>,
 }
 
 impl Implementation for Sequence {
@@ -17,11 +22,7 @@ impl Implementation for Sequence {
     context: &XsdContext,
   ) -> TokenStream {
     info!("Generate elements");
-    self
-      .elements
-      .iter()
-      .map(|element| element.get_field_implementation(context, prefix))
-      .collect()
+    self.get_field_implementation(context, prefix)
   }
 }
 
@@ -33,22 +34,146 @@ impl Sequence {
     prefix: &Option<String>,
   ) -> TokenStream {
     info!("Generate sub types implementation");
-    self
+    let elements_sub_types: TokenStream = self
       .elements
       .iter()
       .map(|element| element.get_subtypes_implementation(namespace_definition, prefix, context))
-      .collect()
+      .collect();
+
+    let choices_sub_types: TokenStream = self
+      .choices
+      .iter()
+      .enumerate()
+      .map(|(index, choice)| {
+        choice.get_subtypes_implementation(namespace_definition, prefix, context, index)
+      })
+      .collect();
+
+    quote!(
+      #elements_sub_types
+      #choices_sub_types
+    )
   }
 
+  /// Fields of the elements, followed by the ones of the choices, with the attributes added by
+  /// the field hook.
   pub fn get_field_implementation(
     &self,
     context: &XsdContext,
     prefix: &Option<String>,
   ) -> TokenStream {
-    self
+    let fields = self
       .elements
       .iter()
       .map(|element| element.get_field_implementation(context, prefix))
+      .chain(
+        self
+          .choices
+          .iter()
+          .enumerate()
+          .map(|(index, choice)| choice.get_field_implementation(context, index)),
+      );
+
+    let fields: FieldsNamed =
+      syn::parse2(quote!({ #(#fields)* })).expect("[Sequence] unable to parse fields");
+
+    fields
+      .named
+      .iter()
+      .map(|field| {
+        let field_name = field.ident.as_ref().unwrap().to_string();
+        let field_type = &field.ty;
+        let hook_attributes =
+          context.get_field_hook_attributes(&field_name, &quote!(#field_type).to_string());
+
+        quote!(
+          #hook_attributes
+          #field,
+        )
+      })
       .collect()
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::xsd::field_hook::FieldHook;
+  use std::rc::Rc;
+  use std::str::FromStr;
+  use yaserde::de::from_str;
+
+  #[test]
+  fn unbounded_element_rename() {
+    let xml = r#"
+      <xs:sequence xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:element name="item" type="xs:int" maxOccurs="unbounded"/>
+      </xs:sequence>
+    "#;
+
+    let sequence: Sequence = from_str(xml).unwrap();
+
+    let mut context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+    context.set_is_in_sub_module(true);
+
+    let implementation = sequence.get_field_implementation(&context, &None);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(rename = "item")]
+        pub item_list: Vec<i32> ,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
+  #[derive(Debug)]
+  struct OptionalFieldHook;
+
+  impl FieldHook for OptionalFieldHook {
+    fn get_attributes(&self, field_name: &str, field_type: &str) -> TokenStream {
+      if field_type.starts_with("Option") {
+        let note = format!("optional {field_name}");
+        quote!(#[doc = #note])
+      } else {
+        quote!()
+      }
+    }
+  }
+
+  #[test]
+  fn field_hook_attributes() {
+    let xml = r#"
+      <xs:sequence xmlns:xs="http://www.w3.org/2001/XMLSchema">
+        <xs:element name="name" type="xs:string"/>
+        <xs:element name="nickname" type="xs:string" minOccurs="0"/>
+      </xs:sequence>
+    "#;
+
+    let sequence: Sequence = from_str(xml).unwrap();
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap()
+        .with_field_hook(Rc::new(OptionalFieldHook));
+
+    let implementation = sequence.get_field_implementation(&context, &None);
+
+    let expected = TokenStream::from_str(
+      r#"
+        #[yaserde(rename = "name")]
+        pub name: String,
+        #[doc = "optional nickname"]
+        #[yaserde(rename = "nickname")]
+        pub nickname: Option<String> ,
+      "#,
+    )
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+}
@@ -1,11 +1,19 @@
-use crate::xsd::{extension::Extension, Implementation, XsdContext};
+use crate::xsd::{
+  attribute::Attribute,
+  extension::Extension,
+  restriction::Restriction,
+  rust_types_mapping::{BinaryEncoding, RustTypesMapping},
+  Implementation, XsdContext,
+};
 use proc_macro2::TokenStream;
 
 #[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
 #[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
 pub struct SimpleContent {
   #[yaserde(prefix = "xs", rename = "extension")]
-  pub extension: Extension,
+  pub extension: Option<Extension>,
+  #[yaserde(prefix = "xs", rename = "restriction")]
+  pub restriction: Option<Restriction>,
 }
 
 impl Implementation for SimpleContent {
@@ -15,18 +23,106 @@ impl Implementation for SimpleContent {
     prefix: &Option<String>,
     context: &XsdContext,
   ) -> TokenStream {
-    self
-      .extension
-      .implement(namespace_definition, prefix, context)
+    if let Some(extension) = &self.extension {
+      return extension.implement(namespace_definition, prefix, context);
+    }
+
+    let rust_type = self.get_content_type(context);
+
+    let attributes: TokenStream = self
+      .get_attributes(context)
+      .iter()
+      .map(|attribute| attribute.implement(namespace_definition, prefix, context))
+      .collect();
+
+    let inner_attribute = if format!("{rust_type}") == "String" {
+      quote!(#[yaserde(text)])
+    } else {
+      TokenStream::new()
+    };
+
+    quote!(
+      #inner_attribute
+      pub base: #rust_type,
+      #attributes
+    )
   }
 }
 
 impl SimpleContent {
-  pub fn get_field_implementation(
-    &self,
-    context: &XsdContext,
-    prefix: &Option<String>,
-  ) -> TokenStream {
-    self.extension.get_field_implementation(context, prefix)
+  /// Type of the text content, a restriction of a complex type keeps the content type of its base.
+  pub fn get_content_type(&self, context: &XsdContext) -> TokenStream {
+    match (&self.extension, &self.restriction) {
+      (Some(extension), _) => RustTypesMapping::get(context, &extension.base),
+      (None, Some(restriction)) => restriction
+        .base
+        .as_ref()
+        .and_then(|base| context.get_complex_type(base))
+        .and_then(|base| base.simple_content.as_ref())
+        .filter(|simple_content| *simple_content != self)
+        .map(|simple_content| simple_content.get_content_type(context))
+        .unwrap_or_else(|| restriction.get_type_implementation(context, &None)),
+      (None, None) => quote!(String),
+    }
+  }
+
+  /// Encoding of binary text content, following the content type of a restricted complex type.
+  pub fn get_binary_encoding(&self, context: &XsdContext) -> Option<BinaryEncoding> {
+    match (&self.extension, &self.restriction) {
+      (Some(extension), _) => RustTypesMapping::get_binary_encoding(context, &extension.base),
+      (None, Some(restriction)) => {
+        let base = restriction.base.as_ref()?;
+        match context
+          .get_complex_type(base)
+          .and_then(|base| base.simple_content.as_ref())
+          .filter(|simple_content| *simple_content != self)
+        {
+          Some(simple_content) => simple_content.get_binary_encoding(context),
+          None => RustTypesMapping::get_binary_encoding(context, base),
+        }
+      }
+      (None, None) => None,
+    }
+  }
+
+  /// Attributes of the content, a restriction inherits the attributes of its base it does not re-declare.
+  pub fn get_attributes<'a>(&'a self, context: &'a XsdContext) -> Vec<&'a Attribute> {
+    match (&self.extension, &self.restriction) {
+      (Some(extension), _) => extension.attributes.iter().collect(),
+      (None, Some(restriction)) => {
+        let inherited = restriction
+          .base
+          .as_ref()
+          .and_then(|base| context.get_complex_type(base))
+          .and_then(|base| base.simple_content.as_ref())
+          .filter(|simple_content| *simple_content != self)
+          .map(|simple_content| simple_content.get_attributes(context))
+          .unwrap_or_default();
+
+        let find = |attributes: &[&'a Attribute], attribute: &Attribute| {
+          attributes
+            .iter()
+            .find(|other| other.get_field_name() == attribute.get_field_name())
+            .copied()
+        };
+
+        let restricted: Vec<&Attribute> = restriction.attributes.iter().collect();
+
+        // keep the declaration order of the base, re-declared attributes replace the inherited ones
+        let mut attributes: Vec<&Attribute> = inherited
+          .iter()
+          .map(|attribute| find(&restricted, attribute).unwrap_or(attribute))
+          .collect();
+
+        attributes.extend(
+          restricted
+            .iter()
+            .filter(|attribute| find(&inherited, attribute).is_none()),
+        );
+
+        attributes
+      }
+      (None, None) => vec![],
+    }
   }
 }
@@ -26,9 +26,24 @@ impl Implementation for SimpleType {
       return list.implement_childs(namespace_definition, prefix, context, &struct_name);
     }
 
+    if let Some(union) = &self.union {
+      return union.implement_childs(namespace_definition, prefix, context, &struct_name);
+    }
+
+    if let Some(restriction) = self
+      .restriction
+      .as_ref()
+      .filter(|restriction| restriction.is_enumeration() || restriction.has_facets())
+    {
+      return restriction.implement_childs(namespace_definition, prefix, context, &struct_name);
+    }
+
+    let non_exhaustive = context.get_non_exhaustive_attribute();
+
     quote!(
       #[derive(Clone, Debug, Default, PartialEq, yaserde_derive::YaDeserialize, yaserde_derive::YaSerialize)]
       #namespace_definition
+      #non_exhaustive
       pub struct #struct_name {
         #[yaserde(text)]
         pub content: std::string::String,
@@ -54,6 +69,7 @@ impl SimpleType {
 #[cfg(test)]
 mod tests {
   use super::*;
+  use crate::xsd::enumeration::Enumeration;
   use std::str::FromStr;
 
   static DERIVES: &str =
@@ -86,6 +102,56 @@ mod tests {
     assert_eq!(implementation.to_string(), expected.to_string());
   }
 
+  #[test]
+  fn enumeration_simple_type() {
+    let st = SimpleType {
+      name: "status".to_string(),
+      restriction: Some(Restriction {
+        base: Some("xs:string".to_string()),
+        enumerations: vec![
+          Enumeration {
+            value: "open".to_string(),
+          },
+          Enumeration {
+            value: "closed".to_string(),
+          },
+        ],
+        ..Default::default()
+      }),
+      list: None,
+      union: None,
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let implementation = st.implement(&quote!(), &None, &context);
+
+    let expected = TokenStream::from_str(&format!(
+      r#"{DERIVES}
+        pub enum Status {{
+          #[default]
+          #[yaserde(rename = "open")]
+          Open,
+          #[yaserde(rename = "closed")]
+          Closed,
+        }}
+
+        impl std::fmt::Display for Status {{
+          fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {{
+            f.write_str(match self {{
+              Status::Open => "open",
+              Status::Closed => "closed",
+            }})
+          }}
+        }}"#,
+    ))
+    .unwrap();
+
+    assert_eq!(implementation.to_string(), expected.to_string());
+  }
+
   // <!-- Whitespace-separated list of strings -->
   // <xs:simpleType name="StringVectorType">
   //   <xs:list itemType="xs:string"/>
@@ -1,6 +1,144 @@
+use crate::xsd::{
+  restriction::Restriction, rust_types_mapping::RustTypesMapping, Implementation, XsdContext,
+};
+use heck::ToUpperCamelCase;
+use proc_macro2::{Ident, Span, TokenStream};
+
 #[derive(Clone, Default, Debug, PartialEq, YaDeserialize)]
 #[yaserde(prefix = "xs", namespace = "xs: http://www.w3.org/2001/XMLSchema")]
 pub struct Union {
   #[yaserde(rename = "memberTypes", attribute)]
   pub member_types: String,
 }
+
+impl Implementation for Union {
+  fn implement_childs(
+    &self,
+    _namespace_definition: &TokenStream,
+    _prefix: &Option<String>,
+    context: &XsdContext,
+    struct_name: &Ident,
+  ) -> TokenStream {
+    let members: Vec<(Ident, TokenStream, bool)> = self
+      .member_types
+      .split_whitespace()
+      .map(|member| {
+        let name = member.split(':').next_back().unwrap_or(member);
+        let variant_name = Ident::new(
+          &name.replace('.', "_").to_upper_camel_case(),
+          Span::call_site(),
+        );
+        let rust_type = RustTypesMapping::get(context, member);
+        let is_xml_schema_type = RustTypesMapping::is_xml_schema_type(context, member);
+
+        (variant_name, rust_type, is_xml_schema_type)
+      })
+      .collect();
+
+    let Some((default_variant, _, _)) = members.first() else {
+      panic!("[Union] {} has no member types", struct_name);
+    };
+
+    let name = struct_name.to_string();
+    let non_exhaustive = context.get_non_exhaustive_attribute();
+
+    let variants = members
+      .iter()
+      .map(|(variant_name, rust_type, _)| quote!(#variant_name(#rust_type),));
+
+    // members are tried in declaration order
+    let parse_members = members
+      .iter()
+      .map(|(variant_name, rust_type, is_xml_schema_type)| {
+        let parse = if *is_xml_schema_type {
+          quote!(value.parse::<#rust_type>())
+        } else {
+          quote!(yaserde::de::from_str::<#rust_type>(&format!("<{0}>{1}</{0}>", #name, value)))
+        };
+
+        quote!(
+          if let Ok(member) = #parse {
+            return Ok(#struct_name::#variant_name(member));
+          }
+        )
+      });
+
+    let display_members = members.iter().map(|(variant_name, _, is_xml_schema_type)| {
+      if *is_xml_schema_type {
+        quote!(#struct_name::#variant_name(member) => write!(f, "{}", member),)
+      } else {
+        quote!(
+          #struct_name::#variant_name(member) => {
+            let content = yaserde::ser::to_string_content(member).map_err(|_| std::fmt::Error)?;
+            write!(f, "{}", content)
+          }
+        )
+      }
+    });
+
+    let text_implementation = Restriction::get_text_implementation(
+      struct_name,
+      quote!(text_content.parse()),
+      quote!(self.to_string()),
+    );
+
+    quote!(
+      #[derive(Clone, Debug, PartialEq)]
+      #non_exhaustive
+      pub enum #struct_name {
+        #(#variants)*
+      }
+
+      impl Default for #struct_name {
+        fn default() -> Self {
+          #struct_name::#default_variant(Default::default())
+        }
+      }
+
+      impl std::str::FromStr for #struct_name {
+        type Err = String;
+
+        fn from_str(value: &str) -> Result<Self, Self::Err> {
+          #(#parse_members)*
+
+          Err(format!("{:?} does not match any member of {}", value, #name))
+        }
+      }
+
+      impl std::fmt::Display for #struct_name {
+        fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+          match self {
+            #(#display_members)*
+          }
+        }
+      }
+
+      #text_implementation
+    )
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[test]
+  fn union_variants() {
+    let union = Union {
+      member_types: "xs:int xs:string".to_string(),
+    };
+
+    let context =
+      XsdContext::new(r#"<xs:schema xmlns:xs="http://www.w3.org/2001/XMLSchema"></xs:schema>"#)
+        .unwrap();
+
+    let struct_name = Ident::new("Value", Span::call_site());
+
+    let implementation = union
+      .implement_childs(&TokenStream::new(), &None, &context, &struct_name)
+      .to_string();
+
+    assert!(implementation.contains("pub enum Value { Int (i32) , String (String) , }"));
+    assert!(implementation.contains("value . parse :: < i32 > ()"));
+  }
+}
@@ -1,14 +1,76 @@
-use std::collections::BTreeMap;
+use crate::xsd::{
+  attribute::Attribute, attribute_group::AttributeGroup, complex_type::ComplexType,
+  element::Element, field_hook::FieldHook, qualification::Qualification,
+  rust_types_mapping::RustTypesMapping, schema::Schema, simple_type::SimpleType,
+};
+use proc_macro2::TokenStream;
+use std::cell::OnceCell;
+use std::collections::{BTreeMap, BTreeSet};
 use std::io::Cursor;
-use xml::namespace::Namespace;
+use std::rc::Rc;
+use xml::namespace::{Namespace, NS_NO_PREFIX};
 use xml::reader::{EventReader, XmlEvent};
 
+const XML_SCHEMA_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema";
+
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum Inheritance {
+  /// The base type is kept as a `#[yaserde(flatten)]` field.
+  Flatten,
+  /// The fields of the base type are copied into the derived type.
+  #[default]
+  Inline,
+}
+
+/// Form defaults of the schema declaring a definition, when it is included from another schema.
+#[derive(Clone, Debug)]
+struct FormDefaults {
+  element: Option<Qualification>,
+  attribute: Qualification,
+}
+
 #[derive(Clone, Debug)]
 pub struct XsdContext {
-  module_namespace_mappings: BTreeMap<String, String>,
+  module_namespace_mappings: Rc<BTreeMap<String, String>>,
+  namespace_prefixes: Rc<BTreeMap<String, String>>,
   pub namespace: Namespace,
   xml_schema_prefix: Option<String>,
   is_in_sub_module: bool,
+  attribute_form_default: Qualification,
+  element_form_default: Option<Qualification>,
+  included_form_defaults: Rc<BTreeMap<String, FormDefaults>>,
+  parent_name: Option<String>,
+  inheritance: Inheritance,
+  list_wrappers: bool,
+  accessors: bool,
+  non_exhaustive: bool,
+  redact_binary: bool,
+  prune_unused: bool,
+  iterators: bool,
+  streams: bool,
+  visitors: bool,
+  extension_traits: bool,
+  validating_constructors: bool,
+  validation_errors: bool,
+  strict_order: bool,
+  capture_unknown: bool,
+  partial_eq_ignore: Rc<BTreeSet<String>>,
+  hide_anonymous_types: bool,
+  root_elements: bool,
+  types_path: Option<String>,
+  field_hook: Option<Rc<dyn FieldHook>>,
+  complex_types: Rc<BTreeMap<String, ComplexType>>,
+  simple_types: Rc<BTreeMap<String, SimpleType>>,
+  attributes: Rc<BTreeMap<String, Attribute>>,
+  elements: Rc<BTreeMap<String, Element>>,
+  imported_elements: Rc<BTreeMap<String, BTreeMap<String, Element>>>,
+  attribute_groups: Rc<BTreeMap<String, AttributeGroup>>,
+  default_attributes: Option<String>,
+  extensions: Rc<BTreeMap<String, String>>,
+  boxed_references: Rc<BTreeSet<(String, String)>>,
+  validated_types: Rc<OnceCell<BTreeSet<String>>>,
+  has_notations: bool,
+  target_namespace: Option<String>,
 }
 
 impl XsdContext {
@@ -20,20 +82,53 @@ impl XsdContext {
       match xml_element {
         Ok(XmlEvent::StartElement {
           name, namespace, ..
-        }) => {
-          if name.namespace == Some("http://www.w3.org/2001/XMLSchema".to_string())
-            && name.local_name == "schema"
-          {
-            let module_namespace_mappings = BTreeMap::new();
-            let xml_schema_prefix = name.prefix;
-
-            return Ok(XsdContext {
-              module_namespace_mappings,
-              namespace,
-              xml_schema_prefix,
-              is_in_sub_module: false,
-            });
-          }
+        }) if name.namespace.as_deref() == Some(XML_SCHEMA_NAMESPACE)
+          && name.local_name == "schema" =>
+        {
+          let xml_schema_prefix = name.prefix;
+
+          return Ok(XsdContext {
+            module_namespace_mappings: Rc::default(),
+            namespace_prefixes: Rc::default(),
+            namespace,
+            xml_schema_prefix,
+            is_in_sub_module: false,
+            attribute_form_default: Qualification::default(),
+            element_form_default: None,
+            included_form_defaults: Rc::default(),
+            parent_name: None,
+            inheritance: Inheritance::default(),
+            list_wrappers: false,
+            accessors: false,
+            non_exhaustive: false,
+            redact_binary: false,
+            prune_unused: false,
+            iterators: false,
+            streams: false,
+            visitors: false,
+            extension_traits: false,
+            validating_constructors: false,
+            validation_errors: false,
+            strict_order: false,
+            capture_unknown: false,
+            partial_eq_ignore: Rc::default(),
+            hide_anonymous_types: false,
+            root_elements: false,
+            types_path: None,
+            field_hook: None,
+            complex_types: Rc::default(),
+            simple_types: Rc::default(),
+            attributes: Rc::default(),
+            elements: Rc::default(),
+            imported_elements: Rc::default(),
+            attribute_groups: Rc::default(),
+            default_attributes: None,
+            extensions: Rc::default(),
+            boxed_references: Rc::default(),
+            validated_types: Rc::default(),
+            has_notations: false,
+            target_namespace: None,
+          });
         }
         Err(_) => break,
         _ => {}
@@ -47,16 +142,255 @@ impl XsdContext {
     mut self,
     module_namespace_mappings: &BTreeMap<String, String>,
   ) -> Self {
-    self.module_namespace_mappings = module_namespace_mappings.clone();
+    self.module_namespace_mappings = Rc::new(module_namespace_mappings.clone());
+    self
+  }
+
+  pub fn with_namespace_prefixes(mut self, namespace_prefixes: &BTreeMap<String, String>) -> Self {
+    self.namespace_prefixes = Rc::new(namespace_prefixes.clone());
+    self
+  }
+
+  pub fn with_inheritance(mut self, inheritance: Inheritance) -> Self {
+    self.inheritance = inheritance;
+    self
+  }
+
+  pub fn with_list_wrappers(mut self, list_wrappers: bool) -> Self {
+    self.list_wrappers = list_wrappers;
+    self
+  }
+
+  pub fn with_accessors(mut self, accessors: bool) -> Self {
+    self.accessors = accessors;
+    self
+  }
+
+  pub fn with_non_exhaustive(mut self, non_exhaustive: bool) -> Self {
+    self.non_exhaustive = non_exhaustive;
     self
   }
 
+  pub fn with_redact_binary(mut self, redact_binary: bool) -> Self {
+    self.redact_binary = redact_binary;
+    self
+  }
+
+  pub fn with_prune_unused(mut self, prune_unused: bool) -> Self {
+    self.prune_unused = prune_unused;
+    self
+  }
+
+  pub fn with_iterators(mut self, iterators: bool) -> Self {
+    self.iterators = iterators;
+    self
+  }
+
+  pub fn with_streams(mut self, streams: bool) -> Self {
+    self.streams = streams;
+    self
+  }
+
+  pub fn with_visitors(mut self, visitors: bool) -> Self {
+    self.visitors = visitors;
+    self
+  }
+
+  pub fn with_extension_traits(mut self, extension_traits: bool) -> Self {
+    self.extension_traits = extension_traits;
+    self
+  }
+
+  pub fn with_validating_constructors(mut self, validating_constructors: bool) -> Self {
+    self.validating_constructors = validating_constructors;
+    self
+  }
+
+  pub fn with_validation_errors(mut self, validation_errors: bool) -> Self {
+    self.validation_errors = validation_errors;
+    self
+  }
+
+  pub fn with_strict_order(mut self, strict_order: bool) -> Self {
+    self.strict_order = strict_order;
+    self
+  }
+
+  pub fn with_capture_unknown(mut self, capture_unknown: bool) -> Self {
+    self.capture_unknown = capture_unknown;
+    self
+  }
+
+  pub fn with_partial_eq_ignore(mut self, field_names: &[String]) -> Self {
+    self.partial_eq_ignore = Rc::new(field_names.iter().cloned().collect());
+    self
+  }
+
+  pub fn with_hide_anonymous_types(mut self, hide_anonymous_types: bool) -> Self {
+    self.hide_anonymous_types = hide_anonymous_types;
+    self
+  }
+
+  pub fn with_root_elements(mut self, root_elements: bool) -> Self {
+    self.root_elements = root_elements;
+    self
+  }
+
+  pub fn with_types_path(mut self, types_path: &Option<String>) -> Self {
+    self.types_path = types_path.clone();
+    self
+  }
+
+  pub fn with_field_hook(mut self, field_hook: Rc<dyn FieldHook>) -> Self {
+    self.field_hook = Some(field_hook);
+    self
+  }
+
+  pub fn with_schema(mut self, schema: &Schema) -> Self {
+    self.attribute_form_default = schema.attribute_form_default.clone().unwrap_or_default();
+    self.element_form_default = schema.element_form_default.clone();
+    self.complex_types = Rc::new(
+      schema
+        .complex_type
+        .iter()
+        .map(|complex_type| (complex_type.name.clone(), complex_type.clone()))
+        .collect(),
+    );
+    self.simple_types = Rc::new(
+      schema
+        .simple_type
+        .iter()
+        .map(|simple_type| (simple_type.name.clone(), simple_type.clone()))
+        .collect(),
+    );
+    self.attributes = Rc::new(
+      schema
+        .attributes
+        .iter()
+        .filter_map(|attribute| Some((attribute.name.clone()?, attribute.clone())))
+        .collect(),
+    );
+    self.elements = Rc::new(
+      schema
+        .elements
+        .iter()
+        .map(|element| (element.name.clone(), element.clone()))
+        .collect(),
+    );
+    self.attribute_groups = Rc::new(
+      schema
+        .attribute_group
+        .iter()
+        .filter_map(|attribute_group| {
+          Some((attribute_group.name.clone()?, attribute_group.clone()))
+        })
+        .collect(),
+    );
+    self.default_attributes = schema.default_attributes.clone();
+    self.extensions = Rc::new(
+      schema
+        .complex_type
+        .iter()
+        .filter_map(|complex_type| {
+          complex_type
+            .complex_content
+            .as_ref()
+            .and_then(|complex_content| complex_content.extension.as_ref())
+            .map(|extension| (complex_type.name.clone(), extension.base.clone()))
+        })
+        .collect(),
+    );
+    self.boxed_references = Rc::new(self.collect_boxed_references());
+    self.validated_types = Rc::default();
+    self.has_notations = !schema.notations.is_empty();
+    self.target_namespace = schema.target_namespace.clone();
+    self
+  }
+
+  /// Keep the form defaults of an included schema for its definitions, merged into the schema.
+  pub fn with_included_schema(mut self, schema: &Schema) -> Self {
+    let form_defaults = FormDefaults {
+      element: schema.element_form_default.clone(),
+      attribute: schema.attribute_form_default.clone().unwrap_or_default(),
+    };
+
+    let names = schema
+      .complex_type
+      .iter()
+      .map(|complex_type| complex_type.name.clone())
+      .chain(schema.group.iter().filter_map(|group| group.name.clone()))
+      .chain(schema.elements.iter().map(|element| element.name.clone()));
+
+    for name in names {
+      Rc::make_mut(&mut self.included_form_defaults).insert(name, form_defaults.clone());
+    }
+
+    self
+  }
+
+  /// Context generating a definition of an included schema, with the form defaults of that schema.
+  pub fn get_included_context(&self, name: &str) -> Option<XsdContext> {
+    let form_defaults = self.included_form_defaults.get(name)?;
+
+    let mut context = self.clone();
+    context.element_form_default = form_defaults.element.clone();
+    context.attribute_form_default = form_defaults.attribute.clone();
+    Some(context)
+  }
+
+  /// Index the global elements of a schema imported with its location. Their types are renamed
+  /// with the prefixes of this schema, elements of types it can not name are left out.
+  pub fn with_imported_schema(mut self, content: &str, schema: &Schema) -> Result<Self, String> {
+    let Some(target_namespace) = &schema.target_namespace else {
+      return Err("An imported schema must declare a target namespace".to_string());
+    };
+
+    let imported_namespace = XsdContext::new(content)?.namespace;
+
+    let elements = schema
+      .elements
+      .iter()
+      .filter_map(|element| {
+        let kind = element.kind.as_ref()?;
+        let (prefix, name) = kind.split_once(':').unwrap_or((NS_NO_PREFIX, kind));
+        let namespace = imported_namespace.get(prefix)?;
+
+        let kind = match self.get_declared_prefix(namespace)? {
+          prefix if prefix == NS_NO_PREFIX => name.to_string(),
+          prefix => format!("{prefix}:{name}"),
+        };
+
+        let element = Element {
+          kind: Some(kind),
+          ..element.clone()
+        };
+        Some((element.name.clone(), element))
+      })
+      .collect();
+
+    Rc::make_mut(&mut self.imported_elements).insert(target_namespace.clone(), elements);
+    Ok(self)
+  }
+
+  /// Prefix bound to `namespace` in the schema, `NS_NO_PREFIX` for the default namespace.
+  fn get_declared_prefix(&self, namespace: &str) -> Option<&str> {
+    self
+      .namespace
+      .0
+      .iter()
+      .find(|(_, uri)| *uri == namespace)
+      .map(|(prefix, _)| prefix.as_str())
+  }
+
+  /// Whether built-in types are prefixed, i.e. the XML Schema namespace is not the default one.
   pub fn has_xml_schema_prefix(&self) -> bool {
-    self.xml_schema_prefix.is_some()
+    self.namespace.get(NS_NO_PREFIX) != Some(XML_SCHEMA_NAMESPACE)
   }
 
+  /// Whether the prefix is bound to the XML Schema namespace, whatever its name (`xs`, `xsd`...).
   pub fn match_xml_schema_prefix(&self, value: &str) -> bool {
-    self.xml_schema_prefix == Some(value.to_string())
+    self.xml_schema_prefix.as_deref() == Some(value)
+      || self.namespace.get(value) == Some(XML_SCHEMA_NAMESPACE)
   }
 
   pub fn get_module(&self, prefix: &str) -> Option<String> {
@@ -72,6 +406,80 @@ impl XsdContext {
       .unwrap_or_else(|| None)
   }
 
+  /// Whether the prefix is bound to the target namespace, its types are the ones of the schema.
+  pub fn is_target_namespace_prefix(&self, prefix: &str) -> bool {
+    self.target_namespace.is_some()
+      && self.namespace.get(prefix) == self.target_namespace.as_deref()
+  }
+
+  /// Namespace bound without prefix (`xmlns="..."`) in the schema.
+  pub fn get_default_namespace(&self) -> Option<&str> {
+    self.namespace.get(xml::namespace::NS_NO_PREFIX)
+  }
+
+  /// Configured prefixes by namespace, the imported namespaces keeping the prefix of the schema
+  /// unless configured. A prefix wanted by several namespaces goes to the first configured one,
+  /// the others being numbered (`ns1`, `ns2`...).
+  pub fn get_namespace_prefixes(&self) -> BTreeMap<String, String> {
+    let configured_prefixes = self
+      .namespace_prefixes
+      .iter()
+      .map(|(namespace, prefix)| (namespace.as_str(), prefix.as_str()));
+
+    let imported_prefixes = self
+      .imported_elements
+      .keys()
+      .filter(|namespace| !self.namespace_prefixes.contains_key(*namespace))
+      .filter_map(|namespace| {
+        let prefix = self
+          .get_declared_prefix(namespace)
+          .filter(|prefix| *prefix != NS_NO_PREFIX)?;
+        Some((namespace.as_str(), prefix))
+      });
+
+    let mut namespace_prefixes = BTreeMap::new();
+    let mut used_prefixes = BTreeSet::new();
+
+    for (namespace, prefix) in configured_prefixes.chain(imported_prefixes) {
+      let mut candidate = prefix.to_string();
+      let mut index = 1;
+
+      while !used_prefixes.insert(candidate.clone()) {
+        candidate = format!("{prefix}{index}");
+        index += 1;
+      }
+
+      namespace_prefixes.insert(namespace.to_string(), candidate);
+    }
+
+    namespace_prefixes
+  }
+
+  /// Configured prefix of a namespace, by its URI. An imported namespace defaults to the prefix
+  /// the schema binds it to.
+  pub fn get_namespace_prefix(&self, namespace: &str) -> Option<String> {
+    self.get_namespace_prefixes().remove(namespace)
+  }
+
+  /// Configured prefix of the namespace a prefixed reference (`prefix:Name`) belongs to.
+  pub fn get_reference_prefix(&self, reference: &str) -> Option<String> {
+    let (prefix, _) = reference.split_once(':')?;
+
+    self
+      .namespace
+      .get(prefix)
+      .and_then(|namespace| self.get_namespace_prefix(namespace))
+  }
+
+  pub fn get_attribute_form_default(&self) -> &Qualification {
+    &self.attribute_form_default
+  }
+
+  /// `elementFormDefault` of the schema, local elements are qualified when it is not declared.
+  pub fn get_element_form_default(&self) -> Option<&Qualification> {
+    self.element_form_default.as_ref()
+  }
+
   pub fn set_is_in_sub_module(&mut self, is_in_sub_module: bool) {
     self.is_in_sub_module = is_in_sub_module;
   }
@@ -79,6 +487,533 @@ impl XsdContext {
   pub fn is_in_sub_module(&self) -> bool {
     self.is_in_sub_module
   }
+
+  pub fn set_parent_name(&mut self, parent_name: &str) {
+    self.parent_name = Some(parent_name.to_string());
+  }
+
+  pub fn parent_name(&self) -> Option<&str> {
+    self.parent_name.as_deref()
+  }
+
+  pub fn inheritance(&self) -> &Inheritance {
+    &self.inheritance
+  }
+
+  pub fn has_list_wrappers(&self) -> bool {
+    self.list_wrappers
+  }
+
+  pub fn has_accessors(&self) -> bool {
+    self.accessors
+  }
+
+  /// `#[non_exhaustive]` attribute of the generated structures and enumerations, when enabled.
+  pub fn get_non_exhaustive_attribute(&self) -> TokenStream {
+    if self.non_exhaustive {
+      quote!(#[non_exhaustive])
+    } else {
+      quote!()
+    }
+  }
+
+  /// Attribute hiding the types generated for anonymous types from the documentation.
+  pub fn get_anonymous_type_attribute(&self) -> TokenStream {
+    if self.hide_anonymous_types {
+      quote!(#[doc(hidden)])
+    } else {
+      quote!()
+    }
+  }
+
+  pub fn has_redact_binary(&self) -> bool {
+    self.redact_binary
+  }
+
+  pub fn has_prune_unused(&self) -> bool {
+    self.prune_unused
+  }
+
+  pub fn has_iterators(&self) -> bool {
+    self.iterators
+  }
+
+  pub fn has_streams(&self) -> bool {
+    self.streams
+  }
+
+  pub fn has_visitors(&self) -> bool {
+    self.visitors
+  }
+
+  pub fn has_extension_traits(&self) -> bool {
+    self.extension_traits
+  }
+
+  pub fn has_validating_constructors(&self) -> bool {
+    self.validating_constructors
+  }
+
+  pub fn has_validation_errors(&self) -> bool {
+    self.validation_errors
+  }
+
+  pub fn has_strict_order(&self) -> bool {
+    self.strict_order
+  }
+
+  pub fn has_capture_unknown(&self) -> bool {
+    self.capture_unknown
+  }
+
+  pub fn has_root_elements(&self) -> bool {
+    self.root_elements
+  }
+
+  /// Whether `field_name` is left out of the `PartialEq` implementations.
+  pub fn is_partial_eq_ignored(&self, field_name: &str) -> bool {
+    self.partial_eq_ignore.contains(field_name)
+  }
+
+  /// Path of the generated types module, used to reference the types from outside of it.
+  pub fn get_types_path(&self) -> TokenStream {
+    let types_path = self.types_path.as_deref().unwrap_or("xml_schema_types");
+    let types_path = syn::parse_str::<syn::Path>(types_path)
+      .unwrap_or_else(|_| panic!("Invalid types path {:?}", types_path));
+
+    quote!(#types_path::)
+  }
+
+  /// Extra attributes of the field `field_name` returned by the field hook, if any.
+  pub fn get_field_hook_attributes(&self, field_name: &str, field_type: &str) -> TokenStream {
+    self
+      .field_hook
+      .as_ref()
+      .map(|field_hook| field_hook.get_attributes(field_name, field_type))
+      .unwrap_or_default()
+  }
+
+  /// Whether the schema declares notations, generated as the `Notation` enumeration.
+  pub fn has_notations(&self) -> bool {
+    self.has_notations
+  }
+
+  /// Named complex types getting a `validate` method, computed once for the schema: the types
+  /// declaring checks, then the ones holding them until none is added.
+  pub fn get_validated_types(&self) -> &BTreeSet<String> {
+    self.validated_types.get_or_init(|| {
+      let mut validated_types = BTreeSet::new();
+
+      loop {
+        let added: Vec<String> = self
+          .complex_types
+          .values()
+          .filter(|complex_type| !validated_types.contains(&complex_type.name))
+          .filter(|complex_type| complex_type.has_validation(self, &validated_types))
+          .map(|complex_type| complex_type.name.clone())
+          .collect();
+
+        if added.is_empty() {
+          return validated_types;
+        }
+
+        validated_types.extend(added);
+      }
+    })
+  }
+
+  pub fn get_complex_type(&self, kind: &str) -> Option<&ComplexType> {
+    let name = kind.split(':').next_back().unwrap_or(kind);
+    self.complex_types.get(name)
+  }
+
+  pub fn get_simple_type(&self, kind: &str) -> Option<&SimpleType> {
+    let name = kind.split(':').next_back().unwrap_or(kind);
+    self.simple_types.get(name)
+  }
+
+  /// Whether the type generated for `kind` implements `Default`, restricted newtypes only do
+  /// when the default value of their base satisfies the facets.
+  pub fn has_default(&self, kind: &str) -> bool {
+    if RustTypesMapping::is_xml_schema_type(self, kind) {
+      return true;
+    }
+
+    let name = kind.split(':').next_back().unwrap_or(kind);
+
+    self
+      .simple_types
+      .get(name)
+      .filter(|simple_type| simple_type.list.is_none() && simple_type.union.is_none())
+      .and_then(|simple_type| simple_type.restriction.as_ref())
+      .filter(|restriction| !restriction.is_enumeration() && restriction.has_facets())
+      .map(|restriction| restriction.has_valid_default(self))
+      .unwrap_or(true)
+  }
+
+  /// Global element declaration of the schema, referenced as `name` or `prefix:name` in the target namespace,
+  /// or of an imported schema, referenced as `prefix:name` in its namespace.
+  pub fn get_element(&self, reference: &str) -> Option<&Element> {
+    let name = match reference.split_once(':') {
+      Some((prefix, name)) if self.is_target_namespace_prefix(prefix) => name,
+      Some((prefix, name)) => {
+        return self
+          .namespace
+          .get(prefix)
+          .and_then(|namespace| self.imported_elements.get(namespace))
+          .and_then(|elements| elements.get(name));
+      }
+      None => reference,
+    };
+
+    self.elements.get(name)
+  }
+
+  /// Global elements declaring `head` as one of their `substitutionGroup` heads.
+  pub fn get_substitution_members(&self, head: &str) -> Vec<&Element> {
+    self
+      .elements
+      .values()
+      .filter(|element| {
+        element
+          .get_substitution_heads()
+          .into_iter()
+          .filter_map(|substitution_group| self.get_element(substitution_group))
+          .any(|element| element.name == head)
+      })
+      .collect()
+  }
+
+  /// Global attribute group declaration, referenced as `prefix:name`.
+  pub fn get_attribute_group(&self, reference: &str) -> Option<&AttributeGroup> {
+    let name = reference.split(':').next_back().unwrap_or(reference);
+    self.attribute_groups.get(name)
+  }
+
+  /// Attribute group added to every complex type by the schema `defaultAttributes` (XSD 1.1).
+  pub fn get_default_attributes(&self) -> Option<&str> {
+    self.default_attributes.as_deref()
+  }
+
+  /// Global attribute declaration, referenced as `prefix:name`.
+  pub fn get_attribute(&self, reference: &str) -> Option<&Attribute> {
+    let name = reference.split(':').next_back().unwrap_or(reference);
+    self.attributes.get(name)
+  }
+
+  /// Whether complex types extend `name`.
+  pub fn has_extensions(&self, name: &str) -> bool {
+    self
+      .extensions
+      .values()
+      .any(|base| base.split(':').next_back().unwrap_or(base) == name)
+  }
+
+  /// Whether a `from` value holds a `to` value, directly or through other complex types,
+  /// following the single occurrence elements and the extension bases.
+  pub fn reaches_type(&self, from: &str, to: &str) -> bool {
+    self.follows_types(from, to, false)
+  }
+
+  /// Whether a `from` value contains `to` values, repeated elements included.
+  pub fn contains_type(&self, from: &str, to: &str) -> bool {
+    self.follows_types(from, to, true)
+  }
+
+  fn follows_types(&self, from: &str, to: &str, with_repeated: bool) -> bool {
+    let mut pending = vec![from.to_string()];
+    let mut visited = BTreeSet::new();
+
+    while let Some(name) = pending.pop() {
+      if !visited.insert(name.clone()) {
+        continue;
+      }
+
+      if let Some(complex_type) = self.get_complex_type(&name) {
+        let mut held_types = vec![];
+        collect_held_types(self, complex_type, with_repeated, &mut held_types);
+
+        if held_types.iter().any(|held_type| held_type == to) {
+          return true;
+        }
+
+        pending.extend(held_types);
+      }
+    }
+
+    false
+  }
+
+  /// Whether the single occurrence `element` of the `holder` complex type is held through a `Box`.
+  /// `None` when `holder` is not a global complex type.
+  pub fn is_boxed_reference(&self, holder: &str, element: &str) -> Option<bool> {
+    self.get_complex_type(holder)?;
+
+    Some(
+      self
+        .boxed_references
+        .contains(&(holder.to_string(), element.to_string())),
+    )
+  }
+
+  /// References `(holder, element)` breaking the cycles between complex types. Each cycle found
+  /// gets a single `Box`, on its last optional reference when it has one, until no cycle is left.
+  fn collect_boxed_references(&self) -> BTreeSet<(String, String)> {
+    let mut cut_references = CutReferences::default();
+
+    while let Some(cycle) = self.find_cycle(&cut_references) {
+      let boxed_reference = cycle
+        .iter()
+        .rev()
+        .filter(|reference| reference.element.is_some())
+        .find(|reference| reference.is_optional)
+        .or_else(|| {
+          cycle
+            .iter()
+            .rev()
+            .find(|reference| reference.element.is_some())
+        });
+
+      match boxed_reference {
+        Some(reference) => {
+          let element = reference.element.clone().unwrap_or_default();
+          cut_references
+            .boxed
+            .insert((reference.holder.clone(), element));
+        }
+        // extension bases only, the schema can not be represented
+        None => cut_references.ignored.extend(
+          cycle
+            .into_iter()
+            .map(|reference| (reference.holder, reference.held_type)),
+        ),
+      }
+    }
+
+    cut_references.boxed
+  }
+
+  /// References of a cycle between complex types, skipping the `cut_references`.
+  fn find_cycle(&self, cut_references: &CutReferences) -> Option<Vec<HeldReference>> {
+    let mut visited = BTreeSet::new();
+
+    self
+      .complex_types
+      .keys()
+      .find_map(|name| self.visit_references(name, &mut vec![], &mut visited, cut_references))
+  }
+
+  /// Depth-first traversal, `path` holds the references followed from the first visited type.
+  fn visit_references(
+    &self,
+    name: &str,
+    path: &mut Vec<HeldReference>,
+    visited: &mut BTreeSet<String>,
+    cut_references: &CutReferences,
+  ) -> Option<Vec<HeldReference>> {
+    if let Some(position) = path.iter().position(|reference| reference.holder == name) {
+      return Some(path.split_off(position));
+    }
+
+    if !visited.insert(name.to_string()) {
+      return None;
+    }
+
+    let complex_type = self.get_complex_type(name)?;
+
+    for reference in get_held_references(self, complex_type) {
+      if cut_references.contains(&reference) {
+        continue;
+      }
+
+      let held_type = reference.held_type.clone();
+      path.push(reference);
+
+      if let Some(cycle) = self.visit_references(&held_type, path, visited, cut_references) {
+        return Some(cycle);
+      }
+
+      path.pop();
+    }
+
+    None
+  }
+
+  /// Whether the complex type `kind` is `base` or extends it, other types only derive from themselves.
+  pub fn is_derived_from(&self, kind: &str, base: &str) -> bool {
+    let name = kind.split(':').next_back().unwrap_or(kind);
+    let base_name = base.split(':').next_back().unwrap_or(base);
+
+    name == base_name
+      || self
+        .get_ancestors(name)
+        .iter()
+        .any(|ancestor| ancestor.name == base_name)
+  }
+
+  /// Returns the chain of complex types extended by `name`, the immediate base first.
+  pub fn get_ancestors(&self, name: &str) -> Vec<&ComplexType> {
+    let mut ancestors: Vec<&ComplexType> = vec![];
+    let mut current = name;
+
+    while let Some(base) = self.extensions.get(current) {
+      match self.get_complex_type(base) {
+        Some(complex_type) if !ancestors.contains(&complex_type) && complex_type.name != name => {
+          ancestors.push(complex_type);
+          current = &complex_type.name;
+        }
+        _ => break,
+      }
+    }
+
+    ancestors
+  }
+}
+
+/// Complex type held by another one, through one of its single occurrence elements or its
+/// extension base.
+#[derive(Clone, Debug)]
+struct HeldReference {
+  holder: String,
+  element: Option<String>,
+  held_type: String,
+  is_optional: bool,
+}
+
+/// References skipped while looking for cycles: the boxed `(holder, element)` and the
+/// `(holder, held_type)` of the cycles through extension bases only.
+#[derive(Default)]
+struct CutReferences {
+  boxed: BTreeSet<(String, String)>,
+  ignored: BTreeSet<(String, String)>,
+}
+
+impl CutReferences {
+  fn contains(&self, reference: &HeldReference) -> bool {
+    match &reference.element {
+      Some(element) => self
+        .boxed
+        .contains(&(reference.holder.clone(), element.clone())),
+      None => self
+        .ignored
+        .contains(&(reference.holder.clone(), reference.held_type.clone())),
+    }
+  }
+}
+
+/// Complex types held by `complex_type`, the elements of its inline types included. The extension
+/// base has no element of `complex_type`.
+fn get_held_references(context: &XsdContext, complex_type: &ComplexType) -> Vec<HeldReference> {
+  let mut references = vec![];
+  collect_element_references(context, &complex_type.name, complex_type, &mut references);
+
+  if let Some(extension) = complex_type
+    .complex_content
+    .as_ref()
+    .and_then(|complex_content| complex_content.extension.as_ref())
+  {
+    let base = &extension.base;
+    references.push(HeldReference {
+      holder: complex_type.name.clone(),
+      element: None,
+      held_type: base.split(':').next_back().unwrap_or(base).to_string(),
+      is_optional: false,
+    });
+  }
+
+  references
+}
+
+fn collect_element_references(
+  context: &XsdContext,
+  holder: &str,
+  complex_type: &ComplexType,
+  references: &mut Vec<HeldReference>,
+) {
+  for element in complex_type
+    .get_sequences()
+    .into_iter()
+    .flat_map(|sequence| &sequence.elements)
+    .filter(|element| !element.is_multiple() && !element.is_prohibited())
+  {
+    if let Some(kind) = &element.kind {
+      if context.get_complex_type(kind).is_some() {
+        references.push(HeldReference {
+          holder: holder.to_string(),
+          element: Some(element.name.clone()),
+          held_type: kind.split(':').next_back().unwrap_or(kind).to_string(),
+          is_optional: element.get_min_occurences() == 0,
+        });
+      }
+    }
+
+    if let Some(complex_type) = &element.complex_type {
+      collect_element_references(context, holder, complex_type, references);
+    }
+  }
+
+  // a variant holds a single occurrence, the other variants making it optional
+  for (element, _) in complex_type
+    .get_choice_elements()
+    .into_iter()
+    .filter(|(element, is_repeated)| !is_repeated && !element.is_multiple())
+  {
+    if let Some(kind) = &element.kind {
+      if context.get_complex_type(kind).is_some() {
+        references.push(HeldReference {
+          holder: holder.to_string(),
+          element: Some(element.name.clone()),
+          held_type: kind.split(':').next_back().unwrap_or(kind).to_string(),
+          is_optional: true,
+        });
+      }
+    }
+  }
+}
+
+fn collect_held_types(
+  context: &XsdContext,
+  complex_type: &ComplexType,
+  with_repeated: bool,
+  held_types: &mut Vec<String>,
+) {
+  for element in complex_type
+    .get_sequences()
+    .into_iter()
+    .flat_map(|sequence| &sequence.elements)
+    .filter(|element| with_repeated || !element.is_multiple())
+  {
+    if let Some(kind) = &element.kind {
+      if context.get_complex_type(kind).is_some() {
+        held_types.push(kind.split(':').next_back().unwrap_or(kind).to_string());
+      }
+    }
+
+    if let Some(complex_type) = &element.complex_type {
+      collect_held_types(context, complex_type, with_repeated, held_types);
+    }
+  }
+
+  for (element, _) in complex_type
+    .get_choice_elements()
+    .into_iter()
+    .filter(|(element, is_repeated)| with_repeated || (!is_repeated && !element.is_multiple()))
+  {
+    if let Some(kind) = &element.kind {
+      if context.get_complex_type(kind).is_some() {
+        held_types.push(kind.split(':').next_back().unwrap_or(kind).to_string());
+      }
+    }
+  }
+
+  if let Some(extension) = complex_type
+    .complex_content
+    .as_ref()
+    .and_then(|complex_content| complex_content.extension.as_ref())
+  {
+    let base = &extension.base;
+    held_types.push(base.split(':').next_back().unwrap_or(base).to_string());
+  }
 }
 
 #[test]
@@ -108,6 +1043,94 @@ fn get_module() {
   assert_eq!(context.get_module("other"), None);
 }
 
+#[test]
+fn get_reference_prefix() {
+  let context = XsdContext::new(
+    r#"
+    <xs:schema
+      xmlns:xs="http://www.w3.org/2001/XMLSchema"
+      xmlns:ns1="http://example.com"
+      >
+    </xs:schema>
+  "#,
+  )
+  .unwrap();
+
+  let prefixes = BTreeMap::from([("http://example.com".to_string(), "ex".to_string())]);
+  let context = context.with_namespace_prefixes(&prefixes);
+
+  assert_eq!(
+    context.get_reference_prefix("ns1:Item"),
+    Some("ex".to_string())
+  );
+  assert_eq!(context.get_reference_prefix("xs:string"), None);
+  assert_eq!(context.get_reference_prefix("Item"), None);
+}
+
+#[test]
+fn colliding_namespace_prefixes() {
+  let context = XsdContext::new(
+    r#"
+    <xs:schema
+      xmlns:xs="http://www.w3.org/2001/XMLSchema"
+      xmlns:a="http://a.example.com"
+      xmlns:b="http://b.example.com"
+      >
+    </xs:schema>
+  "#,
+  )
+  .unwrap();
+
+  let prefixes = BTreeMap::from([
+    ("http://a.example.com".to_string(), "ns".to_string()),
+    ("http://b.example.com".to_string(), "ns".to_string()),
+  ]);
+  let context = context.with_namespace_prefixes(&prefixes);
+
+  assert_eq!(
+    context.get_reference_prefix("a:Item"),
+    Some("ns".to_string())
+  );
+  assert_eq!(
+    context.get_reference_prefix("b:Item"),
+    Some("ns1".to_string())
+  );
+}
+
+#[test]
+fn xml_schema_prefixes() {
+  let context = XsdContext::new(
+    r#"
+    <schema
+      xmlns="http://www.w3.org/2001/XMLSchema"
+      xmlns:xsd="http://www.w3.org/2001/XMLSchema"
+      xmlns:example="http://example.com"
+      >
+    </schema>
+  "#,
+  )
+  .unwrap();
+
+  assert!(!context.has_xml_schema_prefix());
+  assert!(context.match_xml_schema_prefix("xsd"));
+  assert!(!context.match_xml_schema_prefix("example"));
+  assert!(!context.match_xml_schema_prefix("xs"));
+
+  let context = XsdContext::new(
+    r#"
+    <xsd:schema
+      xmlns:xsd="http://www.w3.org/2001/XMLSchema"
+      xmlns="http://example.com"
+      >
+    </xsd:schema>
+  "#,
+  )
+  .unwrap();
+
+  assert!(context.has_xml_schema_prefix());
+  assert!(context.match_xml_schema_prefix("xsd"));
+}
+
 #[test]
 fn bad_schema_definition() {
   let context = XsdContext::new(